@@ -0,0 +1,408 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use serde::Serialize;
+
+use super::player_index;
+use crate::model::ingame::*;
+
+/// Kills further apart may belong to different rounds, a single duel can take longer though \
+/// The shopping phase between two rounds alone takes about 30 seconds
+const ROUND_GAP: Time = 25.0;
+/// A subteam that didn't fight in this many completed rounds is considered eliminated
+const MISSED_ROUNDS: u32 = 2;
+
+/// The subteams, rounds and standings of an Arena game \
+/// The API doesn't report them, so they are derived from the [ChampionKill] and [Ace] events
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArenaView {
+    /// the latest round with combat, 0 before the first fight
+    pub round: u32,
+    pub subteams: Vec<Subteam>,
+    pub rounds: Vec<ArenaRound>,
+    pub finished: bool,
+}
+
+/// Two players fighting together
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Subteam {
+    /// numbered from 1 in the order of the players
+    pub id: usize,
+    pub members: Vec<SubteamMember>,
+    pub kills: u32,
+    pub deaths: u32,
+    pub elimination: Option<Elimination>,
+    /// known once the subteam is eliminated or the game has ended
+    pub placement: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubteamMember {
+    pub riot_id: String,
+    pub champion_name: ChampionName,
+    pub level: Level,
+    pub is_dead: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Elimination {
+    pub round: u32,
+    /// the time of the last kill the subteam was involved in
+    pub time: Time,
+    /// whether an [Ace] reported the elimination, \
+    /// otherwise it's inferred from the subteam not fighting anymore
+    pub ace: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArenaRound {
+    pub number: u32,
+    /// the time of the first kill
+    pub start: Time,
+    /// the time of the last kill
+    pub end: Time,
+    /// ids of the subteams that were alive at the start of the round
+    pub alive: Vec<usize>,
+    pub kills: Vec<ArenaKill>,
+    /// ids of the subteams that were eliminated in this round
+    pub eliminated: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArenaKill {
+    pub time: Time,
+    /// the riot id of the killer, `None` if the victim wasn't killed by a player
+    pub killer: Option<String>,
+    pub killer_subteam: Option<usize>,
+    pub victim: String,
+    pub victim_subteam: usize,
+}
+
+/// A [ChampionKill] with the players resolved to their index
+struct Kill {
+    time: Time,
+    killer: Option<usize>,
+    victim: usize,
+    assisters: Vec<usize>,
+}
+
+impl ArenaView {
+    /// The Arena view of the game, `None` if it isn't an Arena game
+    pub fn new(data: &AllGameData) -> Option<Self> {
+        if !matches!(data.game_data.game_mode, GameMode::Arena) {
+            return None;
+        }
+        let players = &data.all_players;
+
+        let mut kills = Vec::new();
+        // indices of the kills that wiped out a subteam
+        let mut aced = Vec::new();
+        let mut finished = false;
+        for event in data.events.iter() {
+            match event {
+                GameEvent::ChampionKill(e) => {
+                    let Some(victim) = player_index(players, &e.victim_name) else {
+                        continue;
+                    };
+                    let killer = match &e.killer_name {
+                        Killer::Summoner(name) => player_index(players, name),
+                        _ => None,
+                    };
+                    kills.push(Kill {
+                        time: e.event_time,
+                        killer,
+                        victim,
+                        // bots summoned by augments assist too
+                        assisters: e
+                            .assisters
+                            .iter()
+                            .filter_map(|a| player_index(players, a))
+                            .collect(),
+                    });
+                }
+                GameEvent::Ace(_) if !kills.is_empty() => aced.push(kills.len() - 1),
+                GameEvent::GameEnd(_) => finished = true,
+                _ => {}
+            }
+        }
+
+        let subteam_of = pair_players(players.len(), &kills);
+        let subteam_count = subteam_of.iter().max().map_or(0, |&max| max + 1);
+
+        let round_of = rounds_of(&kills, &subteam_of, subteam_count);
+        let round = round_of.last().copied().unwrap_or(0);
+
+        let mut kill_count = vec![0; subteam_count];
+        let mut death_count = vec![0; subteam_count];
+        // the round and time each subteam last fought
+        let mut last_fight = vec![(0, 0.0); subteam_count];
+        for (kill, &round) in kills.iter().zip(round_of.iter()) {
+            death_count[subteam_of[kill.victim]] += 1;
+            if let Some(killer) = kill.killer {
+                kill_count[subteam_of[killer]] += 1;
+            }
+            for player in kill
+                .killer
+                .iter()
+                .chain(kill.assisters.iter())
+                .chain([&kill.victim])
+            {
+                last_fight[subteam_of[*player]] = (round, kill.time);
+            }
+        }
+
+        let mut eliminations = vec![None; subteam_count];
+        for &kill in aced.iter() {
+            eliminations[subteam_of[kills[kill].victim]].get_or_insert(Elimination {
+                round: round_of[kill],
+                time: kills[kill].time,
+                ace: true,
+            });
+        }
+        let round_over = kills
+            .last()
+            .is_some_and(|k| data.game_data.game_time - k.time > ROUND_GAP);
+        let completed_rounds = if finished || round_over {
+            round
+        } else {
+            round.saturating_sub(1)
+        };
+        for (subteam, elimination) in eliminations.iter_mut().enumerate() {
+            let (round, time) = last_fight[subteam];
+            if elimination.is_none() && completed_rounds >= round + MISSED_ROUNDS {
+                *elimination = Some(Elimination {
+                    round,
+                    time,
+                    ace: false,
+                });
+            }
+        }
+        if finished {
+            // the last kill was made by the winner, the others are out
+            let winner = kills
+                .last()
+                .and_then(|k| k.killer)
+                .map(|killer| subteam_of[killer])
+                .filter(|&winner| eliminations[winner].is_none())
+                .or_else(|| {
+                    (0..subteam_count)
+                        .filter(|&s| eliminations[s].is_none())
+                        .max_by(|a, b| last_fight[*a].1.total_cmp(&last_fight[*b].1))
+                });
+            for (subteam, elimination) in eliminations.iter_mut().enumerate() {
+                let (round, time) = last_fight[subteam];
+                if Some(subteam) != winner && elimination.is_none() {
+                    *elimination = Some(Elimination {
+                        round,
+                        time,
+                        ace: false,
+                    });
+                }
+            }
+        }
+
+        // the first eliminated subteam is last
+        let mut placements = vec![None; subteam_count];
+        let mut eliminated = eliminations
+            .iter()
+            .enumerate()
+            .filter_map(|(subteam, e)| Some((subteam, e.as_ref()?.time)))
+            .collect::<Vec<_>>();
+        eliminated.sort_by(|a, b| a.1.total_cmp(&b.1));
+        for (index, &(subteam, _)) in eliminated.iter().enumerate() {
+            placements[subteam] = Some(subteam_count - index);
+        }
+        if finished {
+            let remaining = (0..subteam_count).filter(|&s| placements[s].is_none());
+            for (index, subteam) in remaining.collect::<Vec<_>>().into_iter().enumerate() {
+                placements[subteam] = Some(index + 1);
+            }
+        }
+
+        let subteams = (0..subteam_count)
+            .map(|subteam| Subteam {
+                id: subteam + 1,
+                members: players
+                    .iter()
+                    .zip(subteam_of.iter())
+                    .filter(|(_, &s)| s == subteam)
+                    .map(|(p, _)| SubteamMember {
+                        riot_id: p.riot_id.riot_id.clone(),
+                        champion_name: p.champion_name.clone(),
+                        level: p.level,
+                        is_dead: p.is_dead,
+                    })
+                    .collect(),
+                kills: kill_count[subteam],
+                deaths: death_count[subteam],
+                elimination: eliminations[subteam].clone(),
+                placement: placements[subteam],
+            })
+            .collect::<Vec<_>>();
+
+        let rounds = (1..=round)
+            .map(|number| {
+                let round_kills = kills
+                    .iter()
+                    .zip(round_of.iter())
+                    .filter(|(_, &r)| r == number)
+                    .map(|(kill, _)| kill)
+                    .collect::<Vec<_>>();
+                let eliminated_in = |s: &Subteam, round: u32| {
+                    s.elimination.as_ref().is_some_and(|e| e.round < round)
+                };
+                ArenaRound {
+                    number,
+                    start: round_kills.first().map_or(0.0, |k| k.time),
+                    end: round_kills.last().map_or(0.0, |k| k.time),
+                    alive: subteams
+                        .iter()
+                        .filter(|s| !eliminated_in(s, number))
+                        .map(|s| s.id)
+                        .collect(),
+                    kills: round_kills
+                        .iter()
+                        .map(|kill| ArenaKill {
+                            time: kill.time,
+                            killer: kill.killer.map(|k| players[k].riot_id.riot_id.clone()),
+                            killer_subteam: kill.killer.map(|k| subteam_of[k] + 1),
+                            victim: players[kill.victim].riot_id.riot_id.clone(),
+                            victim_subteam: subteam_of[kill.victim] + 1,
+                        })
+                        .collect(),
+                    eliminated: subteams
+                        .iter()
+                        .filter(|s| s.elimination.as_ref().is_some_and(|e| e.round == number))
+                        .map(|s| s.id)
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Some(Self {
+            round,
+            subteams,
+            rounds,
+            finished,
+        })
+    }
+
+    /// The subteam of the player with the riot id
+    pub fn subteam(&self, riot_id: &str) -> Option<&Subteam> {
+        self.subteams
+            .iter()
+            .find(|s| s.members.iter().any(|m| m.riot_id == riot_id))
+    }
+
+    /// The subteams still playing by their kills, then the eliminated subteams by their placement
+    pub fn standings(&self) -> Vec<&Subteam> {
+        let mut standings = self.subteams.iter().collect::<Vec<_>>();
+        standings.sort_by_key(|s| (s.elimination.is_some(), s.placement, Reverse(s.kills)));
+        standings
+    }
+}
+
+/// The round of every kill \
+/// After a pause of [ROUND_GAP] a kill only starts a new round if one of its subteams already finished its duel,
+/// otherwise it belongs to a long duel of the current round
+fn rounds_of(kills: &[Kill], subteam_of: &[usize], subteam_count: usize) -> Vec<u32> {
+    let mut size = vec![0; subteam_count];
+    for &subteam in subteam_of {
+        size[subteam] += 1;
+    }
+
+    let mut round = 0;
+    let mut last_time = None;
+    // the players that died, the opponent of every subteam and whether its duel is over in the round
+    let mut died = HashSet::new();
+    let mut opponent = vec![None; subteam_count];
+    let mut done = vec![false; subteam_count];
+    kills
+        .iter()
+        .map(|kill| {
+            let victim = subteam_of[kill.victim];
+            let killer = kill
+                .killer
+                .map(|killer| subteam_of[killer])
+                .filter(|&killer| killer != victim);
+            let paused = last_time.is_none_or(|time| kill.time - time > ROUND_GAP);
+            let duel_over = done[victim] || killer.is_some_and(|killer| done[killer]);
+            if round == 0 || (paused && duel_over) {
+                round += 1;
+                died.clear();
+                opponent.fill(None);
+                done.fill(false);
+            }
+            last_time = Some(kill.time);
+
+            died.insert(kill.victim);
+            if let Some(killer) = killer {
+                opponent[victim] = Some(killer);
+                opponent[killer] = Some(victim);
+            }
+            // the duel is over once a subteam is wiped out
+            let deaths = died.iter().filter(|&&p| subteam_of[p] == victim).count();
+            if deaths >= size[victim] {
+                done[victim] = true;
+                if let Some(opponent) = opponent[victim] {
+                    done[opponent] = true;
+                }
+            }
+            round
+        })
+        .collect()
+}
+
+/// The subteam index of every player \
+/// Players are paired with the player they kill and assist with the most, the rest in their order
+fn pair_players(count: usize, kills: &[Kill]) -> Vec<usize> {
+    let mut together = HashMap::<(usize, usize), u32>::new();
+    for kill in kills {
+        let Some(killer) = kill.killer else {
+            continue;
+        };
+        for &assister in kill.assisters.iter().filter(|&&a| a != killer) {
+            *together
+                .entry((killer.min(assister), killer.max(assister)))
+                .or_default() += 1;
+        }
+    }
+    let mut pairs = together.into_iter().collect::<Vec<_>>();
+    pairs.sort_by_key(|&(pair, count)| (Reverse(count), pair));
+
+    let mut partner = vec![None; count];
+    for ((a, b), _) in pairs {
+        if partner[a].is_none() && partner[b].is_none() {
+            partner[a] = Some(b);
+            partner[b] = Some(a);
+        }
+    }
+    let unpaired = (0..count)
+        .filter(|&p| partner[p].is_none())
+        .collect::<Vec<_>>();
+    for pair in unpaired.chunks_exact(2) {
+        partner[pair[0]] = Some(pair[1]);
+        partner[pair[1]] = Some(pair[0]);
+    }
+
+    let mut subteams = Vec::with_capacity(count);
+    let mut next = 0;
+    for player in 0..count {
+        match partner[player] {
+            Some(partner) if partner < player => subteams.push(subteams[partner]),
+            _ => {
+                subteams.push(next);
+                next += 1;
+            }
+        }
+    }
+    subteams
+}
@@ -0,0 +1,325 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::{error::IngameClientError, ingame::IngameApi, model::ingame::Time};
+
+/// Game time advancing slower than this per real second counts as paused
+const PAUSE_RATE: f64 = 0.1;
+/// Game time standing still for less than this can't be told apart from the polling jitter
+const MIN_PAUSE_INTERVAL: f64 = 0.25;
+/// How far a sample may be off the estimate before the clock takes it for a seek or a change of the replay speed
+const JUMP_TOLERANCE: Time = 0.5;
+
+/// A pause of the game, e.g. a paused replay or a pause in a custom game
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pause {
+    /// the game time the game stood still at
+    pub game_time: Time,
+    /// estimated from the rate before the pause
+    pub started: Instant,
+    /// estimated from the rate after the pause, `None` while still paused
+    pub ended: Option<Instant>,
+}
+
+impl Pause {
+    fn contains(&self, instant: Instant) -> bool {
+        self.started <= instant && self.ended.is_none_or(|ended| instant < ended)
+    }
+}
+
+/// A change of the relation between game time and real time, returned by [GameClock::push]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClockChange {
+    Paused {
+        game_time: Time,
+    },
+    Resumed {
+        game_time: Time,
+    },
+    /// a seek or a change of the replay speed
+    Jumped {
+        from: Time,
+        to: Time,
+    },
+}
+
+/// A stretch of samples where game time advanced at a constant rate, \
+/// fitted with least squares relative to its first sample
+#[derive(Debug, Clone)]
+struct Segment {
+    start: Instant,
+    first_game_time: Time,
+    last_game_time: Time,
+    /// the rate of the previous segment, used until a second sample arrives
+    rate_hint: f64,
+    n: f64,
+    sum_x: f64,
+    sum_y: f64,
+    sum_xx: f64,
+    sum_xy: f64,
+}
+
+impl Segment {
+    fn new(game_time: Time, at: Instant, rate_hint: f64) -> Self {
+        let mut segment = Self {
+            start: at,
+            first_game_time: game_time,
+            last_game_time: game_time,
+            rate_hint,
+            n: 0.0,
+            sum_x: 0.0,
+            sum_y: 0.0,
+            sum_xx: 0.0,
+            sum_xy: 0.0,
+        };
+        segment.push(game_time, at);
+        segment
+    }
+
+    fn push(&mut self, game_time: Time, at: Instant) {
+        let x = seconds_between(self.start, at);
+        let y = game_time - self.first_game_time;
+        self.n += 1.0;
+        self.sum_x += x;
+        self.sum_y += y;
+        self.sum_xx += x * x;
+        self.sum_xy += x * y;
+        self.last_game_time = self.last_game_time.max(game_time);
+    }
+
+    /// The rate and the game time at the start of the segment
+    fn fit(&self) -> (f64, Time) {
+        let variance = self.n * self.sum_xx - self.sum_x * self.sum_x;
+        if self.n < 2.0 || variance < 1e-9 {
+            return (self.rate_hint, self.first_game_time);
+        }
+        let rate = (self.n * self.sum_xy - self.sum_x * self.sum_y) / variance;
+        let intercept = (self.sum_y - rate * self.sum_x) / self.n;
+        (rate, self.first_game_time + intercept)
+    }
+
+    fn game_time_at(&self, instant: Instant) -> Time {
+        let (rate, start_game_time) = self.fit();
+        start_game_time + rate * seconds_between(self.start, instant)
+    }
+
+    fn instant_of(&self, game_time: Time) -> Option<Instant> {
+        let (rate, start_game_time) = self.fit();
+        if rate <= 0.0 {
+            return None;
+        }
+        add_seconds(self.start, (game_time - start_game_time) / rate)
+    }
+}
+
+/// Maps game time to real time from repeated samples of [GameStats::game_time](crate::model::ingame::GameStats::game_time), \
+/// estimating the rate of the game time and detecting pauses, seeks and replay speed changes \
+/// Lines up [GameEvent::get_event_time](crate::model::ingame::GameEvent::get_event_time) with video captures or stream timestamps
+#[derive(Debug, Clone)]
+pub struct GameClock {
+    /// an [Instant] and [SystemTime] taken together to convert between the two
+    anchor: (Instant, SystemTime),
+    segments: Vec<Segment>,
+    pauses: Vec<Pause>,
+    last: Option<(Time, Instant)>,
+    /// the last sample the game time advanced at, a pause is measured from it
+    moved: Option<(Time, Instant)>,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameClock {
+    pub fn new() -> Self {
+        Self {
+            anchor: (Instant::now(), SystemTime::now()),
+            segments: Vec::new(),
+            pauses: Vec::new(),
+            last: None,
+            moved: None,
+        }
+    }
+
+    /// Sample the game time of the client, timestamped halfway through the request
+    pub async fn sample<C: IngameApi>(
+        &mut self,
+        client: &C,
+    ) -> Result<Option<ClockChange>, IngameClientError> {
+        let before = Instant::now();
+        let game_stats = client.game_stats().await?;
+        let at = before + before.elapsed() / 2;
+        Ok(self.push(game_stats.game_time, at))
+    }
+
+    /// Add a sample of the game time taken at `at` \
+    /// Samples that aren't newer than the last one are ignored
+    pub fn push(&mut self, game_time: Time, at: Instant) -> Option<ClockChange> {
+        let Some((last_game_time, last_at)) = self.last else {
+            self.start_segment(game_time, at, 1.0);
+            return None;
+        };
+        if at <= last_at {
+            return None;
+        }
+        let elapsed = seconds_between(last_at, at);
+        let advanced = game_time - last_game_time;
+        let rate = self.rate().unwrap_or(1.0);
+
+        if self.is_paused() {
+            if advanced.abs() < PAUSE_RATE * elapsed {
+                self.last = Some((game_time, at));
+                return None;
+            }
+            let resumed = add_seconds(at, -advanced.max(0.0) / rate).unwrap_or(at);
+            if let Some(pause) = self.pauses.last_mut() {
+                pause.ended = Some(resumed.max(last_at));
+            }
+            self.start_segment(game_time, at, rate);
+            return Some(ClockChange::Resumed {
+                game_time: last_game_time,
+            });
+        }
+
+        if advanced.abs() < PAUSE_RATE * elapsed {
+            // samples taken faster than the pause interval only add up to a pause together
+            let (moved_game_time, moved_at) = self.moved.unwrap_or((last_game_time, last_at));
+            let stalled = seconds_between(moved_at, at);
+            let advanced = game_time - moved_game_time;
+            self.last = Some((game_time, at));
+            if stalled >= MIN_PAUSE_INTERVAL && advanced.abs() < PAUSE_RATE * stalled {
+                self.pauses.push(Pause {
+                    game_time,
+                    started: add_seconds(moved_at, advanced.max(0.0) / rate).unwrap_or(moved_at),
+                    ended: None,
+                });
+                return Some(ClockChange::Paused { game_time });
+            }
+            // not fitted, the game may be standing still already
+            return None;
+        }
+
+        let segment = self.segments.last_mut()?;
+        // the second sample of a segment sets its rate, unless the game went back
+        let settled = segment.n >= 2.0 || advanced <= 0.0;
+        if settled && (game_time - segment.game_time_at(at)).abs() > JUMP_TOLERANCE {
+            self.start_segment(game_time, at, rate);
+            return Some(ClockChange::Jumped {
+                from: last_game_time,
+                to: game_time,
+            });
+        }
+        segment.push(game_time, at);
+        self.last = Some((game_time, at));
+        self.moved = self.last;
+        None
+    }
+
+    fn start_segment(&mut self, game_time: Time, at: Instant, rate: f64) {
+        self.segments.push(Segment::new(game_time, at, rate));
+        self.last = Some((game_time, at));
+        self.moved = self.last;
+    }
+
+    /// Game seconds per real second since the last pause or jump, \
+    /// `None` until a second sample arrives
+    pub fn rate(&self) -> Option<f64> {
+        let segment = self.segments.last()?;
+        (segment.n >= 2.0).then(|| segment.fit().0)
+    }
+
+    /// The game time at the creation of the clock, extrapolated with the current [GameClock::rate], \
+    /// i.e. `game_time = offset + rate * seconds since the creation` while the game runs
+    pub fn offset(&self) -> Option<Time> {
+        self.rate()?;
+        self.segments
+            .last()
+            .map(|segment| segment.game_time_at(self.anchor.0))
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.pauses.last().is_some_and(|p| p.ended.is_none())
+    }
+
+    pub fn pauses(&self) -> &[Pause] {
+        &self.pauses
+    }
+
+    /// The game time at an instant, extrapolated from the segment of samples the instant falls into, \
+    /// `None` without samples
+    pub fn game_time_at(&self, instant: Instant) -> Option<Time> {
+        if let Some(pause) = self.pauses.iter().rev().find(|p| p.contains(instant)) {
+            return Some(pause.game_time);
+        }
+        let segment = self
+            .segments
+            .iter()
+            .rev()
+            .find(|s| s.start <= instant)
+            .or(self.segments.first())?;
+        Some(segment.game_time_at(instant))
+    }
+
+    pub fn game_time_at_system_time(&self, system_time: SystemTime) -> Option<Time> {
+        self.game_time_at(self.instant_of_system_time(system_time)?)
+    }
+
+    /// The instant the game was or will be at a game time, e.g. of an event \
+    /// After a seek back the latest time the game passed the game time counts, \
+    /// later game times are extrapolated from the last samples and earlier ones from the first samples \
+    /// `None` for game times skipped by a seek forward
+    pub fn instant_of(&self, game_time: Time) -> Option<Instant> {
+        let passed = self
+            .segments
+            .iter()
+            .rev()
+            .find(|s| s.first_game_time <= game_time && game_time <= s.last_game_time);
+        let segment = passed
+            .or_else(|| {
+                self.segments
+                    .last()
+                    .filter(|s| game_time > s.last_game_time)
+            })
+            .or_else(|| {
+                self.segments
+                    .first()
+                    .filter(|s| game_time < s.first_game_time)
+            })?;
+        segment.instant_of(game_time)
+    }
+
+    pub fn system_time_of(&self, game_time: Time) -> Option<SystemTime> {
+        let (anchor, system_anchor) = self.anchor;
+        let instant = self.instant_of(game_time)?;
+        match instant.checked_duration_since(anchor) {
+            Some(after) => system_anchor.checked_add(after),
+            None => system_anchor.checked_sub(anchor.duration_since(instant)),
+        }
+    }
+
+    fn instant_of_system_time(&self, system_time: SystemTime) -> Option<Instant> {
+        let (anchor, system_anchor) = self.anchor;
+        match system_time.duration_since(system_anchor) {
+            Ok(after) => anchor.checked_add(after),
+            Err(e) => anchor.checked_sub(e.duration()),
+        }
+    }
+}
+
+/// Signed seconds from `from` to `to`
+fn seconds_between(from: Instant, to: Instant) -> f64 {
+    match to.checked_duration_since(from) {
+        Some(after) => after.as_secs_f64(),
+        None => -from.duration_since(to).as_secs_f64(),
+    }
+}
+
+fn add_seconds(instant: Instant, seconds: f64) -> Option<Instant> {
+    let duration = Duration::try_from_secs_f64(seconds.abs()).ok()?;
+    if seconds >= 0.0 {
+        instant.checked_add(duration)
+    } else {
+        instant.checked_sub(duration)
+    }
+}
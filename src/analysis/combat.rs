@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::ingame::*;
+
+/// Stats of a champion at level 1 and their growth per level, e.g. from Data Dragon
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaseStats {
+    pub health: f32,
+    pub health_per_level: f32,
+    pub armor: f32,
+    pub armor_per_level: f32,
+    pub magic_resist: f32,
+    pub magic_resist_per_level: f32,
+}
+
+impl BaseStats {
+    /// The stats at `level`, growing like the stats of all champions do
+    pub fn at_level(&self, level: Level) -> Target {
+        let levels = (level.clamp(1, 18) - 1) as f32;
+        let growth = |per_level: f32| per_level * levels * (0.7025 + 0.0175 * levels);
+        let max_health = self.health + growth(self.health_per_level);
+        Target {
+            max_health,
+            current_health: max_health,
+            armor: self.armor + growth(self.armor_per_level),
+            bonus_armor: 0.0,
+            magic_resist: self.magic_resist + growth(self.magic_resist_per_level),
+            bonus_magic_resist: 0.0,
+        }
+    }
+}
+
+/// The stats of an enemy \
+/// The API doesn't expose the full stats of other players, so they are supplied by the user
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Target {
+    pub max_health: f32,
+    pub current_health: f32,
+    /// the total armor including `bonus_armor`
+    pub armor: f32,
+    pub bonus_armor: f32,
+    /// the total magic resist including `bonus_magic_resist`
+    pub magic_resist: f32,
+    pub bonus_magic_resist: f32,
+}
+
+impl Target {
+    /// Add stats from items and runes
+    pub fn with_bonus(mut self, health: f32, armor: f32, magic_resist: f32) -> Self {
+        self.max_health += health;
+        self.current_health += health;
+        self.armor += armor;
+        self.bonus_armor += armor;
+        self.magic_resist += magic_resist;
+        self.bonus_magic_resist += magic_resist;
+        self
+    }
+
+    pub fn effective_health(&self) -> EffectiveHealth {
+        EffectiveHealth::new(self.current_health, self.armor, self.magic_resist)
+    }
+}
+
+/// The raw damage it takes to deal `health` damage through the resistances
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveHealth {
+    pub physical: f32,
+    pub magic: f32,
+}
+
+impl EffectiveHealth {
+    pub fn new(health: f32, armor: f32, magic_resist: f32) -> Self {
+        Self {
+            physical: health / damage_multiplier(armor),
+            magic: health / damage_multiplier(magic_resist),
+        }
+    }
+}
+
+/// The share of damage taken through armor or magic resist \
+/// Negative resistances increase the damage taken up to twice the damage
+pub fn damage_multiplier(resistance: f32) -> f32 {
+    if resistance >= 0.0 {
+        100.0 / (100.0 + resistance)
+    } else {
+        2.0 - 100.0 / (100.0 - resistance)
+    }
+}
+
+/// Combat calculations of the active player built on its [PlayerChampionStats] \
+/// The percentages of the API are multipliers of the remaining resistance, e.g. 0.6 for 40% penetration
+#[derive(Debug, Clone)]
+pub struct CombatCalculator<'a> {
+    stats: &'a PlayerChampionStats,
+}
+
+impl<'a> CombatCalculator<'a> {
+    pub fn new(stats: &'a PlayerChampionStats) -> Self {
+        Self { stats }
+    }
+
+    pub fn from_active_player(active_player: &'a ActivePlayer) -> Self {
+        Self::new(&active_player.champion_stats)
+    }
+
+    /// The effective health at the current health
+    pub fn effective_health(&self) -> EffectiveHealth {
+        let stats = self.stats;
+        EffectiveHealth::new(stats.current_health, stats.armor, stats.magic_resist)
+    }
+
+    /// The effective health at full health
+    pub fn max_effective_health(&self) -> EffectiveHealth {
+        let stats = self.stats;
+        EffectiveHealth::new(stats.max_health, stats.armor, stats.magic_resist)
+    }
+
+    /// The armor of `target` after our penetration \
+    /// Percent penetration applies before flat penetration and lethality, which can't reduce it below 0
+    pub fn effective_armor(&self, target: &Target) -> f32 {
+        let stats = self.stats;
+        penetrate(
+            target.armor,
+            target.bonus_armor,
+            stats.armor_penetration_percent,
+            stats.bonus_armor_penetration_percent,
+            stats.armor_penetration_flat + stats.physical_lethality,
+        )
+    }
+
+    /// The magic resist of `target` after our penetration, like [Self::effective_armor]
+    pub fn effective_magic_resist(&self, target: &Target) -> f32 {
+        let stats = self.stats;
+        penetrate(
+            target.magic_resist,
+            target.bonus_magic_resist,
+            stats.magic_penetration_percent,
+            stats.bonus_magic_penetration_percent,
+            stats.magic_penetration_flat + stats.magic_lethality,
+        )
+    }
+
+    /// The damage `target` takes from `damage` physical damage
+    pub fn physical_damage(&self, damage: f32, target: &Target) -> f32 {
+        damage * damage_multiplier(self.effective_armor(target))
+    }
+
+    /// The damage `target` takes from `damage` magic damage
+    pub fn magic_damage(&self, damage: f32, target: &Target) -> f32 {
+        damage * damage_multiplier(self.effective_magic_resist(target))
+    }
+
+    /// The average damage of an auto attack before mitigation, including crits \
+    /// `critDamage` is sent in percent, e.g. 175 for crits dealing 175% damage
+    pub fn auto_attack_damage(&self) -> f32 {
+        let stats = self.stats;
+        let crit_chance = stats.crit_chance.clamp(0.0, 1.0);
+        stats.attack_damage * (1.0 + crit_chance * (stats.crit_damage / 100.0 - 1.0))
+    }
+
+    /// The damage per second of auto attacks, against `target` if given
+    pub fn auto_attack_dps(&self, target: Option<&Target>) -> f32 {
+        let damage = self.auto_attack_damage() * self.stats.attack_speed;
+        match target {
+            Some(target) => self.physical_damage(damage, target),
+            None => damage,
+        }
+    }
+
+    /// The seconds it takes to kill `target` with auto attacks from its current health
+    pub fn time_to_kill(&self, target: &Target) -> f32 {
+        target.current_health / self.auto_attack_dps(Some(target))
+    }
+}
+
+fn penetrate(total: f32, bonus: f32, percent: f32, bonus_percent: f32, flat: f32) -> f32 {
+    // penetration doesn't lower negative resistances further
+    if total <= 0.0 {
+        return total;
+    }
+    let bonus = bonus.clamp(0.0, total);
+    let reduced = ((total - bonus) + bonus * bonus_percent) * percent;
+    (reduced - flat).max(0.0)
+}
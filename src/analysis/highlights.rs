@@ -0,0 +1,329 @@
+use serde::{Deserialize, Serialize};
+
+use super::enemy;
+use crate::model::ingame::*;
+
+/// Turrets guarding the nexus, the inhibitor turrets are left out since their names are shared between maps
+const NEXUS_TURRETS: [Turret; 7] = [
+    Turret::Team1C01A,
+    Turret::Team1C02A,
+    Turret::Team1C09A,
+    Turret::Team1C10A,
+    Turret::Team2C01A,
+    Turret::Team2C02A,
+    Turret::Team2L04A,
+];
+
+/// A moment of the game worth a clip \
+/// The times include the padding of the [HighlightDetector]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Highlight {
+    pub start_time: Time,
+    pub end_time: Time,
+    pub kind: HighlightKind,
+    /// the players involved, named like in the events
+    pub players: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum HighlightKind {
+    FirstBlood,
+    Multikill {
+        kill_streak: KillStreak,
+    },
+    Ace {
+        acing_team: TeamId,
+    },
+    StolenObjective {
+        objective: Objective,
+    },
+    /// at least [HighlightDetector::set_teamfight] kills close to each other
+    Teamfight {
+        kills: usize,
+    },
+    /// both teams destroying inhibitors or nexus turrets at the same time
+    BaseRace,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Objective {
+    Dragon,
+    Baron,
+    RiftHerald,
+    Voidgrub,
+}
+
+/// The body of a `POST /replay/playback` request of the replay API, \
+/// seeking to the start of a [Highlight]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaySeek {
+    pub time: Time,
+    pub paused: bool,
+}
+
+impl Highlight {
+    /// The request to seek the replay to the start of the highlight and play it
+    pub fn seek(&self) -> ReplaySeek {
+        ReplaySeek {
+            time: self.start_time,
+            paused: false,
+        }
+    }
+
+    pub fn duration(&self) -> Time {
+        self.end_time - self.start_time
+    }
+}
+
+/// Scans the [GameEvent]s for highlights
+#[derive(Debug, Clone)]
+pub struct HighlightDetector {
+    padding_before: Time,
+    padding_after: Time,
+    teamfight_gap: Time,
+    teamfight_kills: usize,
+    base_race_window: Time,
+}
+
+impl Default for HighlightDetector {
+    fn default() -> Self {
+        Self {
+            padding_before: 10.0,
+            padding_after: 5.0,
+            teamfight_gap: 10.0,
+            teamfight_kills: 3,
+            base_race_window: 30.0,
+        }
+    }
+}
+
+impl HighlightDetector {
+    /// A detector with 10 seconds of padding before and 5 seconds after every highlight
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the seconds added before and after every highlight
+    pub fn set_padding(&mut self, before: Time, after: Time) {
+        self.padding_before = before;
+        self.padding_after = after;
+    }
+
+    /// Set the seconds between kills that still count as the same fight \
+    /// and the kills a fight needs to be a teamfight, 10 seconds and 3 kills by default
+    pub fn set_teamfight(&mut self, gap: Time, kills: usize) {
+        self.teamfight_gap = gap;
+        self.teamfight_kills = kills;
+    }
+
+    /// Set the seconds in which both teams have to destroy base structures for a base race, 30 by default
+    pub fn set_base_race_window(&mut self, window: Time) {
+        self.base_race_window = window;
+    }
+
+    /// All highlights of the events, ordered by their start time \
+    /// Highlights may overlap, e.g. a multikill during a teamfight
+    pub fn detect(&self, events: &[GameEvent]) -> Vec<Highlight> {
+        let kills = events
+            .iter()
+            .filter_map(|e| match e {
+                GameEvent::ChampionKill(kill) => Some(kill),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let fights = kills
+            .chunk_by(|a, b| b.event_time - a.event_time <= self.teamfight_gap)
+            .collect::<Vec<_>>();
+
+        let mut highlights = Vec::new();
+        let mut push = |start: Time, end: Time, kind: HighlightKind, players: Vec<String>| {
+            highlights.push(Highlight {
+                start_time: (start - self.padding_before).max(0.0),
+                end_time: end + self.padding_after,
+                kind,
+                players,
+            })
+        };
+
+        for event in events {
+            match event {
+                GameEvent::FirstBlood(e) => push(
+                    e.event_time,
+                    e.event_time,
+                    HighlightKind::FirstBlood,
+                    vec![e.recipient.clone()],
+                ),
+                GameEvent::Multikill(e) => {
+                    // start with the first kill of the streak
+                    let start = kills
+                        .iter()
+                        .rev()
+                        .skip_while(|k| k.event_time > e.event_time)
+                        .filter(|k| summoner(&k.killer_name) == Some(&e.killer_name))
+                        .take(e.kill_streak.max(1) as usize)
+                        .last()
+                        .map_or(e.event_time, |k| k.event_time);
+                    push(
+                        start,
+                        e.event_time,
+                        HighlightKind::Multikill {
+                            kill_streak: e.kill_streak,
+                        },
+                        vec![e.killer_name.clone()],
+                    )
+                }
+                GameEvent::Ace(e) => {
+                    // start with the fight that led to the ace
+                    let start = fights
+                        .iter()
+                        .find(|fight| {
+                            fight[0].event_time <= e.event_time
+                                && e.event_time - fight[fight.len() - 1].event_time
+                                    <= self.teamfight_gap
+                        })
+                        .map_or(e.event_time, |fight| fight[0].event_time);
+                    push(
+                        start,
+                        e.event_time,
+                        HighlightKind::Ace {
+                            acing_team: e.acing_team,
+                        },
+                        vec![e.acer.clone()],
+                    )
+                }
+                _ => {
+                    let Some((objective, time, killer, assisters)) = stolen_objective(event) else {
+                        continue;
+                    };
+                    push(
+                        time,
+                        time,
+                        HighlightKind::StolenObjective { objective },
+                        players(killer, assisters, None),
+                    )
+                }
+            }
+        }
+
+        for fight in fights.iter().filter(|f| f.len() >= self.teamfight_kills) {
+            let mut involved = Vec::new();
+            for kill in fight.iter() {
+                let victim = Some(&kill.victim_name);
+                add_players(
+                    &mut involved,
+                    players(&kill.killer_name, &kill.assisters, victim),
+                );
+            }
+            push(
+                fight[0].event_time,
+                fight[fight.len() - 1].event_time,
+                HighlightKind::Teamfight { kills: fight.len() },
+                involved,
+            );
+        }
+
+        let destroyed = events
+            .iter()
+            .filter_map(base_structure_destroyed)
+            .collect::<Vec<_>>();
+        for race in destroyed.chunk_by(|a, b| b.0 - a.0 <= self.base_race_window) {
+            if race.iter().all(|(_, team, ..)| *team == race[0].1) {
+                continue;
+            }
+            let mut involved = Vec::new();
+            for (_, _, killer, assisters) in race.iter() {
+                add_players(&mut involved, players(killer, assisters, None));
+            }
+            push(
+                race[0].0,
+                race[race.len() - 1].0,
+                HighlightKind::BaseRace,
+                involved,
+            );
+        }
+
+        highlights.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+        highlights
+    }
+}
+
+/// The objective, time, killer and assisters of a stolen objective
+fn stolen_objective(event: &GameEvent) -> Option<(Objective, Time, &Killer, &[String])> {
+    match event {
+        GameEvent::DragonKill(e) if e.stolen => Some((
+            Objective::Dragon,
+            e.event_time,
+            &e.killer_name,
+            &e.assisters,
+        )),
+        GameEvent::BaronKill(e) if e.stolen => {
+            Some((Objective::Baron, e.event_time, &e.killer_name, &e.assisters))
+        }
+        GameEvent::HeraldKill(e) if e.stolen => Some((
+            Objective::RiftHerald,
+            e.event_time,
+            &e.killer_name,
+            &e.assisters,
+        )),
+        GameEvent::HordeKill(e) if e.stolen => Some((
+            Objective::Voidgrub,
+            e.event_time,
+            &e.killer_name,
+            &e.assisters,
+        )),
+        _ => None,
+    }
+}
+
+/// The time, the destroying team, killer and assisters of a destroyed inhibitor or nexus turret
+fn base_structure_destroyed(event: &GameEvent) -> Option<(Time, TeamId, &Killer, &[String])> {
+    let (time, owner, killer, assisters) = match event {
+        GameEvent::InhibKilled(e) => (
+            e.event_time,
+            e.inhib_killed.team(),
+            &e.killer_name,
+            &e.assisters,
+        ),
+        GameEvent::TurretKilled(e) if NEXUS_TURRETS.contains(&e.turret_killed) => (
+            e.event_time,
+            e.turret_killed.team(),
+            &e.killer_name,
+            &e.assisters,
+        ),
+        _ => return None,
+    };
+    let destroyed_by = enemy(owner?);
+    Some((time, destroyed_by, killer, assisters))
+}
+
+fn summoner(killer: &Killer) -> Option<&String> {
+    match killer {
+        Killer::Summoner(name) => Some(name),
+        _ => None,
+    }
+}
+
+/// The names of the players among the killer, assisters and victim
+fn players(killer: &Killer, assisters: &[String], victim: Option<&String>) -> Vec<String> {
+    summoner(killer)
+        .into_iter()
+        .chain(assisters.iter())
+        .chain(victim)
+        .cloned()
+        .collect()
+}
+
+fn add_players(involved: &mut Vec<String>, players: Vec<String>) {
+    for player in players {
+        if !involved.contains(&player) {
+            involved.push(player);
+        }
+    }
+}
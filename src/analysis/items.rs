@@ -0,0 +1,343 @@
+use serde::Serialize;
+
+use crate::{
+    model::ingame::*,
+    static_data::{ItemData, StaticData},
+};
+
+/// A change to the inventory of a player
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemEvent {
+    /// the time of the sample that first showed the change
+    pub game_time: Time,
+    pub riot_id: String,
+    pub item_id: ItemId,
+    pub item_name: ItemName,
+    pub kind: ItemEventKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum ItemEventKind {
+    /// bought without using any items of the inventory
+    Purchased,
+    /// built from components of the inventory
+    Upgraded { components: Vec<ItemId> },
+    /// a legendary or mythic item was completed
+    PowerSpike {
+        rarity: ItemRarity,
+        components: Vec<ItemId>,
+        /// the completed items in the inventory, including this one
+        completed_items: usize,
+    },
+    /// sold or used up, the components of an upgrade are listed in [ItemEventKind::Upgraded] \
+    /// and [ItemEventKind::PowerSpike] instead
+    Removed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ItemRarity {
+    Legendary,
+    /// only in the seasons with mythic items
+    Mythic,
+}
+
+/// The way from the items of a player to a completed item
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildPath {
+    pub target: ItemId,
+    pub target_name: String,
+    /// the items of the inventory that go into the target
+    pub owned: Vec<ItemId>,
+    /// the components still to buy, split up as far as some of their components are owned
+    pub missing: Vec<ItemId>,
+    pub remaining_gold: Price,
+}
+
+#[derive(Debug, Clone)]
+struct Inventory {
+    riot_id: String,
+    game_time: Time,
+    /// one entry per item, stacks are split up
+    items: Vec<(ItemId, ItemName)>,
+}
+
+/// Tracks the inventories of all players and detects purchases, upgrades and completed items \
+/// The first sample of a player is only recorded, changes are detected from the second one on
+#[derive(Debug, Clone)]
+pub struct ItemTracker<'a> {
+    data: &'a StaticData,
+    inventories: Vec<Inventory>,
+    events: Vec<ItemEvent>,
+}
+
+impl<'a> ItemTracker<'a> {
+    pub fn new(data: &'a StaticData) -> Self {
+        Self {
+            data,
+            inventories: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Add a sample of the items of all players, returns the new events
+    pub fn push(&mut self, data: &AllGameData) -> &[ItemEvent] {
+        let start = self.events.len();
+        for player in data.all_players.iter() {
+            self.update(
+                &player.riot_id.riot_id,
+                data.game_data.game_time,
+                &player.items,
+            );
+        }
+        &self.events[start..]
+    }
+
+    /// Add a sample of the items of one player, e.g. from [IngameClient::player_items](crate::ingame::IngameClient::player_items), \
+    /// returns the new events
+    pub fn push_player_items(
+        &mut self,
+        riot_id: &str,
+        game_time: Time,
+        items: &[PlayerItem],
+    ) -> &[ItemEvent] {
+        let start = self.events.len();
+        self.update(riot_id, game_time, items);
+        &self.events[start..]
+    }
+
+    /// All events so far, ordered by the samples they were detected in
+    pub fn events(&self) -> &[ItemEvent] {
+        &self.events
+    }
+
+    pub fn power_spikes(&self) -> impl Iterator<Item = &ItemEvent> {
+        self.events
+            .iter()
+            .filter(|e| matches!(e.kind, ItemEventKind::PowerSpike { .. }))
+    }
+
+    /// The current items of a player, with Arena copies resolved to the regular items
+    pub fn items(&self, riot_id: &str) -> Option<Vec<ItemId>> {
+        self.inventory(riot_id)
+            .map(|inventory| inventory.items.iter().map(|(id, _)| *id).collect())
+    }
+
+    /// The completed items the current items of a player build into, the cheapest to finish first
+    pub fn build_paths(&self, riot_id: &str) -> Vec<BuildPath> {
+        let Some(inventory) = self.inventory(riot_id) else {
+            return Vec::new();
+        };
+        let owned = inventory
+            .items
+            .iter()
+            .map(|(id, _)| *id)
+            .filter(|id| self.data.item(*id).is_some_and(|i| !is_final(self.data, i)))
+            .collect::<Vec<_>>();
+
+        let mut targets = Vec::new();
+        let mut next = owned.clone();
+        while let Some(id) = next.pop() {
+            for item in self.data.builds_into(id) {
+                if targets.contains(&item.id) || next.contains(&item.id) {
+                    continue;
+                }
+                if completed(self.data, item).is_some() {
+                    targets.push(item.id);
+                } else {
+                    next.push(item.id);
+                }
+            }
+        }
+
+        let mut paths = targets
+            .into_iter()
+            .filter_map(|target| self.build_path(target, owned.clone()))
+            .collect::<Vec<_>>();
+        paths.sort_by_key(|p| (p.remaining_gold, p.target));
+        paths
+    }
+
+    fn build_path(&self, target: ItemId, mut available: Vec<ItemId>) -> Option<BuildPath> {
+        let item = self.data.item(target)?;
+        let mut owned = Vec::new();
+        let missing = item
+            .from
+            .iter()
+            .flat_map(|id| self.missing_components(*id, &mut available, &mut owned))
+            .collect();
+        if owned.is_empty() {
+            return None;
+        }
+        let owned_gold = owned
+            .iter()
+            .filter_map(|id| self.data.item(*id))
+            .map(|i| i.gold.total)
+            .sum::<Price>();
+        Some(BuildPath {
+            target,
+            target_name: item.name.clone(),
+            owned,
+            missing,
+            remaining_gold: item.gold.total - owned_gold,
+        })
+    }
+
+    /// The parts of `id` that aren't `available`, moving the used items to `owned`
+    fn missing_components(
+        &self,
+        id: ItemId,
+        available: &mut Vec<ItemId>,
+        owned: &mut Vec<ItemId>,
+    ) -> Vec<ItemId> {
+        if let Some(index) = available.iter().position(|a| *a == id) {
+            owned.push(available.remove(index));
+            return Vec::new();
+        }
+        let owned_before = owned.len();
+        let components = self.data.item(id).map_or(Vec::new(), |i| i.from.clone());
+        let missing = components
+            .iter()
+            .flat_map(|c| self.missing_components(*c, available, owned))
+            .collect();
+        if owned.len() == owned_before {
+            vec![id]
+        } else {
+            missing
+        }
+    }
+
+    fn inventory(&self, riot_id: &str) -> Option<&Inventory> {
+        self.inventories.iter().find(|i| i.riot_id == riot_id)
+    }
+
+    fn update(&mut self, riot_id: &str, game_time: Time, items: &[PlayerItem]) {
+        let items = items
+            .iter()
+            .flat_map(|item| {
+                let id = self.data.item(item.item_id).map_or(item.item_id, |i| i.id);
+                (0..item.count.max(1)).map(move |_| (id, item.display_name.clone()))
+            })
+            .collect::<Vec<_>>();
+
+        let Some(inventory) = self.inventories.iter_mut().find(|i| i.riot_id == riot_id) else {
+            self.inventories.push(Inventory {
+                riot_id: riot_id.to_string(),
+                game_time,
+                items,
+            });
+            return;
+        };
+        if inventory.game_time >= game_time {
+            return;
+        }
+
+        let mut removed = inventory.items.clone();
+        let mut added = Vec::new();
+        for item in items.iter() {
+            match removed.iter().position(|(id, _)| *id == item.0) {
+                Some(index) => {
+                    removed.remove(index);
+                }
+                None => added.push(item.clone()),
+            }
+        }
+        let completed_items = items
+            .iter()
+            .filter(|(id, _)| {
+                self.data
+                    .item(*id)
+                    .is_some_and(|i| completed(self.data, i).is_some())
+            })
+            .count();
+
+        for (id, name) in added {
+            let mut components = Vec::new();
+            if let Some(item) = self.data.item(id) {
+                for component in self.data.components(id) {
+                    if let Some(index) = removed.iter().position(|(id, _)| *id == component.id) {
+                        components.push(removed.remove(index).0);
+                    }
+                }
+                // e.g. Manamune turning into Muramana doesn't complete another item
+                let upgrades_completed = components.iter().any(|c| {
+                    self.data
+                        .item(*c)
+                        .is_some_and(|c| completed(self.data, c).is_some())
+                });
+                if let Some(rarity) = completed(self.data, item).filter(|_| !upgrades_completed) {
+                    self.events.push(ItemEvent {
+                        game_time,
+                        riot_id: riot_id.to_string(),
+                        item_id: id,
+                        item_name: name,
+                        kind: ItemEventKind::PowerSpike {
+                            rarity,
+                            components,
+                            completed_items,
+                        },
+                    });
+                    continue;
+                }
+            }
+            let kind = if components.is_empty() {
+                ItemEventKind::Purchased
+            } else {
+                ItemEventKind::Upgraded { components }
+            };
+            self.events.push(ItemEvent {
+                game_time,
+                riot_id: riot_id.to_string(),
+                item_id: id,
+                item_name: name,
+                kind,
+            });
+        }
+        for (id, name) in removed {
+            self.events.push(ItemEvent {
+                game_time,
+                riot_id: riot_id.to_string(),
+                item_id: id,
+                item_name: name,
+                kind: ItemEventKind::Removed,
+            });
+        }
+
+        inventory.game_time = game_time;
+        inventory.items = items;
+    }
+}
+
+/// The rarity of a completed item, i.e. an item built from components that doesn't build into anything buyable \
+/// Boots, consumables and trinkets aren't counted
+pub fn completed(data: &StaticData, item: &ItemData) -> Option<ItemRarity> {
+    let excluded = ["Boots", "Consumable", "Trinket"];
+    if item.from.is_empty() || item.tags.iter().any(|t| excluded.contains(&t.as_str())) {
+        return None;
+    }
+    if is_final(data, item) {
+        let mythic = item.description.contains("rarityMythic");
+        Some(if mythic {
+            ItemRarity::Mythic
+        } else {
+            ItemRarity::Legendary
+        })
+    } else {
+        None
+    }
+}
+
+/// Whether `item` doesn't build into any item that can be bought
+fn is_final(data: &StaticData, item: &ItemData) -> bool {
+    !item
+        .into
+        .iter()
+        .filter_map(|id| data.item(*id))
+        .any(|i| i.gold.purchasable)
+}
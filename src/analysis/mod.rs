@@ -0,0 +1,72 @@
+use crate::model::ingame::{Killer, Player, TeamId};
+
+/// The subteams, rounds and standings of an Arena game
+pub mod arena;
+/// Game time synchronized with the real time, see [GameClock](clock::GameClock)
+pub mod clock;
+/// Effective health, damage after penetration and auto attack DPS of the active player
+pub mod combat;
+/// Clip markers for highlights like multikills, steals and teamfights
+pub mod highlights;
+/// Item purchases, upgrades and power spikes resolved with the [StaticData](crate::static_data::StaticData)
+pub mod items;
+/// Deaths, predicted respawns and numbers advantages of the two teams
+pub mod respawn;
+/// Destroyed and standing turrets and inhibitors per lane, see [StructureStatus](structures::StructureStatus)
+pub mod structures;
+/// The result, scoreboard and objectives of a finished game as JSON or Markdown
+pub mod summary;
+/// Team-level aggregates of a game
+pub mod team;
+/// The performance of every player over time, exportable as CSV or JSON
+pub mod timeline;
+
+/// Find the player an event refers to \
+/// Events name players by their riot id game name, older games by their summoner name
+pub(crate) fn find_player<'a>(players: &'a [Player], name: &str) -> Option<&'a Player> {
+    player_index(players, name).map(|index| &players[index])
+}
+
+/// The index of the player an event refers to, see [find_player]
+pub(crate) fn player_index(players: &[Player], name: &str) -> Option<usize> {
+    players.iter().position(|p| {
+        p.riot_id.game_name == name || p.riot_id.riot_id == name || p.summoner_name == name
+    })
+}
+
+/// The player behind a [Killer], if the killer is a player
+pub(crate) fn find_killer<'a>(players: &'a [Player], killer: &Killer) -> Option<&'a Player> {
+    match killer {
+        Killer::Summoner(name) => find_player(players, name),
+        _ => None,
+    }
+}
+
+/// The team of the killing player, else of the first assisting player, else of the killing minion
+pub(crate) fn team_of_killer(
+    players: &[Player],
+    killer: &Killer,
+    assisters: &[String],
+) -> Option<TeamId> {
+    find_killer(players, killer)
+        .or_else(|| assisters.iter().find_map(|a| find_player(players, a)))
+        .map(|p| p.team)
+        .or_else(|| minion_team(killer))
+}
+
+/// Minions are named `Minion_T{100|200}...` by their team
+fn minion_team(killer: &Killer) -> Option<TeamId> {
+    match killer {
+        Killer::Minion(name) if name.starts_with("Minion_T100") => Some(TeamId::Order),
+        Killer::Minion(name) if name.starts_with("Minion_T200") => Some(TeamId::Chaos),
+        _ => None,
+    }
+}
+
+pub(crate) fn enemy(team: TeamId) -> TeamId {
+    match team {
+        TeamId::Order => TeamId::Chaos,
+        TeamId::Chaos => TeamId::Order,
+        other => other,
+    }
+}
@@ -0,0 +1,244 @@
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+use super::player_index;
+use crate::model::ingame::*;
+
+/// A death of a player with its predicted respawn
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeathRecord {
+    pub riot_id: String,
+    pub champion_name: ChampionName,
+    pub team: TeamId,
+    /// the time of the kill event, or of the first sample showing the player dead
+    pub time: Time,
+    /// `game_time + respawn_timer` of the latest sample showing the player dead
+    pub respawn_at: Time,
+}
+
+/// The alive and dead players of a team
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamStatus {
+    pub team: TeamId,
+    pub alive: Vec<String>,
+    /// ordered by their respawn, the first to respawn first
+    pub dead: Vec<RespawningPlayer>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RespawningPlayer {
+    pub riot_id: String,
+    pub champion_name: ChampionName,
+    pub respawn_at: Time,
+}
+
+/// One team having more players alive than the other, e.g. `5v3 for the next 12s`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NumbersAdvantage {
+    pub time: Time,
+    /// the team with more players alive
+    pub team: TeamId,
+    pub players: usize,
+    pub opponents: usize,
+    /// the next respawn of any dead player, which ends or changes the advantage
+    pub until: Time,
+}
+
+impl NumbersAdvantage {
+    pub fn duration(&self) -> Time {
+        self.until - self.time
+    }
+}
+
+impl Display for NumbersAdvantage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}v{} for the next {:.0}s",
+            self.players,
+            self.opponents,
+            self.duration()
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PlayerState {
+    riot_id: String,
+    champion_name: ChampionName,
+    team: TeamId,
+    respawn_at: Option<Time>,
+}
+
+/// Turns the `is_dead` and `respawn_timer` of repeated [AllGameData] samples into deaths, \
+/// predicted respawn times and numbers advantages of the two teams
+#[derive(Debug, Clone, Default)]
+pub struct RespawnTracker {
+    game_time: Option<Time>,
+    last_event_id: Option<EventId>,
+    players: Vec<PlayerState>,
+    deaths: Vec<DeathRecord>,
+    advantages: Vec<NumbersAdvantage>,
+    alive: Option<(usize, usize)>,
+}
+
+impl RespawnTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a sample of the game, returns the numbers advantage if one started or changed \
+    /// Samples that aren't newer than the last one are ignored
+    pub fn push(&mut self, data: &AllGameData) -> Option<&NumbersAdvantage> {
+        let game_time = data.game_data.game_time;
+        if self.game_time.is_some_and(|t| t >= game_time) {
+            return None;
+        }
+        self.game_time = Some(game_time);
+
+        let kills = data
+            .events
+            .iter()
+            .filter(|e| self.last_event_id.is_none_or(|id| e.get_event_id() > id))
+            .filter_map(|e| match e {
+                GameEvent::ChampionKill(kill) => Some(kill),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if let Some(event) = data.events.last() {
+            self.last_event_id = Some(event.get_event_id());
+        }
+
+        for (index, player) in data.all_players.iter().enumerate() {
+            let riot_id = &player.riot_id.riot_id;
+            let state = match self.players.iter().position(|p| &p.riot_id == riot_id) {
+                Some(state) => &mut self.players[state],
+                None => {
+                    self.players.push(PlayerState {
+                        riot_id: riot_id.clone(),
+                        champion_name: player.champion_name.clone(),
+                        team: player.team,
+                        respawn_at: None,
+                    });
+                    self.players.last_mut().unwrap()
+                }
+            };
+            if !player.is_dead {
+                state.respawn_at = None;
+                continue;
+            }
+
+            let respawn_at = game_time + player.respawn_timer;
+            let kill_time = kills
+                .iter()
+                .rev()
+                .find(|k| player_index(&data.all_players, &k.victim_name) == Some(index))
+                .map(|k| k.event_time);
+            // a kill after the last predicted respawn is a new death between the samples
+            let new_death = state
+                .respawn_at
+                .is_none_or(|previous| kill_time.is_some_and(|t| t >= previous));
+            if new_death {
+                self.deaths.push(DeathRecord {
+                    riot_id: riot_id.clone(),
+                    champion_name: player.champion_name.clone(),
+                    team: player.team,
+                    time: kill_time.unwrap_or(game_time),
+                    respawn_at,
+                });
+            } else if let Some(death) = self.deaths.iter_mut().rev().find(|d| &d.riot_id == riot_id)
+            {
+                // the latest sample predicts the respawn best
+                death.respawn_at = respawn_at;
+            }
+            state.respawn_at = Some(respawn_at);
+        }
+
+        let alive = (self.alive(TeamId::Order), self.alive(TeamId::Chaos));
+        if self.alive == Some(alive) {
+            return None;
+        }
+        self.alive = Some(alive);
+        let (team, players, opponents) = match alive {
+            (order, chaos) if order > chaos => (TeamId::Order, order, chaos),
+            (order, chaos) if chaos > order => (TeamId::Chaos, chaos, order),
+            _ => return None,
+        };
+        let until = self
+            .players
+            .iter()
+            .filter_map(|p| p.respawn_at)
+            .min_by(|a, b| a.total_cmp(b))
+            .unwrap_or(game_time);
+        self.advantages.push(NumbersAdvantage {
+            time: game_time,
+            team,
+            players,
+            opponents,
+            until,
+        });
+        self.advantages.last()
+    }
+
+    /// All deaths so far
+    pub fn deaths(&self) -> &[DeathRecord] {
+        &self.deaths
+    }
+
+    /// All numbers advantages so far
+    pub fn advantages(&self) -> &[NumbersAdvantage] {
+        &self.advantages
+    }
+
+    /// The predicted respawn of a dead player, `None` while the player is alive
+    pub fn respawn_at(&self, riot_id: &str) -> Option<Time> {
+        self.players
+            .iter()
+            .find(|p| p.riot_id == riot_id)
+            .and_then(|p| p.respawn_at)
+    }
+
+    /// The players of `team` alive at the last sample
+    pub fn alive(&self, team: TeamId) -> usize {
+        self.players
+            .iter()
+            .filter(|p| p.team == team && p.respawn_at.is_none())
+            .count()
+    }
+
+    /// The players of `team` alive at `time` if nobody dies until then
+    pub fn alive_at(&self, team: TeamId, time: Time) -> usize {
+        self.players
+            .iter()
+            .filter(|p| p.team == team && p.respawn_at.is_none_or(|t| t <= time))
+            .count()
+    }
+
+    pub fn team_status(&self, team: TeamId) -> TeamStatus {
+        let players = self.players.iter().filter(|p| p.team == team);
+        let mut dead = players
+            .clone()
+            .filter_map(|p| {
+                Some(RespawningPlayer {
+                    riot_id: p.riot_id.clone(),
+                    champion_name: p.champion_name.clone(),
+                    respawn_at: p.respawn_at?,
+                })
+            })
+            .collect::<Vec<_>>();
+        dead.sort_by(|a, b| a.respawn_at.total_cmp(&b.respawn_at));
+        TeamStatus {
+            team,
+            alive: players
+                .filter(|p| p.respawn_at.is_none())
+                .map(|p| p.riot_id.clone())
+                .collect(),
+            dead,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use serde::Serialize;
+
+use crate::model::{
+    ingame::*,
+    map::{lanes, Lane},
+};
+
+/// The turrets and the inhibitor of one lane of a team
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaneStructures {
+    pub team: TeamId,
+    pub lane: Lane,
+    /// the standing turrets from the outermost
+    pub turrets: Vec<Turret>,
+    pub destroyed_turrets: usize,
+    pub inhibitor_up: bool,
+}
+
+/// The destroyed turrets and inhibitors of a game on Summoner's Rift or Howling Abyss, \
+/// from the [GameEvent::TurretKilled], [GameEvent::InhibKilled] and [GameEvent::InhibRespawned] events
+#[derive(Debug, Clone)]
+pub struct StructureStatus {
+    map: MapName,
+    destroyed_turrets: Vec<Turret>,
+    inhibitors_down: Vec<Inhibitor>,
+}
+
+impl StructureStatus {
+    pub fn new(map: MapName, events: &[GameEvent]) -> Self {
+        let mut status = Self {
+            map,
+            destroyed_turrets: Vec::new(),
+            inhibitors_down: Vec::new(),
+        };
+        for event in events {
+            match event {
+                GameEvent::TurretKilled(e)
+                    if !status.destroyed_turrets.contains(&e.turret_killed) =>
+                {
+                    status.destroyed_turrets.push(e.turret_killed)
+                }
+                GameEvent::InhibKilled(e) if !status.inhibitors_down.contains(&e.inhib_killed) => {
+                    status.inhibitors_down.push(e.inhib_killed)
+                }
+                GameEvent::InhibRespawned(e) => {
+                    status.inhibitors_down.retain(|i| *i != e.inhib_respawned)
+                }
+                _ => {}
+            }
+        }
+        status
+    }
+
+    pub fn from_game_data(data: &AllGameData) -> Self {
+        Self::new(data.game_data.map_name.clone(), &data.events)
+    }
+
+    /// In the order they were destroyed
+    pub fn destroyed_turrets(&self) -> &[Turret] {
+        &self.destroyed_turrets
+    }
+
+    /// The standing turrets of a team, including the nexus and fountain turrets
+    pub fn remaining_turrets(&self, team: TeamId) -> Vec<Turret> {
+        Turret::on_map(&self.map)
+            .filter(|t| t.team() == Some(team) && !self.destroyed_turrets.contains(t))
+            .collect()
+    }
+
+    pub fn inhibitors_down(&self, team: TeamId) -> Vec<Inhibitor> {
+        Inhibitor::on_map(&self.map)
+            .filter(|i| i.team() == Some(team) && self.inhibitors_down.contains(i))
+            .collect()
+    }
+
+    /// The structures of every lane of a team, e.g. the remaining turrets per lane
+    pub fn lanes(&self, team: TeamId) -> Vec<LaneStructures> {
+        lanes(&self.map)
+            .iter()
+            .map(|lane| {
+                let (destroyed, standing): (Vec<_>, Vec<_>) = Turret::on_map(&self.map)
+                    .filter(|t| t.team() == Some(team) && t.lane(&self.map) == Some(*lane))
+                    .partition(|t| self.destroyed_turrets.contains(t));
+                let inhibitor_up = Inhibitor::on_map(&self.map)
+                    .filter(|i| i.team() == Some(team) && i.lane(&self.map) == Some(*lane))
+                    .all(|i| !self.inhibitors_down.contains(&i));
+                LaneStructures {
+                    team,
+                    lane: *lane,
+                    turrets: standing,
+                    destroyed_turrets: destroyed.len(),
+                    inhibitor_up,
+                }
+            })
+            .collect()
+    }
+
+    /// The outermost standing turret of a lane, the next one the enemies have to take
+    pub fn next_turret(&self, team: TeamId, lane: Lane) -> Option<Turret> {
+        Turret::on_map(&self.map).find(|t| {
+            t.team() == Some(team)
+                && t.lane(&self.map) == Some(lane)
+                && !self.destroyed_turrets.contains(t)
+        })
+    }
+}
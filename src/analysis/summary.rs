@@ -0,0 +1,373 @@
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use super::{enemy, find_player, team::TeamSummary, team_of_killer};
+use crate::model::ingame::*;
+
+/// Everything worth posting about a finished game, built from the last [AllGameData] \
+/// and the full event history, e.g. when an [EventStream](crate::ingame::EventStream) yields the [GameEvent::GameEnd]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameSummary {
+    pub game_mode: GameMode,
+    pub map_name: MapName,
+    /// the time of the [GameEvent::GameEnd], the game time of the data if the game hasn't ended
+    pub game_length: Time,
+    pub teams: Vec<TeamOutcome>,
+    /// the final scoreboard with the items, runes and summoner spells of every player
+    pub players: Vec<Player>,
+    /// all objectives in the order they were taken
+    pub objectives: Vec<ObjectiveKill>,
+    pub first_blood: Option<FirstBlood>,
+    pub multikills: Vec<Multikill>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamOutcome {
+    pub team: TeamId,
+    /// known from the [GameEvent::GameEnd], which is sent to the active player only, `None` while spectating
+    pub result: Option<GameResult>,
+    pub summary: TeamSummary,
+}
+
+/// A dragon, voidgrub, herald, baron, turret or inhibitor taken by a team
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectiveKill {
+    pub time: Time,
+    /// the team that took the objective
+    pub team: Option<TeamId>,
+    pub objective: ObjectiveKind,
+    /// the riot id of the killing player, the name of the killer otherwise
+    pub killer: String,
+    pub stolen: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum ObjectiveKind {
+    Dragon { dragon_type: DragonType },
+    Voidgrub,
+    RiftHerald,
+    Baron,
+    Turret { turret: Turret },
+    Inhibitor { inhibitor: Inhibitor },
+}
+
+impl GameSummary {
+    /// Summarize a game from its last data and all of its events, \
+    /// the events of `data` are ignored since they may have been fetched since an event id
+    pub fn new(data: &AllGameData, events: &[GameEvent]) -> Self {
+        let mut data = data.clone();
+        data.events = events.to_vec();
+        let players = &data.all_players;
+
+        let game_end = events.iter().find_map(|e| match e {
+            GameEvent::GameEnd(e) => Some(e),
+            _ => None,
+        });
+        // the result is sent from the perspective of the active player
+        let winner = game_end.and_then(|end| {
+            let active_player = data.active_player.as_ref()?;
+            let team = find_player(players, &active_player.riot_id.riot_id)?.team;
+            Some(match end.result {
+                GameResult::Win => team,
+                GameResult::Lose => enemy(team),
+            })
+        });
+        let teams = [TeamId::Order, TeamId::Chaos]
+            .into_iter()
+            .map(|team| TeamOutcome {
+                team,
+                result: winner.map(|winner| {
+                    if winner == team {
+                        GameResult::Win
+                    } else {
+                        GameResult::Lose
+                    }
+                }),
+                summary: TeamSummary::new(&data, team),
+            })
+            .collect();
+
+        Self {
+            game_mode: data.game_data.game_mode.clone(),
+            map_name: data.game_data.map_name.clone(),
+            game_length: game_end.map_or(data.game_data.game_time, |end| end.event_time),
+            teams,
+            objectives: events
+                .iter()
+                .filter_map(|e| objective_kill(players, e))
+                .collect(),
+            first_blood: events.iter().find_map(|e| match e {
+                GameEvent::FirstBlood(e) => Some(e.clone()),
+                _ => None,
+            }),
+            multikills: events
+                .iter()
+                .filter_map(|e| match e {
+                    GameEvent::Multikill(e) => Some(e.clone()),
+                    _ => None,
+                })
+                .collect(),
+            players: data.all_players,
+        }
+    }
+
+    /// Summarize a game from data including all events
+    pub fn from_game_data(data: &AllGameData) -> Self {
+        Self::new(data, &data.events)
+    }
+
+    pub fn write_json<W: Write>(&self, writer: W) -> io::Result<()> {
+        serde_json::to_writer_pretty(writer, self).map_err(io::Error::from)
+    }
+
+    /// Write the summary as Markdown tables and lists for posting to a chat
+    pub fn write_markdown<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(
+            writer,
+            "## {} on {} ({})\n",
+            self.game_mode,
+            self.map_name,
+            minutes(self.game_length)
+        )?;
+
+        writeln!(
+            writer,
+            "| Team | Result | K / D / A | CS | Item value | Dragons | Barons | Turrets | Inhibitors |"
+        )?;
+        writeln!(writer, "|---|---|---|---|---|---|---|---|---|")?;
+        for outcome in self.teams.iter() {
+            let s = &outcome.summary;
+            writeln!(
+                writer,
+                "| {:?} | {} | {} / {} / {} | {} | {} | {} | {} | {} | {} |",
+                outcome.team,
+                outcome.result.map_or("-".to_string(), |r| r.to_string()),
+                s.kills,
+                s.deaths,
+                s.assists,
+                s.creep_score,
+                s.item_value,
+                s.objectives.dragons.len(),
+                s.objectives.barons,
+                s.objectives.turrets,
+                s.objectives.inhibitors,
+            )?;
+        }
+
+        writeln!(writer, "\n### Scoreboard\n")?;
+        writeln!(
+            writer,
+            "| Player | Champion | Team | Level | K / D / A | CS | Runes | Summoner spells | Items |"
+        )?;
+        writeln!(writer, "|---|---|---|---|---|---|---|---|---|")?;
+        for p in self.players.iter() {
+            let runes = p.runes.as_ref().map_or(String::new(), |r| {
+                format!(
+                    "{} ({} / {})",
+                    r.keystone.display_name,
+                    r.primary_rune_tree.display_name,
+                    r.secondary_rune_tree.display_name
+                )
+            });
+            let summoner_spells = match &p.summoner_spells {
+                SummonerSpells::SummonerSpells {
+                    summoner_spell_one,
+                    summoner_spell_two,
+                } => format!(
+                    "{}, {}",
+                    summoner_spell_one.display_name, summoner_spell_two.display_name
+                ),
+                SummonerSpells::NoSummonerSpells {} => String::new(),
+            };
+            let items = p
+                .items
+                .iter()
+                .map(|i| match i.count {
+                    1 => i.display_name.clone(),
+                    count => format!("{} x{count}", i.display_name),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                writer,
+                "| {} | {} | {:?} | {} | {} / {} / {} | {} | {} | {} | {} |",
+                markdown_cell(&p.riot_id.riot_id),
+                markdown_cell(&p.champion_name),
+                p.team,
+                p.level,
+                p.scores.kills,
+                p.scores.deaths,
+                p.scores.assists,
+                p.scores.creep_score,
+                markdown_cell(&runes),
+                markdown_cell(&summoner_spells),
+                markdown_cell(&items),
+            )?;
+        }
+
+        if !self.objectives.is_empty() {
+            writeln!(writer, "\n### Objectives\n")?;
+            for o in self.objectives.iter() {
+                let objective = match &o.objective {
+                    ObjectiveKind::Dragon { dragon_type } => format!("{dragon_type} Dragon"),
+                    ObjectiveKind::Voidgrub => "Voidgrub".to_string(),
+                    ObjectiveKind::RiftHerald => "Rift Herald".to_string(),
+                    ObjectiveKind::Baron => "Baron".to_string(),
+                    ObjectiveKind::Turret { turret } => {
+                        match (turret.lane(&self.map_name), turret.tier(&self.map_name)) {
+                            (Some(lane), Some(tier)) => format!("{lane} {tier} Turret"),
+                            (None, Some(tier)) => format!("{tier} Turret"),
+                            _ => format!("Turret {turret}"),
+                        }
+                    }
+                    ObjectiveKind::Inhibitor { inhibitor } => {
+                        match inhibitor.lane(&self.map_name) {
+                            Some(lane) => format!("{lane} Inhibitor"),
+                            None => format!("Inhibitor {inhibitor}"),
+                        }
+                    }
+                };
+                let team = o.team.map_or("-".to_string(), |t| format!("{t:?}"));
+                let stolen = if o.stolen { ", stolen" } else { "" };
+                writeln!(
+                    writer,
+                    "- {} {team}: {objective} by {}{stolen}",
+                    minutes(o.time),
+                    o.killer
+                )?;
+            }
+        }
+
+        if let Some(first_blood) = &self.first_blood {
+            writeln!(
+                writer,
+                "\n**First blood**: {} at {}",
+                first_blood.recipient,
+                minutes(first_blood.event_time)
+            )?;
+        }
+
+        if !self.multikills.is_empty() {
+            writeln!(writer, "\n### Multikills\n")?;
+            for m in self.multikills.iter() {
+                let kind = match m.kill_streak {
+                    2 => "Double kill".to_string(),
+                    3 => "Triple kill".to_string(),
+                    4 => "Quadra kill".to_string(),
+                    5 => "Penta kill".to_string(),
+                    streak => format!("{streak} kills"),
+                };
+                writeln!(
+                    writer,
+                    "- {} {}: {kind}",
+                    minutes(m.event_time),
+                    m.killer_name
+                )?;
+            }
+        }
+        writer.flush()
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = Vec::new();
+        self.write_markdown(&mut markdown)
+            .expect("writing to a Vec doesn't fail");
+        String::from_utf8(markdown).expect("the summary is written as UTF-8")
+    }
+}
+
+fn objective_kill(players: &[Player], event: &GameEvent) -> Option<ObjectiveKill> {
+    let (time, objective, killer, assisters, stolen) = match event {
+        GameEvent::DragonKill(e) => (
+            e.event_time,
+            ObjectiveKind::Dragon {
+                dragon_type: e.dragon_type.clone(),
+            },
+            &e.killer_name,
+            &e.assisters[..],
+            e.stolen,
+        ),
+        GameEvent::HordeKill(e) => (
+            e.event_time,
+            ObjectiveKind::Voidgrub,
+            &e.killer_name,
+            &e.assisters[..],
+            e.stolen,
+        ),
+        GameEvent::HeraldKill(e) => (
+            e.event_time,
+            ObjectiveKind::RiftHerald,
+            &e.killer_name,
+            &e.assisters[..],
+            e.stolen,
+        ),
+        GameEvent::BaronKill(e) => (
+            e.event_time,
+            ObjectiveKind::Baron,
+            &e.killer_name,
+            &e.assisters[..],
+            e.stolen,
+        ),
+        GameEvent::TurretKilled(e) => (
+            e.event_time,
+            ObjectiveKind::Turret {
+                turret: e.turret_killed,
+            },
+            &e.killer_name,
+            &e.assisters[..],
+            false,
+        ),
+        GameEvent::InhibKilled(e) => (
+            e.event_time,
+            ObjectiveKind::Inhibitor {
+                inhibitor: e.inhib_killed,
+            },
+            &e.killer_name,
+            &e.assisters[..],
+            false,
+        ),
+        _ => return None,
+    };
+    // structures are taken by the enemies of their team, whoever killed them
+    let structure_team = match &objective {
+        ObjectiveKind::Turret { turret } => turret.team(),
+        ObjectiveKind::Inhibitor { inhibitor } => inhibitor.team(),
+        _ => None,
+    };
+    let team = structure_team
+        .map(enemy)
+        .or_else(|| team_of_killer(players, killer, assisters));
+    let killer = match killer {
+        Killer::Summoner(name) => {
+            find_player(players, name).map_or(name.clone(), |p| p.riot_id.riot_id.clone())
+        }
+        killer => String::from(killer.clone()),
+    };
+    Some(ObjectiveKill {
+        time,
+        team,
+        objective,
+        killer,
+        stolen,
+    })
+}
+
+/// A game time like `12:05`
+fn minutes(time: Time) -> String {
+    let seconds = time.max(0.0) as u64;
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Escape the pipes that would end a table cell
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
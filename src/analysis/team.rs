@@ -0,0 +1,165 @@
+use serde::Serialize;
+
+use super::{enemy, find_player, team_of_killer};
+use crate::model::ingame::*;
+
+/// Aggregated stats of all players of a team and the objectives the team has taken
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamSummary {
+    pub team: TeamId,
+    pub players: usize,
+    pub kills: Kills,
+    pub deaths: Deaths,
+    pub assists: Assists,
+    pub creep_score: CreepScore,
+    pub ward_score: WardScore,
+    /// the sum of `price * count` of all items
+    pub item_value: Price,
+    pub average_level: f32,
+    pub dead_players: Vec<DeadPlayer>,
+    pub objectives: ObjectivesTaken,
+}
+
+/// A player waiting to respawn
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadPlayer {
+    pub riot_id: String,
+    pub champion_name: ChampionName,
+    pub respawn_timer: Time,
+}
+
+/// Objectives counted from the events
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectivesTaken {
+    /// includes the elder dragons
+    pub dragons: Vec<DragonType>,
+    pub voidgrubs: u32,
+    pub heralds: u32,
+    pub barons: u32,
+    pub turrets: u32,
+    pub inhibitors: u32,
+    pub first_blood: bool,
+    pub first_brick: bool,
+}
+
+impl TeamSummary {
+    /// Summarize the players of `team` and the objectives they took until now
+    pub fn new(data: &AllGameData, team: TeamId) -> Self {
+        let players = data
+            .all_players
+            .iter()
+            .filter(|p| p.team == team)
+            .collect::<Vec<_>>();
+        let sum = |score: fn(&PlayerScores) -> i32| players.iter().map(|p| score(&p.scores)).sum();
+
+        let average_level = match players.len() {
+            0 => 0.0,
+            len => players.iter().map(|p| p.level as f32).sum::<f32>() / len as f32,
+        };
+        let dead_players = players
+            .iter()
+            .filter(|p| p.is_dead)
+            .map(|p| DeadPlayer {
+                riot_id: p.riot_id.riot_id.clone(),
+                champion_name: p.champion_name.clone(),
+                respawn_timer: p.respawn_timer,
+            })
+            .collect();
+
+        Self {
+            team,
+            players: players.len(),
+            kills: sum(|s| s.kills),
+            deaths: sum(|s| s.deaths),
+            assists: sum(|s| s.assists),
+            creep_score: sum(|s| s.creep_score),
+            ward_score: players.iter().map(|p| p.scores.ward_score).sum(),
+            item_value: players
+                .iter()
+                .flat_map(|p| p.items.iter())
+                .map(|item| item.price * item.count)
+                .sum(),
+            average_level,
+            dead_players,
+            objectives: objectives_taken(data, team),
+        }
+    }
+
+    /// Summaries of the teams [TeamId::Order] and [TeamId::Chaos]
+    pub fn both_teams(data: &AllGameData) -> [Self; 2] {
+        [
+            Self::new(data, TeamId::Order),
+            Self::new(data, TeamId::Chaos),
+        ]
+    }
+
+    /// The estimated gold difference to `other`, positive if this team is ahead \
+    /// The API doesn't show the gold of other players, so this compares the value of the items
+    pub fn gold_difference(&self, other: &TeamSummary) -> Price {
+        self.item_value - other.item_value
+    }
+}
+
+fn objectives_taken(data: &AllGameData, team: TeamId) -> ObjectivesTaken {
+    let players = &data.all_players;
+    let team_of_killer =
+        |killer: &Killer, assisters: &[String]| team_of_killer(players, killer, assisters);
+
+    let mut objectives = ObjectivesTaken::default();
+    for event in data.events.iter() {
+        match event {
+            GameEvent::FirstBlood(e) => {
+                objectives.first_blood |=
+                    find_player(players, &e.recipient).is_some_and(|p| p.team == team)
+            }
+            GameEvent::FirstBrick(e) => {
+                objectives.first_brick |= team_of_killer(&e.killer_name, &[]) == Some(team)
+            }
+            GameEvent::DragonKill(e)
+                if team_of_killer(&e.killer_name, &e.assisters) == Some(team) =>
+            {
+                objectives.dragons.push(e.dragon_type.clone())
+            }
+            GameEvent::HordeKill(e)
+                if team_of_killer(&e.killer_name, &e.assisters) == Some(team) =>
+            {
+                objectives.voidgrubs += 1
+            }
+            GameEvent::HeraldKill(e)
+                if team_of_killer(&e.killer_name, &e.assisters) == Some(team) =>
+            {
+                objectives.heralds += 1
+            }
+            GameEvent::BaronKill(e)
+                if team_of_killer(&e.killer_name, &e.assisters) == Some(team) =>
+            {
+                objectives.barons += 1
+            }
+            GameEvent::TurretKilled(e) => {
+                let destroyed_by = e
+                    .turret_killed
+                    .team()
+                    .map(enemy)
+                    .or_else(|| team_of_killer(&e.killer_name, &e.assisters));
+                if destroyed_by == Some(team) {
+                    objectives.turrets += 1;
+                }
+            }
+            GameEvent::InhibKilled(e) => {
+                let destroyed_by = e
+                    .inhib_killed
+                    .team()
+                    .map(enemy)
+                    .or_else(|| team_of_killer(&e.killer_name, &e.assisters));
+                if destroyed_by == Some(team) {
+                    objectives.inhibitors += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    objectives
+}
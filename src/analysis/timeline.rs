@@ -0,0 +1,238 @@
+use std::{
+    borrow::Borrow,
+    io::{self, Write},
+};
+
+use serde::Serialize;
+
+use super::player_index;
+use crate::model::ingame::*;
+
+/// The performance of a player over the course of a game, built from repeated [AllGameData] samples
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerTimeline {
+    pub riot_id: String,
+    pub champion_name: ChampionName,
+    pub team: TeamId,
+    pub samples: Vec<TimelineSample>,
+    pub deaths: Vec<Death>,
+    #[serde(skip)]
+    last_event_id: Option<EventId>,
+    #[serde(skip)]
+    team_kills: u32,
+    #[serde(skip)]
+    kills_involved: u32,
+}
+
+/// The stats of a player at one point of the game
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineSample {
+    pub game_time: Time,
+    pub level: Level,
+    pub creep_score: CreepScore,
+    pub cs_per_minute: f64,
+    pub kills: Kills,
+    pub deaths: Deaths,
+    pub assists: Assists,
+    /// the share of the team's kills the player killed or assisted, between 0 and 1
+    pub kill_participation: f64,
+    /// the sum of `price * count` of all items
+    pub item_value: Price,
+    /// estimated from the item value, the API doesn't show the gold of other players
+    pub gold_per_minute: f64,
+    pub ward_score: WardScore,
+    pub is_dead: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Death {
+    pub time: Time,
+    pub killer: Killer,
+    /// how long the player stayed dead, \
+    /// `None` until a sample shows the player dead with their `respawn_timer`
+    pub time_dead: Option<Time>,
+}
+
+impl PlayerTimeline {
+    /// An empty timeline of `player`
+    pub fn new(player: &Player) -> Self {
+        Self {
+            riot_id: player.riot_id.riot_id.clone(),
+            champion_name: player.champion_name.clone(),
+            team: player.team,
+            samples: Vec::new(),
+            deaths: Vec::new(),
+            last_event_id: None,
+            team_kills: 0,
+            kills_involved: 0,
+        }
+    }
+
+    /// The timelines of all players from the samples of a game, e.g. the snapshots of a recording
+    pub fn from_samples<I, D>(samples: I) -> Vec<Self>
+    where
+        I: IntoIterator<Item = D>,
+        D: Borrow<AllGameData>,
+    {
+        let mut timelines: Vec<Self> = Vec::new();
+        for data in samples {
+            let data = data.borrow();
+            if timelines.is_empty() {
+                timelines = data.all_players.iter().map(Self::new).collect();
+            }
+            for timeline in timelines.iter_mut() {
+                timeline.push(data);
+            }
+        }
+        timelines
+    }
+
+    /// Add a sample of the game \
+    /// Samples have to be pushed in order, samples that aren't newer than the last one are ignored \
+    /// Returns false if the player isn't part of the game
+    pub fn push(&mut self, data: &AllGameData) -> bool {
+        let players = &data.all_players;
+        let Some(index) = player_index(players, &self.riot_id) else {
+            return false;
+        };
+        let game_time = data.game_data.game_time;
+        if self
+            .samples
+            .last()
+            .is_some_and(|s| s.game_time >= game_time)
+        {
+            return true;
+        }
+        let player = &players[index];
+
+        let new_events = data
+            .events
+            .iter()
+            .filter(|e| self.last_event_id.is_none_or(|id| e.get_event_id() > id));
+        for event in new_events {
+            let GameEvent::ChampionKill(kill) = event else {
+                continue;
+            };
+            if player_index(players, &kill.victim_name) == Some(index) {
+                self.deaths.push(Death {
+                    time: kill.event_time,
+                    killer: kill.killer_name.clone(),
+                    time_dead: None,
+                });
+            }
+            let killer = match &kill.killer_name {
+                Killer::Summoner(name) => player_index(players, name),
+                _ => None,
+            };
+            if killer.is_some_and(|killer| players[killer].team == self.team) {
+                self.team_kills += 1;
+                let assisted = kill
+                    .assisters
+                    .iter()
+                    .any(|a| player_index(players, a) == Some(index));
+                if killer == Some(index) || assisted {
+                    self.kills_involved += 1;
+                }
+            }
+        }
+        if let Some(last) = data.events.last() {
+            self.last_event_id = Some(last.get_event_id());
+        }
+
+        if player.is_dead {
+            if let Some(death) = self.deaths.last_mut() {
+                death
+                    .time_dead
+                    .get_or_insert(game_time - death.time + player.respawn_timer);
+            }
+        }
+
+        let per_minute = |value: f64| {
+            if game_time > 0.0 {
+                value / (game_time / 60.0)
+            } else {
+                0.0
+            }
+        };
+        let item_value = player
+            .items
+            .iter()
+            .map(|item| item.price * item.count)
+            .sum::<Price>();
+        let scores = &player.scores;
+        self.samples.push(TimelineSample {
+            game_time,
+            level: player.level,
+            creep_score: scores.creep_score,
+            cs_per_minute: per_minute(scores.creep_score as f64),
+            kills: scores.kills,
+            deaths: scores.deaths,
+            assists: scores.assists,
+            kill_participation: match self.team_kills {
+                0 => 0.0,
+                team_kills => self.kills_involved as f64 / team_kills as f64,
+            },
+            item_value,
+            gold_per_minute: per_minute(item_value as f64),
+            ward_score: scores.ward_score,
+            is_dead: player.is_dead,
+        });
+        true
+    }
+
+    /// The latest sample
+    pub fn last(&self) -> Option<&TimelineSample> {
+        self.samples.last()
+    }
+
+    /// The time the player spent dead so far, not counting deaths no sample has seen yet
+    pub fn time_spent_dead(&self) -> Time {
+        self.deaths.iter().filter_map(|d| d.time_dead).sum()
+    }
+}
+
+/// Write the samples of all timelines as CSV, one row per player and sample \
+/// The columns are named like the JSON fields
+pub fn write_csv<W: Write>(timelines: &[PlayerTimeline], mut writer: W) -> io::Result<()> {
+    writeln!(
+        writer,
+        "riotId,championName,team,gameTime,level,creepScore,csPerMinute,kills,deaths,assists,\
+         killParticipation,itemValue,goldPerMinute,wardScore,isDead"
+    )?;
+    for timeline in timelines {
+        for s in timeline.samples.iter() {
+            writeln!(
+                writer,
+                "{},{},{},{:.3},{},{},{:.2},{},{},{},{:.3},{},{:.1},{:.2},{}",
+                csv_field(&timeline.riot_id),
+                csv_field(&timeline.champion_name),
+                timeline.team,
+                s.game_time,
+                s.level,
+                s.creep_score,
+                s.cs_per_minute,
+                s.kills,
+                s.deaths,
+                s.assists,
+                s.kill_participation,
+                s.item_value,
+                s.gold_per_minute,
+                s.ward_score,
+                s.is_dead,
+            )?;
+        }
+    }
+    writer.flush()
+}
+
+/// Quote fields containing separators, quotes or line breaks
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
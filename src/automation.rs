@@ -0,0 +1,632 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant, SystemTime},
+};
+
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use crate::{
+    error::LcuError,
+    model::{
+        lcu::champ_select::{ChampSelectActionType, ChampSelectSession},
+        ws::LcuSubscriptionType,
+    },
+    ready_check::{
+        ReadyCheckDecision, ReadyCheckOutcome, ReadyCheckPolicy, ReadyCheckResponder,
+        READY_CHECK_ENDPOINT,
+    },
+    rest::RESTClient,
+    ws::LcuWebsocketClient,
+};
+
+const SESSION_ENDPOINT: &str = "/lol-champ-select/v1/session";
+/// The wait before retrying after a failed request, doubled with every failure in a row
+const RETRY_DELAY: Duration = Duration::from_millis(250);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(4);
+
+/// The rules of a [ChampSelectAutomation], e.g. loaded from a JSON file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AutomationConfig {
+    /// the first rule matching the queue and assigned position is used
+    pub rules: Vec<AutomationRule>,
+    /// accept every found match while running, like a [ReadyCheckWatcher](crate::ready_check::ReadyCheckWatcher)
+    /// with [ReadyCheckPolicy::Always] which must not run at the same time
+    pub accept_ready_check: bool,
+    /// hover the pick or ban right away and lock it in when this many milliseconds are left, \
+    /// lock in right away if `None`
+    pub lock_margin_ms: Option<u64>,
+}
+
+/// What to pick, ban and select in the queues and positions the rule applies to
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AutomationRule {
+    /// the queue ids, e.g. 420 for Ranked Solo/Duo, the rule applies to all queues if empty
+    pub queues: Vec<i32>,
+    /// the assigned positions, e.g. `middle`, the rule applies to all positions if empty
+    pub positions: Vec<String>,
+    /// champion ids by priority, the first one that isn't banned or taken is picked
+    pub picks: Vec<i32>,
+    /// champion ids by priority, the first one that isn't banned or hovered by a teammate is banned
+    pub bans: Vec<i32>,
+    pub summoner_spells: Option<[u64; 2]>,
+    /// the rune page id for all champions without a page in `rune_pages`
+    pub rune_page: Option<u64>,
+    /// rune page ids by champion id
+    pub rune_pages: HashMap<i32, u64>,
+    /// skin ids by champion id
+    pub skins: HashMap<i32, i32>,
+}
+
+impl AutomationRule {
+    pub fn applies_to(&self, queue_id: Option<i32>, position: &str) -> bool {
+        (self.queues.is_empty() || queue_id.is_some_and(|id| self.queues.contains(&id)))
+            && (self.positions.is_empty() || self.positions.iter().any(|p| p == position))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum AutomationAction {
+    Hover {
+        action_id: i64,
+        champion_id: i32,
+    },
+    Lock {
+        action_id: i64,
+        champion_id: i32,
+    },
+    Ban {
+        action_id: i64,
+        champion_id: i32,
+    },
+    /// All champions of the rule are banned or taken, the action is left to the player
+    NoChampionAvailable {
+        action_id: i64,
+    },
+    SetSummonerSpells {
+        spell1_id: u64,
+        spell2_id: u64,
+    },
+    SetRunePage {
+        page_id: u64,
+    },
+    SetSkin {
+        skin_id: i32,
+    },
+    AcceptReadyCheck,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DecisionOutcome {
+    /// Decided but not applied yet
+    Planned,
+    /// Not applied because of the dry-run mode
+    DryRun,
+    Applied,
+    /// The request to the client failed, the action is decided again after a delay \
+    /// A champion the client refused to lock in or ban is skipped for the rest of the champ select
+    Failed(String),
+}
+
+/// A logged decision of the [ChampSelectAutomation]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomationDecision {
+    pub time: SystemTime,
+    pub action: AutomationAction,
+    /// why the action was taken, e.g. which champions were skipped
+    pub reason: String,
+    pub outcome: DecisionOutcome,
+}
+
+type DecisionLogger = Box<dyn FnMut(&AutomationDecision) + Send>;
+
+/// What was already done in the current champ select, to act only once
+#[derive(Debug, Clone, Default)]
+struct AutomationState {
+    hovered: HashMap<i64, i32>,
+    locked: HashSet<i64>,
+    unavailable: HashSet<i64>,
+    /// actions the rule has no champions for, left to the player
+    ignored: HashSet<i64>,
+    /// actions whose lock deadline already passed, to wake up only once for them
+    deadline_passed: HashSet<i64>,
+    /// champions the client refused to lock in or ban
+    rejected: HashSet<i32>,
+    /// failed requests in a row
+    failures: u32,
+    retry_at: Option<Instant>,
+    summoner_spells: Option<[u64; 2]>,
+    rune_page: Option<(i32, u64)>,
+    skin: Option<i32>,
+}
+
+/// Picks, bans and selects according to an [AutomationConfig] \
+/// [run](ChampSelectAutomation::run) it against the client, or [replay](ChampSelectAutomation::replay) recorded sessions to see what it would do
+pub struct ChampSelectAutomation {
+    config: AutomationConfig,
+    dry_run: bool,
+    state: AutomationState,
+    log: Vec<AutomationDecision>,
+    logger: Option<DecisionLogger>,
+}
+
+impl ChampSelectAutomation {
+    pub fn new(config: AutomationConfig) -> Self {
+        Self {
+            config,
+            dry_run: false,
+            state: AutomationState::default(),
+            log: Vec::new(),
+            logger: None,
+        }
+    }
+
+    /// Only log the decisions without sending them to the client
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Call `logger` with every decision once its outcome is known, e.g. to print it
+    pub fn on_decision<F: FnMut(&AutomationDecision) + Send + 'static>(
+        mut self,
+        logger: F,
+    ) -> Self {
+        self.logger = Some(Box::new(logger));
+        self
+    }
+
+    pub fn config(&self) -> &AutomationConfig {
+        &self.config
+    }
+
+    /// All decisions made so far
+    pub fn decisions(&self) -> &[AutomationDecision] {
+        &self.log
+    }
+
+    /// Forget what was done in the last champ select, called when champ select ends
+    pub fn reset(&mut self) {
+        self.state = AutomationState::default();
+    }
+
+    /// Decide what to do in `session` at `now` without applying it \
+    /// Every action is decided only once per champ select, the decisions are not logged
+    pub fn decide(
+        &mut self,
+        session: &ChampSelectSession,
+        queue_id: Option<i32>,
+        now: SystemTime,
+    ) -> Vec<AutomationDecision> {
+        let mut decisions = Vec::new();
+        let Some(local_player) = session.local_player() else {
+            return decisions;
+        };
+        let Some(rule) = self
+            .config
+            .rules
+            .iter()
+            .find(|r| r.applies_to(queue_id, &local_player.assigned_position))
+        else {
+            return decisions;
+        };
+        let position = match local_player.assigned_position.as_str() {
+            "" => "any position",
+            position => position,
+        };
+        let mut decide = |action: AutomationAction, reason: String| {
+            decisions.push(AutomationDecision {
+                time: now,
+                action,
+                reason,
+                outcome: DecisionOutcome::Planned,
+            })
+        };
+
+        if let Some([spell1_id, spell2_id]) = rule.summoner_spells {
+            let current = [local_player.spell1_id, local_player.spell2_id];
+            if current != [spell1_id, spell2_id]
+                && self.state.summoner_spells != Some([spell1_id, spell2_id])
+            {
+                self.state.summoner_spells = Some([spell1_id, spell2_id]);
+                decide(
+                    AutomationAction::SetSummonerSpells {
+                        spell1_id,
+                        spell2_id,
+                    },
+                    format!("summoner spells for {position}"),
+                );
+            }
+        }
+
+        if let Some(action) = session
+            .current_action()
+            .filter(|a| !self.state.locked.contains(&a.id))
+        {
+            let (candidates, banning) = match action.action_type {
+                ChampSelectActionType::Pick => (&rule.picks, false),
+                ChampSelectActionType::Ban => (&rule.bans, true),
+                _ => (&Vec::new(), false),
+            };
+            let mut unavailable = unavailable_champions(session);
+            unavailable.extend(&self.state.rejected);
+            let skipped = candidates
+                .iter()
+                .take_while(|id| unavailable.contains(id))
+                .collect::<Vec<_>>();
+            let kind = if banning { "ban" } else { "pick" };
+            let reason = if skipped.is_empty() {
+                format!("first {kind} for {position}")
+            } else {
+                format!("first available {kind} for {position}, {skipped:?} are banned, taken or rejected")
+            };
+            match candidates.get(skipped.len()) {
+                Some(&champion_id) => {
+                    let lock_due =
+                        match (self.config.lock_margin_ms, session.timer.time_left_at(now)) {
+                            (Some(margin), Some(time_left)) => {
+                                time_left <= Duration::from_millis(margin)
+                            }
+                            _ => true,
+                        };
+                    if lock_due {
+                        self.state.locked.insert(action.id);
+                        let action_id = action.id;
+                        let lock = if banning {
+                            AutomationAction::Ban {
+                                action_id,
+                                champion_id,
+                            }
+                        } else {
+                            AutomationAction::Lock {
+                                action_id,
+                                champion_id,
+                            }
+                        };
+                        decide(lock, reason);
+                    } else if action.champion_id != champion_id
+                        && self.state.hovered.get(&action.id) != Some(&champion_id)
+                    {
+                        self.state.hovered.insert(action.id, champion_id);
+                        decide(
+                            AutomationAction::Hover {
+                                action_id: action.id,
+                                champion_id,
+                            },
+                            reason,
+                        );
+                    }
+                }
+                None if candidates.is_empty() => _ = self.state.ignored.insert(action.id),
+                None if self.state.unavailable.insert(action.id) => {
+                    decide(
+                        AutomationAction::NoChampionAvailable {
+                            action_id: action.id,
+                        },
+                        format!("all {kind}s for {position} are banned or taken: {candidates:?}"),
+                    );
+                }
+                None => {}
+            }
+        }
+
+        // runes and skins depend on the champion, wait until it is locked in
+        let picked = session.all_actions().any(|a| {
+            a.actor_cell_id == session.local_player_cell_id
+                && a.action_type == ChampSelectActionType::Pick
+                && a.completed
+        });
+        let champion_id = local_player.champion_id;
+        if picked && champion_id != 0 {
+            let page_id = rule
+                .rune_pages
+                .get(&champion_id)
+                .or(rule.rune_page.as_ref());
+            if let Some(&page_id) = page_id {
+                if self.state.rune_page != Some((champion_id, page_id)) {
+                    self.state.rune_page = Some((champion_id, page_id));
+                    decide(
+                        AutomationAction::SetRunePage { page_id },
+                        format!("rune page for champion {champion_id}"),
+                    );
+                }
+            }
+            if let Some(&skin_id) = rule.skins.get(&champion_id) {
+                if local_player.selected_skin_id != skin_id && self.state.skin != Some(skin_id) {
+                    self.state.skin = Some(skin_id);
+                    decide(
+                        AutomationAction::SetSkin { skin_id },
+                        format!("skin for champion {champion_id}"),
+                    );
+                }
+            }
+        }
+        decisions
+    }
+
+    /// Decide on recorded sessions of one champ select as if they were received at the time of their timers, \
+    /// nothing is sent to the client \
+    /// With a lock margin a pick is only locked in if a session was recorded within the margin
+    pub fn replay<'a, I: IntoIterator<Item = &'a ChampSelectSession>>(
+        &mut self,
+        queue_id: Option<i32>,
+        sessions: I,
+    ) -> Vec<AutomationDecision> {
+        let mut decisions = Vec::new();
+        for session in sessions {
+            for mut decision in self.decide(session, queue_id, session.timer.sent_at()) {
+                decision.outcome = DecisionOutcome::DryRun;
+                self.record(decision.clone());
+                decisions.push(decision);
+            }
+        }
+        decisions
+    }
+
+    /// Subscribe `ws_client` to the champ select session and the ready check and act on their updates \
+    /// until the websocket disconnects
+    pub async fn run(
+        &mut self,
+        rest_client: &RESTClient,
+        mut ws_client: LcuWebsocketClient,
+    ) -> Result<(), LcuError> {
+        ws_client
+            .subscribe(LcuSubscriptionType::JsonApiEvent(
+                SESSION_ENDPOINT.to_string(),
+            ))
+            .await?;
+        let mut ready_check = None;
+        if self.config.accept_ready_check {
+            ws_client
+                .subscribe(LcuSubscriptionType::JsonApiEvent(
+                    READY_CHECK_ENDPOINT.to_string(),
+                ))
+                .await?;
+            ready_check = Some(ReadyCheckResponder::new(
+                rest_client.clone(),
+                ReadyCheckPolicy::Always,
+            ));
+        }
+
+        let mut session = match rest_client.champ_select_session().await {
+            Ok(session) => Some(session),
+            Err(e) if e.status().is_some_and(|status| status == 404) => None,
+            Err(e) => return Err(e.into()),
+        };
+        let mut queue_id = match session {
+            Some(_) => queue_id(rest_client).await,
+            None => None,
+        };
+
+        loop {
+            if let Some((_, outcomes)) = &mut ready_check {
+                self.record_ready_checks(outcomes);
+            }
+
+            let mut wake_in = None;
+            if let Some(session) = &session {
+                wake_in = self.retry_in();
+                if wake_in.is_none() {
+                    let decisions = self.decide(session, queue_id, SystemTime::now());
+                    self.apply_all(rest_client, decisions).await;
+                    wake_in = self
+                        .retry_in()
+                        .or_else(|| self.lock_deadline(session, SystemTime::now()));
+                }
+            }
+
+            // wake up without an update when it is time to lock in or retry
+            let event = match wake_in {
+                Some(wake_in) => match tokio::time::timeout(wake_in, ws_client.next()).await {
+                    Ok(event) => event,
+                    Err(_) => continue,
+                },
+                None => ws_client.next().await,
+            };
+            let Some(event) = event else {
+                if let Some((_, outcomes)) = &mut ready_check {
+                    self.record_ready_checks(outcomes);
+                }
+                return Ok(());
+            };
+            if !event.subscription_type.is_json_api_event(SESSION_ENDPOINT) {
+                if let Some((responder, _)) = &mut ready_check {
+                    responder.handle(&event);
+                }
+            } else if event.event_type == "Delete" {
+                session = None;
+                queue_id = None;
+                self.reset();
+            } else {
+                let Ok(update) = serde_json::from_value(event.data) else {
+                    continue;
+                };
+                if session.is_none() {
+                    queue_id = self::queue_id(rest_client).await;
+                }
+                session = Some(update);
+            }
+        }
+    }
+
+    /// The time from `now` until the current pick or ban of `session` has to be locked in, \
+    /// `None` without a lock margin or if the action was already decided, see [ChampSelectAutomation::decide] \
+    /// Once the deadline passed it is returned as zero only once per action
+    pub fn lock_deadline(
+        &mut self,
+        session: &ChampSelectSession,
+        now: SystemTime,
+    ) -> Option<Duration> {
+        let margin = Duration::from_millis(self.config.lock_margin_ms?);
+        let action = session.current_action()?;
+        let state = &mut self.state;
+        if [&state.locked, &state.unavailable, &state.ignored]
+            .iter()
+            .any(|actions| actions.contains(&action.id))
+        {
+            return None;
+        }
+        let deadline = session.timer.time_left_at(now)?.saturating_sub(margin);
+        if deadline.is_zero() && !state.deadline_passed.insert(action.id) {
+            return None;
+        }
+        Some(deadline)
+    }
+
+    /// The time until failed actions may be retried, `None` if they may be retried right away
+    fn retry_in(&self) -> Option<Duration> {
+        let retry_in = self
+            .state
+            .retry_at?
+            .saturating_duration_since(Instant::now());
+        (!retry_in.is_zero()).then_some(retry_in)
+    }
+
+    async fn apply_all(&mut self, rest_client: &RESTClient, decisions: Vec<AutomationDecision>) {
+        for mut decision in decisions {
+            decision.outcome = if self.dry_run {
+                DecisionOutcome::DryRun
+            } else {
+                match apply(rest_client, &decision.action).await {
+                    Ok(()) => {
+                        self.state.failures = 0;
+                        DecisionOutcome::Applied
+                    }
+                    Err(e) => {
+                        // an error status means the client refused the action, not just the connection failed
+                        self.forget(&decision.action, e.status().is_some());
+                        let delay = RETRY_DELAY * 2u32.pow(self.state.failures.min(4));
+                        self.state.failures += 1;
+                        self.state.retry_at = Some(Instant::now() + delay.min(MAX_RETRY_DELAY));
+                        DecisionOutcome::Failed(e.to_string())
+                    }
+                }
+            };
+            self.record(decision);
+        }
+    }
+
+    /// Allow a failed action to be decided again, \
+    /// with another champion if the client `rejected` the pick or ban
+    fn forget(&mut self, action: &AutomationAction, rejected: bool) {
+        match action {
+            AutomationAction::Hover { action_id, .. } => _ = self.state.hovered.remove(action_id),
+            AutomationAction::Lock {
+                action_id,
+                champion_id,
+            }
+            | AutomationAction::Ban {
+                action_id,
+                champion_id,
+            } => {
+                self.state.locked.remove(action_id);
+                if rejected {
+                    self.state.rejected.insert(*champion_id);
+                }
+            }
+            AutomationAction::NoChampionAvailable { .. } => {}
+            AutomationAction::SetSummonerSpells { .. } => self.state.summoner_spells = None,
+            AutomationAction::SetRunePage { .. } => self.state.rune_page = None,
+            AutomationAction::SetSkin { .. } => self.state.skin = None,
+            AutomationAction::AcceptReadyCheck => {}
+        }
+    }
+
+    /// Log the ready checks the [ReadyCheckResponder] of [ChampSelectAutomation::run] accepted so far
+    fn record_ready_checks(&mut self, outcomes: &mut UnboundedReceiver<ReadyCheckOutcome>) {
+        while let Ok(outcome) = outcomes.try_recv() {
+            let outcome = match outcome {
+                ReadyCheckOutcome::Decided {
+                    decision: ReadyCheckDecision::Accept,
+                    ..
+                } => DecisionOutcome::Applied,
+                ReadyCheckOutcome::Failed { error, .. } => DecisionOutcome::Failed(error),
+                _ => continue,
+            };
+            self.record(AutomationDecision {
+                time: SystemTime::now(),
+                action: AutomationAction::AcceptReadyCheck,
+                reason: "ready checks are accepted".to_string(),
+                outcome,
+            });
+        }
+    }
+
+    fn record(&mut self, decision: AutomationDecision) {
+        if let Some(logger) = self.logger.as_mut() {
+            logger(&decision);
+        }
+        self.log.push(decision);
+    }
+}
+
+async fn apply(rest_client: &RESTClient, action: &AutomationAction) -> Result<(), reqwest::Error> {
+    match *action {
+        AutomationAction::Hover {
+            action_id,
+            champion_id,
+        } => rest_client.hover_champion(action_id, champion_id).await,
+        AutomationAction::Lock {
+            action_id,
+            champion_id,
+        } => rest_client.lock_champion(action_id, champion_id).await,
+        AutomationAction::Ban {
+            action_id,
+            champion_id,
+        } => rest_client.ban_champion(action_id, champion_id).await,
+        AutomationAction::NoChampionAvailable { .. } => Ok(()),
+        AutomationAction::SetSummonerSpells {
+            spell1_id,
+            spell2_id,
+        } => rest_client.set_summoner_spells(spell1_id, spell2_id).await,
+        AutomationAction::SetRunePage { page_id } => {
+            rest_client.set_current_rune_page(page_id).await
+        }
+        AutomationAction::SetSkin { skin_id } => rest_client.set_skin(skin_id).await,
+        AutomationAction::AcceptReadyCheck => rest_client.accept_ready_check().await,
+    }
+}
+
+/// The queue of the champ select, the session itself doesn't contain it
+async fn queue_id(rest_client: &RESTClient) -> Option<i32> {
+    rest_client
+        .gameflow_session()
+        .await
+        .ok()
+        .map(|session| session.game_data.queue.id)
+}
+
+/// The champions that are banned, picked or hovered by teammates
+fn unavailable_champions(session: &ChampSelectSession) -> HashSet<i32> {
+    let mut unavailable = session.banned_champions().collect::<HashSet<_>>();
+    unavailable.extend(&session.bans.my_team_bans);
+    unavailable.extend(&session.bans.their_team_bans);
+    unavailable.extend(
+        session
+            .all_actions()
+            .filter(|a| a.action_type == ChampSelectActionType::Pick && a.completed)
+            .map(|a| a.champion_id),
+    );
+    unavailable.extend(session.their_team.iter().map(|p| p.champion_id));
+    for teammate in session
+        .my_team
+        .iter()
+        .filter(|p| p.cell_id != session.local_player_cell_id)
+    {
+        unavailable.insert(teammate.champion_id);
+        // don't take the champion a teammate wants to play, don't ban it either
+        unavailable.insert(teammate.champion_pick_intent);
+    }
+    unavailable.remove(&0);
+    unavailable
+}
@@ -0,0 +1,69 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::{Stream, StreamExt};
+
+use crate::{
+    error::LcuError,
+    model::lcu::champ_select::{ChampSelectSession, ChampSelectUpdate},
+    rest::RESTClient,
+    ws::{connect_to_client, JsonApiEvents, LcuWebsocketClient},
+};
+
+const SESSION_ENDPOINT: &str = "/lol-champ-select/v1/session";
+
+/// A [Stream] of the [ChampSelectUpdate]s of the client \
+/// Yields the current session first if the client is already in champ select
+pub struct ChampSelectStream {
+    events: JsonApiEvents,
+    initial: Option<ChampSelectSession>,
+}
+
+impl ChampSelectStream {
+    /// Subscribe `ws_client` to the champ select session and get the current session with `rest_client`
+    pub async fn new(
+        rest_client: &RESTClient,
+        ws_client: LcuWebsocketClient,
+    ) -> Result<Self, LcuError> {
+        let events = JsonApiEvents::subscribe(ws_client, SESSION_ENDPOINT).await?;
+        let initial = match rest_client.champ_select_session().await {
+            Ok(session) => Some(session),
+            // not in champ select
+            Err(e) if e.status().is_some_and(|status| status == 404) => None,
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self { events, initial })
+    }
+
+    /// Connect to the running League client and create the stream
+    pub async fn connect() -> Result<Self, LcuError> {
+        let (rest_client, ws_client) = connect_to_client().await?;
+        Self::new(&rest_client, ws_client).await
+    }
+}
+
+impl Stream for ChampSelectStream {
+    type Item = ChampSelectUpdate;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(initial) = self.initial.take() {
+            return Poll::Ready(Some(ChampSelectUpdate::Session(Box::new(initial))));
+        }
+        loop {
+            let event = match self.events.poll_next_unpin(cx) {
+                Poll::Ready(Some(event)) => event,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+            if event.event_type == "Delete" {
+                return Poll::Ready(Some(ChampSelectUpdate::Ended));
+            }
+            let Ok(session) = serde_json::from_value(event.data) else {
+                continue;
+            };
+            return Poll::Ready(Some(ChampSelectUpdate::Session(Box::new(session))));
+        }
+    }
+}
@@ -104,3 +104,112 @@ impl Display for LcuWebsocketError {
         }
     }
 }
+
+/// Errors for the League-Client(LCU) helpers that combine the REST and the Websocket API, \
+/// e.g. the [GameflowPhaseStream](crate::gameflow::GameflowPhaseStream)
+#[derive(Debug, Clone)]
+pub enum LcuError {
+    /// A request to the REST API failed or its response couldn't be deserialized
+    Request(String),
+    /// The Websocket connection failed
+    Websocket(LcuWebsocketError),
+}
+
+impl From<reqwest::Error> for LcuError {
+    fn from(error: reqwest::Error) -> Self {
+        LcuError::Request(error.to_string())
+    }
+}
+
+impl From<LcuWebsocketError> for LcuError {
+    fn from(error: LcuWebsocketError) -> Self {
+        LcuError::Websocket(error)
+    }
+}
+
+impl Error for LcuError {}
+
+impl Display for LcuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Request(s) => write!(f, "LCU request failed: {s}"),
+            Self::Websocket(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Errors for writing and reading ingame recordings
+#[derive(Debug, Clone)]
+pub enum RecordingError {
+    /// The recording could not be written to or read from
+    Io(String),
+    /// The recording is malformed or was written in an unsupported format version
+    Format(String),
+    /// The Ingame API returned an error before the recording could be started
+    Ingame(IngameClientError),
+}
+
+impl From<std::io::Error> for RecordingError {
+    fn from(error: std::io::Error) -> Self {
+        RecordingError::Io(error.to_string())
+    }
+}
+
+impl From<serde_json::Error> for RecordingError {
+    fn from(error: serde_json::Error) -> Self {
+        if error.is_io() {
+            return RecordingError::Io(error.to_string());
+        }
+        RecordingError::Format(error.to_string())
+    }
+}
+
+impl From<IngameClientError> for RecordingError {
+    fn from(error: IngameClientError) -> Self {
+        RecordingError::Ingame(error)
+    }
+}
+
+impl Error for RecordingError {}
+
+impl Display for RecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(s) => write!(f, "Recording IO error: {s}"),
+            Self::Format(s) => write!(f, "Malformed recording: {s}"),
+            Self::Ingame(e) => write!(f, "Ingame API error: {e}"),
+        }
+    }
+}
+
+/// Errors for loading the [StaticData](crate::static_data::StaticData)
+#[derive(Debug, Clone)]
+pub enum StaticDataError {
+    /// A file could not be read
+    Io(String),
+    /// A file is not in the Data Dragon format
+    Format(String),
+}
+
+impl From<std::io::Error> for StaticDataError {
+    fn from(error: std::io::Error) -> Self {
+        StaticDataError::Io(error.to_string())
+    }
+}
+
+impl From<serde_json::Error> for StaticDataError {
+    fn from(error: serde_json::Error) -> Self {
+        StaticDataError::Format(error.to_string())
+    }
+}
+
+impl Error for StaticDataError {}
+
+impl Display for StaticDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(s) => write!(f, "Static data IO error: {s}"),
+            Self::Format(s) => write!(f, "Malformed static data: {s}"),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::{Stream, StreamExt};
+
+use crate::{
+    error::LcuError,
+    model::lcu::gameflow::{GameflowPhase, GameflowTransition},
+    rest::RESTClient,
+    ws::{connect_to_client, JsonApiEvents, LcuWebsocketClient},
+};
+
+const GAMEFLOW_PHASE_ENDPOINT: &str = "/lol-gameflow/v1/gameflow-phase";
+
+/// A [Stream] of the [GameflowTransition]s of the client \
+/// The first item is the transition from [GameflowPhase::None] to the current phase, unless the client is in no phase at all, \
+/// repeated updates of the same phase are skipped
+pub struct GameflowPhaseStream {
+    events: JsonApiEvents,
+    phase: GameflowPhase,
+    initial: Option<GameflowTransition>,
+}
+
+impl GameflowPhaseStream {
+    /// Subscribe `ws_client` to the gameflow phase and get the current phase with `rest_client` \
+    /// Subscribing first makes sure no transition between the request and the subscription is missed
+    pub async fn new(
+        rest_client: &RESTClient,
+        ws_client: LcuWebsocketClient,
+    ) -> Result<Self, LcuError> {
+        let events = JsonApiEvents::subscribe(ws_client, GAMEFLOW_PHASE_ENDPOINT).await?;
+        let phase = rest_client.gameflow_phase().await?;
+        let initial = (phase != GameflowPhase::None).then_some(GameflowTransition {
+            previous: GameflowPhase::None,
+            next: phase,
+        });
+        Ok(Self {
+            events,
+            phase,
+            initial,
+        })
+    }
+
+    /// Connect to the running League client and create the stream
+    pub async fn connect() -> Result<Self, LcuError> {
+        let (rest_client, ws_client) = connect_to_client().await?;
+        Self::new(&rest_client, ws_client).await
+    }
+
+    /// The phase after the last yielded transition
+    pub fn phase(&self) -> GameflowPhase {
+        self.phase
+    }
+}
+
+impl Stream for GameflowPhaseStream {
+    type Item = GameflowTransition;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(initial) = self.initial.take() {
+            return Poll::Ready(Some(initial));
+        }
+        loop {
+            let event = match self.events.poll_next_unpin(cx) {
+                Poll::Ready(Some(event)) => event,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+            let next = match event.event_type.as_str() {
+                "Delete" => GameflowPhase::None,
+                _ => match serde_json::from_value(event.data) {
+                    Ok(phase) => phase,
+                    Err(_) => continue,
+                },
+            };
+            if next == self.phase {
+                continue;
+            }
+            let previous = std::mem::replace(&mut self.phase, next);
+            return Poll::Ready(Some(GameflowTransition { previous, next }));
+        }
+    }
+}
@@ -1,7 +1,8 @@
-use std::{task::Poll, time::Duration};
+use std::{future::Future, task::Poll, time::Duration};
 
 use futures_util::Stream;
 use reqwest::Response;
+use serde::{de::DeserializeOwned, Serialize};
 use tokio::{
     sync::mpsc::{unbounded_channel, UnboundedReceiver},
     sync::oneshot,
@@ -9,12 +10,150 @@ use tokio::{
     task::JoinHandle,
 };
 
-use crate::{error::IngameClientError, model::ingame::*, utils::request::build_reqwest_client};
+use crate::{
+    error::IngameClientError,
+    model::{ingame::*, lazy::LazyGameData, replay::ReplayPlayback},
+    spectator::SpectatorView,
+    utils::request::{build_reqwest_client, build_reqwest_client_with_root},
+};
 
 const PORT: u16 = 2999;
 
+/// The two styles of routes the ingame API serves, e.g. `/GetLiveclientdataGamestats` and `/liveclientdata/gamestats`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RouteStyle {
+    /// The `GetLiveclientdata*` aliases
+    #[default]
+    Legacy,
+    /// The `/liveclientdata/*` routes documented by Riot
+    Modern,
+}
+
+impl RouteStyle {
+    /// The path of an endpoint given by its legacy name, e.g. `liveclientdata/gamestats` for `GetLiveclientdataGamestats` \
+    /// Other routes like `Help` are the same in both styles, a query string is kept as it is
+    pub fn path(self, endpoint: &str) -> String {
+        let (path, query) = match endpoint.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (endpoint, None),
+        };
+        match (self, path.strip_prefix("GetLiveclientdata")) {
+            (RouteStyle::Modern, Some(name)) => match query {
+                Some(query) => format!("liveclientdata/{}?{}", name.to_lowercase(), query),
+                None => format!("liveclientdata/{}", name.to_lowercase()),
+            },
+            _ => endpoint.to_string(),
+        }
+    }
+}
+
+/// How far a replay has to be loaded beyond the current time to not be taken for a spectated game, \
+/// spectated games are only loaded a few chunks of 30 seconds ahead
+const REPLAY_LOADED_AHEAD: Time = 180.0;
+
+/// The kind of game the ingame API serves
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IngameMode {
+    Live,
+    Spectator,
+    Replay,
+}
+
+/// The endpoints of the ingame API and the replay API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IngameEndpoint {
+    AllGameData,
+    EventData,
+    GameStats,
+    PlayerList,
+    PlayerItems,
+    PlayerMainRunes,
+    PlayerScores,
+    PlayerSummonerSpells,
+    ActivePlayer,
+    ActivePlayerAbilities,
+    ActivePlayerName,
+    ActivePlayerRunes,
+    ReplayPlayback,
+    ReplayRender,
+}
+
+impl IngameEndpoint {
+    pub const ALL: [IngameEndpoint; 14] = [
+        Self::AllGameData,
+        Self::EventData,
+        Self::GameStats,
+        Self::PlayerList,
+        Self::PlayerItems,
+        Self::PlayerMainRunes,
+        Self::PlayerScores,
+        Self::PlayerSummonerSpells,
+        Self::ActivePlayer,
+        Self::ActivePlayerAbilities,
+        Self::ActivePlayerName,
+        Self::ActivePlayerRunes,
+        Self::ReplayPlayback,
+        Self::ReplayRender,
+    ];
+
+    /// The route of the endpoint, the Live Client Data endpoints by their legacy name, see [RouteStyle::path]
+    pub fn route(self) -> &'static str {
+        match self {
+            Self::AllGameData => "GetLiveclientdataAllgamedata",
+            Self::EventData => "GetLiveclientdataEventdata",
+            Self::GameStats => "GetLiveclientdataGamestats",
+            Self::PlayerList => "GetLiveclientdataPlayerlist",
+            Self::PlayerItems => "GetLiveclientdataPlayeritems",
+            Self::PlayerMainRunes => "GetLiveclientdataPlayermainrunes",
+            Self::PlayerScores => "GetLiveclientdataPlayerscores",
+            Self::PlayerSummonerSpells => "GetLiveclientdataPlayersummonerspells",
+            Self::ActivePlayer => "GetLiveclientdataActiveplayer",
+            Self::ActivePlayerAbilities => "GetLiveclientdataActiveplayerabilities",
+            Self::ActivePlayerName => "GetLiveclientdataActiveplayername",
+            Self::ActivePlayerRunes => "GetLiveclientdataActiveplayerrunes",
+            Self::ReplayPlayback => "replay/playback",
+            Self::ReplayRender => "replay/render",
+        }
+    }
+
+    /// Only available in live games
+    pub fn is_active_player(self) -> bool {
+        matches!(
+            self,
+            Self::ActivePlayer
+                | Self::ActivePlayerAbilities
+                | Self::ActivePlayerName
+                | Self::ActivePlayerRunes
+        )
+    }
+
+    /// Only available when spectating or watching a replay with the replay API enabled
+    pub fn is_replay(self) -> bool {
+        matches!(self, Self::ReplayPlayback | Self::ReplayRender)
+    }
+}
+
+/// What the ingame API serves for the current game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IngameCapabilities {
+    pub mode: IngameMode,
+    /// whether the replay API is enabled, it's never served in live games
+    pub replay_api: bool,
+    pub endpoints: Vec<IngameEndpoint>,
+}
+
+impl IngameCapabilities {
+    pub fn supports(&self, endpoint: IngameEndpoint) -> bool {
+        self.endpoints.contains(&endpoint)
+    }
+}
+
 /// A client for the LoL-Ingame API
-pub struct IngameClient(reqwest::Client);
+pub struct IngameClient {
+    port: u16,
+    route_style: RouteStyle,
+    reqwest_client: reqwest::Client,
+}
 
 impl Default for IngameClient {
     fn default() -> Self {
@@ -25,17 +164,83 @@ impl Default for IngameClient {
 impl IngameClient {
     /// Create a new connection to the ingame api. This will return an error if a game is not running
     pub fn new() -> Self {
-        Self(build_reqwest_client(None))
+        Self {
+            port: PORT,
+            route_style: RouteStyle::default(),
+            reqwest_client: build_reqwest_client(None),
+        }
+    }
+
+    /// Create a client for an ingame API that is served on another port, e.g. by a mock server or a proxy \
+    /// `root_certificate` is the PEM encoded certificate the server gets verified with, in addition to the Riot Games certificate
+    pub fn with_port(port: u16, root_certificate: &[u8]) -> Result<Self, IngameClientError> {
+        let reqwest_client = build_reqwest_client_with_root(None, root_certificate)
+            .map_err(|e| IngameClientError::ConnectionError(e.to_string()))?;
+        Ok(Self {
+            port,
+            route_style: RouteStyle::default(),
+            reqwest_client,
+        })
+    }
+
+    fn url(&self, endpoint: &str) -> String {
+        self.url_with_style(endpoint, self.route_style)
+    }
+
+    fn url_with_style(&self, endpoint: &str, route_style: RouteStyle) -> String {
+        format!(
+            "https://127.0.0.1:{}/{}",
+            self.port,
+            route_style.path(endpoint)
+        )
+    }
+
+    /// The style of the routes the client calls, [RouteStyle::Legacy] by default
+    pub fn route_style(&self) -> RouteStyle {
+        self.route_style
+    }
+
+    pub fn set_route_style(&mut self, route_style: RouteStyle) {
+        self.route_style = route_style;
+    }
+
+    /// Use the modern routes if the API serves them and the legacy routes otherwise \
+    /// Fails like [IngameClient::game_stats] if neither is available, e.g. during the loading screen
+    pub async fn detect_route_style(&mut self) -> Result<RouteStyle, IngameClientError> {
+        let mut error = IngameClientError::ApiNotAvailableDuringLoadingScreen;
+        for route_style in [RouteStyle::Modern, RouteStyle::Legacy] {
+            let response = self
+                .reqwest_client
+                .get(self.url_with_style("GetLiveclientdataGamestats", route_style))
+                .send()
+                .await
+                .and_then(Response::error_for_status);
+            match response {
+                Ok(_) => {
+                    self.route_style = route_style;
+                    return Ok(route_style);
+                }
+                Err(e) => error = e.into(),
+            }
+        }
+        Err(error)
+    }
+
+    /// Get the OpenAPI description the API serves, \
+    /// see [compare_spec](crate::openapi::compare_spec) to compare it against the models of this crate
+    pub async fn openapi_spec(&self) -> Result<serde_json::Value, IngameClientError> {
+        match self.get_raw("swagger/v3/openapi.json").await {
+            Ok(spec) => Ok(spec),
+            Err(_) => self.get_raw("swagger/v2/swagger.json").await,
+        }
     }
 
     /// Checks if there is an active game \
     /// Returns true only after the loading screen
     pub async fn active_game(&self) -> bool {
         let req = self
-            .0
-            .get(format!(
-                "https://127.0.0.1:{PORT}/GetLiveclientdataGamestats"
-            ))
+            .reqwest_client
+            .get(self.url("GetLiveclientdataGamestats"))
             .send()
             .await;
 
@@ -56,11 +261,7 @@ impl IngameClient {
     /// Same as [`IngameClient::active_game`] but returns true during loading screen when other API calls still return Error.
     /// Also returns true when the game has already started.
     pub async fn active_game_loadingscreen(&self) -> bool {
-        let req = self
-            .0
-            .head(format!("https://127.0.0.1:{PORT}/Help"))
-            .send()
-            .await;
+        let req = self.reqwest_client.head(self.url("Help")).send().await;
 
         if let Ok(req) = req {
             req.status().is_success()
@@ -78,10 +279,8 @@ impl IngameClient {
         }
 
         let req = self
-            .0
-            .head(format!(
-                "https://127.0.0.1:{PORT}/GetLiveclientdataActiveplayer"
-            ))
+            .reqwest_client
+            .head(self.url("GetLiveclientdataActiveplayer"))
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -94,17 +293,53 @@ impl IngameClient {
         }
     }
 
+    /// Probe whether the game is live, spectated or a replay and which endpoints are available \
+    /// Replays can only be told apart from spectated games with the replay API enabled,
+    /// and replays watched close to their end are taken for spectated games \
+    /// Fails during the loading screen like [IngameClient::is_spectator_mode]
+    pub async fn capabilities(&self) -> Result<IngameCapabilities, IngameClientError> {
+        let spectator = self.is_spectator_mode().await?;
+        let playback = if spectator {
+            self.get_json::<ReplayPlayback>(IngameEndpoint::ReplayPlayback.route())
+                .await
+                .ok()
+        } else {
+            None
+        };
+        let mode = match &playback {
+            _ if !spectator => IngameMode::Live,
+            Some(playback) if playback.length - playback.time > REPLAY_LOADED_AHEAD => {
+                IngameMode::Replay
+            }
+            _ => IngameMode::Spectator,
+        };
+        let replay_api = playback.is_some();
+        let endpoints = IngameEndpoint::ALL
+            .into_iter()
+            .filter(|e| !(spectator && e.is_active_player()))
+            .filter(|e| replay_api || !e.is_replay())
+            .collect();
+        Ok(IngameCapabilities {
+            mode,
+            replay_api,
+            endpoints,
+        })
+    }
+
+    /// A view of the game limited to the data available while spectating
+    pub fn spectator_view(self) -> SpectatorView {
+        SpectatorView::new(self)
+    }
+
     /// Get all current game data
     pub async fn all_game_data(
         &self,
         event_id: Option<u32>,
     ) -> Result<AllGameData, IngameClientError> {
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{}/GetLiveclientdataAllgamedata?eventID={}",
-                PORT,
-                event_id.unwrap_or(0) // an event_id of 0 returns all events
-            ))
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataAllgamedata"))
+            // an event_id of 0 returns all events
+            .query(&[("eventID", event_id.unwrap_or(0))])
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -114,23 +349,39 @@ impl IngameClient {
             .map_err(IngameClientError::from)
     }
 
+    /// Get all current game data, parsing the active player and the players items, runes \
+    /// and summoner spells only when accessed \
+    /// Pass the [LazyGameData::next_event_id] of the previous poll to only get the new events
+    pub async fn all_game_data_lazy(
+        &self,
+        event_id: Option<u32>,
+    ) -> Result<LazyGameData, IngameClientError> {
+        // an event_id of 0 returns all events
+        let event_id = event_id.unwrap_or(0);
+        let mut data: LazyGameData = self
+            .reqwest_client
+            .get(self.url("GetLiveclientdataAllgamedata"))
+            .query(&[("eventID", event_id)])
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(IngameClientError::from)?
+            .json()
+            .await
+            .map_err(IngameClientError::from)?;
+        data.requested_event_id = event_id;
+        Ok(data)
+    }
+
     /// Get event data for the active game
     pub async fn event_data(
         &self,
         event_id: Option<u32>,
     ) -> Result<Vec<GameEvent>, IngameClientError> {
-        #[derive(serde::Deserialize)]
-        #[serde(rename_all = "PascalCase")]
-        struct IngameEvents {
-            pub events: Vec<GameEvent>,
-        }
-
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{}/GetLiveclientdataEventdata?eventID={}",
-                PORT,
-                event_id.unwrap_or(0) // an event_id of 0 returns all events
-            ))
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataEventdata"))
+            // an event_id of 0 returns all events
+            .query(&[("eventID", event_id.unwrap_or(0))])
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -143,10 +394,8 @@ impl IngameClient {
 
     /// Get the active games stats
     pub async fn game_stats(&self) -> Result<GameStats, IngameClientError> {
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{PORT}/GetLiveclientdataGamestats"
-            ))
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataGamestats"))
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -161,12 +410,9 @@ impl IngameClient {
         &self,
         summoner_name: S,
     ) -> Result<Vec<PlayerItem>, IngameClientError> {
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{}/GetLiveclientdataPlayeritems?riotId={}",
-                PORT,
-                summoner_name.as_ref()
-            ))
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataPlayeritems"))
+            .query(&[("riotId", summoner_name.as_ref())])
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -186,14 +432,14 @@ impl IngameClient {
         #[derive(serde::Deserialize)]
         struct PlayerOpt(#[serde(deserialize_with = "treat_error_as_none")] Option<Player>);
 
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{}/GetLiveclientdataPlayerlist?teamID={}",
-                PORT,
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataPlayerlist"))
+            .query(&[(
+                "teamID",
                 team_id
                     .map(|team_id| format!("{team_id}"))
-                    .unwrap_or("".to_string())
-            ))
+                    .unwrap_or("".to_string()),
+            )])
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -214,12 +460,9 @@ impl IngameClient {
         &self,
         summoner_name: S,
     ) -> Result<PlayerRunes, IngameClientError> {
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{}/GetLiveclientdataPlayermainrunes?riotId={}",
-                PORT,
-                summoner_name.as_ref()
-            ))
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataPlayermainrunes"))
+            .query(&[("riotId", summoner_name.as_ref())])
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -234,12 +477,9 @@ impl IngameClient {
         &self,
         summoner_name: S,
     ) -> Result<PlayerScores, IngameClientError> {
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{}/GetLiveclientdataPlayerscores?riotId={}",
-                PORT,
-                summoner_name.as_ref()
-            ))
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataPlayerscores"))
+            .query(&[("riotId", summoner_name.as_ref())])
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -254,12 +494,9 @@ impl IngameClient {
         &self,
         summoner_name: S,
     ) -> Result<SummonerSpells, IngameClientError> {
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{}/GetLiveclientdataPlayersummonerspells?riotId={}",
-                PORT,
-                summoner_name.as_ref()
-            ))
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataPlayersummonerspells"))
+            .query(&[("riotId", summoner_name.as_ref())])
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -280,10 +517,8 @@ impl IngameClient {
             Error { error: String },
         }
 
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{PORT}/GetLiveclientdataActiveplayer"
-            ))
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataActiveplayer"))
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -302,10 +537,8 @@ impl IngameClient {
     /// Get the active players abilities \
     /// Only available during livegame
     pub async fn active_player_abilities(&self) -> Result<PlayerAbilities, IngameClientError> {
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{PORT}/GetLiveclientdataActiveplayerabilities"
-            ))
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataActiveplayerabilities"))
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -318,10 +551,8 @@ impl IngameClient {
     /// Get the active players name \
     /// Only available during livegame
     pub async fn active_player_name(&self) -> Result<String, IngameClientError> {
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{PORT}/GetLiveclientdataActiveplayername"
-            ))
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataActiveplayername"))
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -341,10 +572,66 @@ impl IngameClient {
     /// Get the active players runes \
     /// Only available during livegames
     pub async fn active_player_runes(&self) -> Result<FullPlayerRunes, IngameClientError> {
-        self.0
-            .get(format!(
-                "https://127.0.0.1:{PORT}/GetLiveclientdataActiveplayerrunes"
-            ))
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataActiveplayerrunes"))
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(IngameClientError::from)?
+            .json()
+            .await
+            .map_err(IngameClientError::from)
+    }
+
+    /// Get the undeserialized JSON of an endpoint, e.g. `GetLiveclientdataGamestats` \
+    /// Used where the data has to be kept exactly as the API returned it
+    pub(crate) async fn get_raw(
+        &self,
+        endpoint: &str,
+    ) -> Result<serde_json::Value, IngameClientError> {
+        self.get_json(endpoint).await
+    }
+
+    /// Get the undeserialized JSON of `GetLiveclientdataAllgamedata` with the events starting at `event_id`
+    pub(crate) async fn all_game_data_raw(
+        &self,
+        event_id: u32,
+    ) -> Result<serde_json::Value, IngameClientError> {
+        self.reqwest_client
+            .get(self.url("GetLiveclientdataAllgamedata"))
+            .query(&[("eventID", event_id)])
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(IngameClientError::from)?
+            .json()
+            .await
+            .map_err(IngameClientError::from)
+    }
+
+    pub(crate) async fn get_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+    ) -> Result<T, IngameClientError> {
+        self.reqwest_client
+            .get(self.url(endpoint))
+            .send()
+            .await
+            .and_then(Response::error_for_status)
+            .map_err(IngameClientError::from)?
+            .json()
+            .await
+            .map_err(IngameClientError::from)
+    }
+
+    pub(crate) async fn post_json<B: Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &B,
+    ) -> Result<T, IngameClientError> {
+        self.reqwest_client
+            .post(self.url(endpoint))
+            .json(body)
             .send()
             .await
             .and_then(Response::error_for_status)
@@ -355,9 +642,165 @@ impl IngameClient {
     }
 }
 
+/// The data available through the LoL-Ingame API \
+/// Implemented by the [IngameClient] and the [Playback](crate::playback::Playback) of recorded games,
+/// so consumers like the [EventStream] work with both
+pub trait IngameApi: Send + Sync {
+    /// Checks if there is an active game \
+    /// Returns true only after the loading screen
+    fn active_game(&self) -> impl Future<Output = bool> + Send;
+
+    /// Checks if there is an active game \
+    /// Returns true during the loading screen and when the game has already started
+    fn active_game_loadingscreen(&self) -> impl Future<Output = bool> + Send;
+
+    /// Checks if the game is a livegame or in spectatormode
+    fn is_spectator_mode(&self) -> impl Future<Output = Result<bool, IngameClientError>> + Send;
+
+    /// Get all current game data
+    fn all_game_data(
+        &self,
+        event_id: Option<u32>,
+    ) -> impl Future<Output = Result<AllGameData, IngameClientError>> + Send;
+
+    /// Get event data for the active game
+    fn event_data(
+        &self,
+        event_id: Option<u32>,
+    ) -> impl Future<Output = Result<Vec<GameEvent>, IngameClientError>> + Send;
+
+    /// Get the active games stats
+    fn game_stats(&self) -> impl Future<Output = Result<GameStats, IngameClientError>> + Send;
+
+    /// Get a specified players items
+    fn player_items<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> impl Future<Output = Result<Vec<PlayerItem>, IngameClientError>> + Send;
+
+    /// Get a list of players in game
+    fn player_list(
+        &self,
+        team_id: Option<TeamId>,
+    ) -> impl Future<Output = Result<Vec<Player>, IngameClientError>> + Send;
+
+    /// Get a specified players main runes
+    fn player_main_runes<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> impl Future<Output = Result<PlayerRunes, IngameClientError>> + Send;
+
+    /// Get a specified players score
+    fn player_scores<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> impl Future<Output = Result<PlayerScores, IngameClientError>> + Send;
+
+    /// Get specified players summoner spells
+    fn player_summoner_spells<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> impl Future<Output = Result<SummonerSpells, IngameClientError>> + Send;
+
+    /// Get active players data \
+    /// Only available during livegame
+    fn active_player(&self)
+        -> impl Future<Output = Result<ActivePlayer, IngameClientError>> + Send;
+
+    /// Get the active players abilities \
+    /// Only available during livegame
+    fn active_player_abilities(
+        &self,
+    ) -> impl Future<Output = Result<PlayerAbilities, IngameClientError>> + Send;
+
+    /// Get the active players name \
+    /// Only available during livegame
+    fn active_player_name(&self) -> impl Future<Output = Result<String, IngameClientError>> + Send;
+
+    /// Get the active players runes \
+    /// Only available during livegames
+    fn active_player_runes(
+        &self,
+    ) -> impl Future<Output = Result<FullPlayerRunes, IngameClientError>> + Send;
+}
+
+impl IngameApi for IngameClient {
+    async fn active_game(&self) -> bool {
+        IngameClient::active_game(self).await
+    }
+
+    async fn active_game_loadingscreen(&self) -> bool {
+        IngameClient::active_game_loadingscreen(self).await
+    }
+
+    async fn is_spectator_mode(&self) -> Result<bool, IngameClientError> {
+        IngameClient::is_spectator_mode(self).await
+    }
+
+    async fn all_game_data(&self, event_id: Option<u32>) -> Result<AllGameData, IngameClientError> {
+        IngameClient::all_game_data(self, event_id).await
+    }
+
+    async fn event_data(&self, event_id: Option<u32>) -> Result<Vec<GameEvent>, IngameClientError> {
+        IngameClient::event_data(self, event_id).await
+    }
+
+    async fn game_stats(&self) -> Result<GameStats, IngameClientError> {
+        IngameClient::game_stats(self).await
+    }
+
+    async fn player_items<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> Result<Vec<PlayerItem>, IngameClientError> {
+        IngameClient::player_items(self, summoner_name).await
+    }
+
+    async fn player_list(&self, team_id: Option<TeamId>) -> Result<Vec<Player>, IngameClientError> {
+        IngameClient::player_list(self, team_id).await
+    }
+
+    async fn player_main_runes<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> Result<PlayerRunes, IngameClientError> {
+        IngameClient::player_main_runes(self, summoner_name).await
+    }
+
+    async fn player_scores<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> Result<PlayerScores, IngameClientError> {
+        IngameClient::player_scores(self, summoner_name).await
+    }
+
+    async fn player_summoner_spells<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> Result<SummonerSpells, IngameClientError> {
+        IngameClient::player_summoner_spells(self, summoner_name).await
+    }
+
+    async fn active_player(&self) -> Result<ActivePlayer, IngameClientError> {
+        IngameClient::active_player(self).await
+    }
+
+    async fn active_player_abilities(&self) -> Result<PlayerAbilities, IngameClientError> {
+        IngameClient::active_player_abilities(self).await
+    }
+
+    async fn active_player_name(&self) -> Result<String, IngameClientError> {
+        IngameClient::active_player_name(self).await
+    }
+
+    async fn active_player_runes(&self) -> Result<FullPlayerRunes, IngameClientError> {
+        IngameClient::active_player_runes(self).await
+    }
+}
+
 const DEFAULT_POLLING_RATE_MILLIS: u64 = 500;
 
-/// A wrapper around a [IngameClient] that regularly polls the ingame events
+/// A wrapper around an [IngameApi] like the [IngameClient] that regularly polls the ingame events
 pub struct EventStream {
     start_tx: Option<Sender<()>>,
     poll_task_handle: JoinHandle<()>,
@@ -365,10 +808,13 @@ pub struct EventStream {
 }
 
 impl EventStream {
-    /// Create an [EventStream] from an [IngameClient] \
-    /// Takes an [Option] that specifies the polling rate of the [IngameClient] that's being wrapped \
+    /// Create an [EventStream] from an [IngameClient] or any other [IngameApi] \
+    /// Takes an [Option] that specifies the polling rate of the [IngameApi] that's being wrapped \
     /// The default [Duration] is 500ms
-    pub fn from_ingame_client(ingame_client: IngameClient, polling_rate: Option<Duration>) -> Self {
+    pub fn from_ingame_client<C: IngameApi + 'static>(
+        ingame_client: C,
+        polling_rate: Option<Duration>,
+    ) -> Self {
         let (start_tx, start_rx) = oneshot::channel::<()>();
         let (events_tx, events_rx) = unbounded_channel();
 
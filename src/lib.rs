@@ -4,19 +4,66 @@
 //!
 //! - [RESTClient](rest::RESTClient): A REST client for the League-Client(LCU) API
 //! - [LcuWebsocketClient](ws::LcuWebsocketClient): Subscription based Websocket API for the League-Client(LCU) API
+//! - [ChampSelectStream](champ_select::ChampSelectStream): The updates of the champ select session, with the pick, ban, swap and selection helpers on the [RESTClient](rest::RESTClient)
+//! - [ChampSelectAutomation](automation::ChampSelectAutomation): Picks, bans, runes, summoner spells and skins by rules per queue and position, with a dry-run mode
+//! - [GameflowPhaseStream](gameflow::GameflowPhaseStream): The transitions of the client from the lobby over champ select to the end of game screen
+//! - [InvitationStream](lobby::InvitationStream): The invitations to other lobbies, with the lobby, invitation and queue search helpers on the [RESTClient](rest::RESTClient)
+//! - [ReadyCheckWatcher](ready_check::ReadyCheckWatcher): Accepts or declines found matches by a [ReadyCheckPolicy](ready_check::ReadyCheckPolicy) and reports dodges and re-queues
 //! - [IngameClient](ingame::IngameClient): A REST client for the LoL-Ingame API
+//! - [SpectatorView](spectator::SpectatorView): The ingame API limited to what observers can access, plus the replay API
+//! - [LazyGameData](model::lazy::LazyGameData): The all game data for frequent polling, parsing players items, runes and summoner spells only when accessed
 //! - [EventStream](ingame::EventStream): A wrapper around polling ingame events implementing the [futures_util::Stream] Trait
+//! - [IngameApi](ingame::IngameApi): The Ingame API as a trait, implemented by the [IngameClient](ingame::IngameClient) and the [Playback](playback::Playback) of recorded games
+//! - [Recorder](recorder::Recorder): Records a whole game into a file that can be read again with a [RecordingReader](recorder::RecordingReader)
+//! - [TeamSummary](analysis::team::TeamSummary): Team-level aggregates computed from the ingame data
+//! - [ArenaView](analysis::arena::ArenaView): Subteams, rounds and standings of an Arena game
+//! - [PlayerTimeline](analysis::timeline::PlayerTimeline): Per-minute metrics of a player built from repeated samples
+//! - [HighlightDetector](analysis::highlights::HighlightDetector): Finds highlights in the game events to clip or seek the replay to
+//! - [CombatCalculator](analysis::combat::CombatCalculator): Effective health, mitigated damage and DPS from the stats of the active player
+//! - [ItemTracker](analysis::items::ItemTracker): Item purchases, component upgrades, power spikes and build paths of every player
+//! - [RespawnTracker](analysis::respawn::RespawnTracker): Deaths, predicted respawn times and numbers advantages like `5v3 for the next 12s`
+//! - [GameSummary](analysis::summary::GameSummary): Result, scoreboard, builds and objective timeline of a finished game as JSON or Markdown
+//! - [StructureStatus](analysis::structures::StructureStatus): Standing turrets and inhibitors per lane, with the lanes, tiers and positions of the [map](model::map)
+//! - [GameClock](analysis::clock::GameClock): Converts between game time and [Instant](std::time::Instant)/[SystemTime](std::time::SystemTime), detecting pauses and seeks
+//! - [StaticData](static_data::StaticData): Items, runes, summoner spells and champions loaded from Data Dragon files
+//!
+//! With the `testing` feature the [testing] module provides mock servers and a game simulator to test against without a running League client
 //!
 //! If you are looking for a Rust library for the Riot Games API see [Riven](https://docs.rs/riven/latest/riven/)
 
+/// Views computed from the ingame data, e.g. the [TeamSummary](analysis::team::TeamSummary)
+pub mod analysis;
 /// Error types for the whole library
 pub mod error;
-/// Contains the [IngameClient](ingame::IngameClient) and [IngameClient](ingame::EventStream)
+/// Contains the [ChampSelectAutomation](automation::ChampSelectAutomation) and its [AutomationConfig](automation::AutomationConfig)
+pub mod automation;
+/// Contains the [ChampSelectStream](champ_select::ChampSelectStream)
+pub mod champ_select;
+/// Contains the [GameflowPhaseStream](gameflow::GameflowPhaseStream)
+pub mod gameflow;
+/// Contains the [IngameClient](ingame::IngameClient), [EventStream](ingame::EventStream) and the [IngameApi](ingame::IngameApi) trait
 pub mod ingame;
+/// Contains the [InvitationStream](lobby::InvitationStream)
+pub mod lobby;
 /// Contains all the type definitions for the data returned by the library
 pub mod model;
+/// Compares the OpenAPI description of the ingame API against the models, see [compare_spec](openapi::compare_spec)
+pub mod openapi;
+/// Contains the [Playback](playback::Playback)
+pub mod playback;
+/// Contains the [ReadyCheckWatcher](ready_check::ReadyCheckWatcher) and its [ReadyCheckPolicy](ready_check::ReadyCheckPolicy)
+pub mod ready_check;
+/// Contains the [Recorder](recorder::Recorder) and [RecordingReader](recorder::RecordingReader)
+pub mod recorder;
 /// Contains the [RESTClient](rest::RESTClient)
 pub mod rest;
+/// Contains the [SpectatorView](spectator::SpectatorView)
+pub mod spectator;
+/// Contains the [StaticData](static_data::StaticData) resolver
+pub mod static_data;
+/// Mock servers of the APIs and a game simulator for tests, only available with the `testing` feature
+#[cfg(feature = "testing")]
+pub mod testing;
 mod utils;
 /// Contains the [LcuWebsocketClient](ws::LcuWebsocketClient)
 pub mod ws;
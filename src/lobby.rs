@@ -0,0 +1,86 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::{Stream, StreamExt};
+
+use crate::{
+    error::LcuError,
+    model::lcu::lobby::{InvitationState, ReceivedInvitation},
+    rest::RESTClient,
+    ws::{connect_to_client, JsonApiEvents, LcuWebsocketClient},
+};
+
+const INVITATIONS_ENDPOINT: &str = "/lol-lobby/v2/received-invitations";
+
+/// A [Stream] of the pending [ReceivedInvitation]s, every invitation is yielded once \
+/// The invitations that are already pending when the stream is created come first
+pub struct InvitationStream {
+    events: JsonApiEvents,
+    seen: HashSet<String>,
+    pending: VecDeque<ReceivedInvitation>,
+}
+
+impl InvitationStream {
+    /// Subscribe `ws_client` to the received invitations and get the pending ones with `rest_client`
+    pub async fn new(
+        rest_client: &RESTClient,
+        ws_client: LcuWebsocketClient,
+    ) -> Result<Self, LcuError> {
+        let events = JsonApiEvents::subscribe(ws_client, INVITATIONS_ENDPOINT).await?;
+        let mut stream = Self {
+            events,
+            seen: HashSet::new(),
+            pending: VecDeque::new(),
+        };
+        stream.update(rest_client.received_invitations().await?);
+        Ok(stream)
+    }
+
+    /// Connect to the running League client and create the stream
+    pub async fn connect() -> Result<Self, LcuError> {
+        let (rest_client, ws_client) = connect_to_client().await?;
+        Self::new(&rest_client, ws_client).await
+    }
+
+    /// The client always sends all received invitations, queue the new pending ones
+    fn update(&mut self, invitations: Vec<ReceivedInvitation>) {
+        // forget the invitations that are gone so the list doesn't grow
+        self.seen
+            .retain(|id| invitations.iter().any(|i| &i.invitation_id == id));
+        for invitation in invitations {
+            if invitation.state == InvitationState::Pending
+                && self.seen.insert(invitation.invitation_id.clone())
+            {
+                self.pending.push_back(invitation);
+            }
+        }
+    }
+}
+
+impl Stream for InvitationStream {
+    type Item = ReceivedInvitation;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(invitation) = self.pending.pop_front() {
+                return Poll::Ready(Some(invitation));
+            }
+            let event = match self.events.poll_next_unpin(cx) {
+                Poll::Ready(Some(event)) => event,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+            let invitations = match event.event_type.as_str() {
+                "Delete" => Vec::new(),
+                _ => match serde_json::from_value(event.data) {
+                    Ok(invitations) => invitations,
+                    Err(_) => continue,
+                },
+            };
+            self.update(invitations);
+        }
+    }
+}
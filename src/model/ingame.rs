@@ -1,7 +1,7 @@
 use std::fmt;
 
 use derive_more::Display;
-use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
 
 pub type SummonerName = String;
 pub type Time = f64;
@@ -10,11 +10,17 @@ pub type Time = f64;
 #[serde(rename_all = "camelCase")]
 pub struct AllGameData {
     /// only available in live game - None in spectator mode
-    #[serde(deserialize_with = "treat_error_as_none")]
+    #[serde(
+        deserialize_with = "treat_error_as_none",
+        serialize_with = "serialize_active_player"
+    )]
     pub active_player: Option<ActivePlayer>,
     #[serde(deserialize_with = "deserialize_players")]
     pub all_players: Vec<Player>,
-    #[serde(deserialize_with = "deserialize_events")]
+    #[serde(
+        deserialize_with = "deserialize_events",
+        serialize_with = "serialize_events"
+    )]
     pub events: Vec<GameEvent>,
     pub game_data: GameStats,
 }
@@ -61,6 +67,36 @@ where
     Ok(events)
 }
 
+/// in spectator mode the API returns an error instead of the active player
+fn serialize_active_player<S: Serializer>(
+    active_player: &Option<ActivePlayer>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct SpectatorError {
+        error: &'static str,
+    }
+
+    match active_player {
+        Some(active_player) => active_player.serialize(serializer),
+        None => SpectatorError {
+            error: "Spectator mode doesn't currently support this feature",
+        }
+        .serialize(serializer),
+    }
+}
+
+/// the API wraps the events in an object
+fn serialize_events<S: Serializer>(events: &[GameEvent], serializer: S) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct GameEventsTmp<'a> {
+        events: &'a [GameEvent],
+    }
+
+    GameEventsTmp { events }.serialize(serializer)
+}
+
 pub(crate) fn treat_error_as_none<'de, D, T: DeserializeOwned>(
     deserializer: D,
 ) -> Result<Option<T>, D::Error>
@@ -92,6 +128,7 @@ pub struct ActivePlayer {
     pub riot_id: RiotId,
     pub abilities: PlayerAbilities,
     pub champion_stats: PlayerChampionStats,
+    #[serde(serialize_with = "serialize_f32")]
     pub current_gold: Gold,
     #[serde(rename = "fullRunes")]
     pub runes: FullPlayerRunes,
@@ -165,36 +202,66 @@ pub type Tenacity = f32;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PlayerChampionStats {
+    #[serde(serialize_with = "serialize_f32")]
     pub ability_haste: AbilityHaste,
+    #[serde(serialize_with = "serialize_f32")]
     pub ability_power: AbilityPower,
+    #[serde(serialize_with = "serialize_f32")]
     pub armor: Armor,
+    #[serde(serialize_with = "serialize_f32")]
     pub armor_penetration_flat: ArmorPenetrationFlat,
+    #[serde(serialize_with = "serialize_f32")]
     pub armor_penetration_percent: ArmorPenetrationPercent,
+    #[serde(serialize_with = "serialize_f32")]
     pub attack_damage: AttackDamage,
+    #[serde(serialize_with = "serialize_f32")]
     pub attack_range: AttackRange,
+    #[serde(serialize_with = "serialize_f32")]
     pub attack_speed: AttackSpeed,
+    #[serde(serialize_with = "serialize_f32")]
     pub bonus_armor_penetration_percent: BonusArmorPenetrationPercent,
+    #[serde(serialize_with = "serialize_f32")]
     pub bonus_magic_penetration_percent: BonusMagicPenetrationPercent,
+    #[serde(serialize_with = "serialize_f32")]
     pub crit_chance: CritChance,
+    #[serde(serialize_with = "serialize_f32")]
     pub crit_damage: CritDamage,
+    #[serde(serialize_with = "serialize_f32")]
     pub current_health: CurrentHealth,
+    #[serde(serialize_with = "serialize_f32")]
     pub heal_shield_power: HealShieldPower,
+    #[serde(serialize_with = "serialize_f32")]
     pub health_regen_rate: HealthRegenRate,
+    #[serde(serialize_with = "serialize_f32")]
     pub life_steal: LifeSteal,
+    #[serde(serialize_with = "serialize_f32")]
     pub magic_lethality: MagicLethality,
+    #[serde(serialize_with = "serialize_f32")]
     pub magic_penetration_flat: MagicPenetrationFlat,
+    #[serde(serialize_with = "serialize_f32")]
     pub magic_penetration_percent: MagicPenetrationPercent,
+    #[serde(serialize_with = "serialize_f32")]
     pub magic_resist: MagicResist,
+    #[serde(serialize_with = "serialize_f32")]
     pub max_health: MaxHealth,
+    #[serde(serialize_with = "serialize_f32")]
     pub move_speed: MoveSpeed,
+    #[serde(serialize_with = "serialize_f32")]
     pub omnivamp: Omnivamp,
+    #[serde(serialize_with = "serialize_f32")]
     pub physical_lethality: PhysicalLethality,
+    #[serde(serialize_with = "serialize_f32")]
     pub physical_vamp: PhysicalVamp,
+    #[serde(serialize_with = "serialize_f32")]
     pub resource_max: ResourceMax,
+    #[serde(serialize_with = "serialize_f32")]
     pub resource_regen_rate: ResourceRegenRate,
     pub resource_type: ResourceType,
+    #[serde(serialize_with = "serialize_f32")]
     pub resource_value: ResourceValue,
+    #[serde(serialize_with = "serialize_f32")]
     pub spell_vamp: SpellVamp,
+    #[serde(serialize_with = "serialize_f32")]
     pub tenacity: Tenacity,
 }
 
@@ -222,7 +289,7 @@ pub enum ResourceType {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all_fields = "camelCase")]
 #[serde(untagged)]
 pub enum FullPlayerRunes {
     Runes {
@@ -289,8 +356,10 @@ pub struct Player {
     pub champion_name: ChampionName,
     pub raw_champion_name: String,
     /// only available in live game - None in spectator mode
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub skin_name: Option<SkinName>,
     /// only available in live game - None in spectator mode
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub raw_skin_name: Option<String>,
     #[serde(rename = "skinID")]
     pub skin_id: SkinId,
@@ -363,11 +432,12 @@ pub struct PlayerScores {
     pub deaths: Deaths,
     pub assists: Assists,
     pub creep_score: CreepScore,
+    #[serde(serialize_with = "serialize_f32")]
     pub ward_score: WardScore,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all_fields = "camelCase")]
 #[serde(untagged)]
 pub enum SummonerSpells {
     SummonerSpells {
@@ -387,7 +457,7 @@ pub struct SummonerSpell {
     pub raw_display_name: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum TeamId {
     /// Blue / Left Side
@@ -499,7 +569,10 @@ pub struct HordeKill {
     pub event_id: EventId,
     pub event_time: Time,
     pub killer_name: Killer,
-    #[serde(deserialize_with = "deserialize_bool")]
+    #[serde(
+        deserialize_with = "deserialize_bool",
+        serialize_with = "serialize_bool"
+    )]
     pub stolen: bool,
 }
 
@@ -511,7 +584,10 @@ pub struct BaronKill {
     pub event_id: EventId,
     pub event_time: Time,
     pub killer_name: Killer,
-    #[serde(deserialize_with = "deserialize_bool")]
+    #[serde(
+        deserialize_with = "deserialize_bool",
+        serialize_with = "serialize_bool"
+    )]
     pub stolen: bool,
 }
 
@@ -535,7 +611,10 @@ pub struct DragonKill {
     pub event_id: EventId,
     pub event_time: Time,
     pub killer_name: Killer,
-    #[serde(deserialize_with = "deserialize_bool")]
+    #[serde(
+        deserialize_with = "deserialize_bool",
+        serialize_with = "serialize_bool"
+    )]
     pub stolen: bool,
 }
 
@@ -582,7 +661,10 @@ pub struct HeraldKill {
     pub event_id: EventId,
     pub event_time: Time,
     pub killer_name: Killer,
-    #[serde(deserialize_with = "deserialize_bool")]
+    #[serde(
+        deserialize_with = "deserialize_bool",
+        serialize_with = "serialize_bool"
+    )]
     pub stolen: bool,
 }
 
@@ -647,7 +729,7 @@ pub struct TurretKilled {
 }
 
 #[derive(Debug, Display, Clone, Serialize, Deserialize)]
-#[serde(from = "String")]
+#[serde(from = "String", into = "String")]
 pub enum DragonType {
     Infernal,
     Ocean,
@@ -660,97 +742,146 @@ pub enum DragonType {
 
 impl From<String> for DragonType {
     fn from(s: String) -> Self {
-        match s.as_str() {
-            "Fire" | "SRU_Dragon_Fire" => DragonType::Infernal,
-            "Water" | "SRU_Dragon_Water" => DragonType::Ocean,
-            "Earth" | "SRU_Dragon_Earth" => DragonType::Mountain,
-            "Air" | "SRU_Dragon_Air" => DragonType::Cloud,
-            "Hextech" | "SRU_Dragon_Hextech" => DragonType::Hextech,
-            "Chemtech" | "SRU_Dragon_Chemtech" => DragonType::Chemtech,
-            "Elder" | "SRU_Dragon_Elder" => DragonType::Elder,
-            _ => unreachable!("unknown dragon type"),
+        dragon_type(s.trim_start_matches("SRU_Dragon_")).expect("unknown dragon type")
+    }
+}
+
+fn dragon_type(s: &str) -> Option<DragonType> {
+    Some(match s {
+        "Fire" => DragonType::Infernal,
+        "Water" => DragonType::Ocean,
+        "Earth" => DragonType::Mountain,
+        "Air" => DragonType::Cloud,
+        "Hextech" => DragonType::Hextech,
+        "Chemtech" => DragonType::Chemtech,
+        "Elder" => DragonType::Elder,
+        _ => return None,
+    })
+}
+
+impl From<DragonType> for String {
+    fn from(dragon_type: DragonType) -> Self {
+        match dragon_type {
+            DragonType::Infernal => "Fire",
+            DragonType::Ocean => "Water",
+            DragonType::Mountain => "Earth",
+            DragonType::Cloud => "Air",
+            DragonType::Hextech => "Hextech",
+            DragonType::Chemtech => "Chemtech",
+            DragonType::Elder => "Elder",
         }
+        .to_string()
     }
 }
 
-#[derive(Debug, Display, Clone, Serialize, Deserialize)]
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GameResult {
     Win,
     Lose,
 }
 
+/// Monsters keep the rest of their name after the monster, i.e. the camp number, \
+/// e.g. `12.1.1` of `SRU_Baron12.1.1` or `Mini8.1.2` of `SRU_MurkwolfMini8.1.2`
 #[derive(Debug, Display, Clone, Serialize, Deserialize)]
-#[serde(from = "String")]
+#[serde(from = "String", into = "String")]
 pub enum Killer {
-    Minion,
-    Dragon(DragonType),
-    Gromp,
-    Blue,
-    Murkwolf,
-    Razorbeak,
-    Red,
-    Krug,
-    RiftHerald,
-    Baron,
+    /// The full name of the minion, e.g. `Minion_T100L0S25N0155`
+    Minion(String),
+    #[display(fmt = "{}", _0)]
+    Dragon(DragonType, String),
+    #[display(fmt = "Gromp")]
+    Gromp(String),
+    #[display(fmt = "Blue")]
+    Blue(String),
+    #[display(fmt = "Murkwolf")]
+    Murkwolf(String),
+    #[display(fmt = "Razorbeak")]
+    Razorbeak(String),
+    #[display(fmt = "Red")]
+    Red(String),
+    #[display(fmt = "Krug")]
+    Krug(String),
+    #[display(fmt = "RiftHerald")]
+    RiftHerald(String),
+    #[display(fmt = "Baron")]
+    Baron(String),
     Turret(Turret),
     Summoner(SummonerName),
 }
 
 impl From<String> for Killer {
     fn from(s: String) -> Self {
-        // SRU_Dragon_{type}
-        if let Ok(dragon) = serde_json::from_str::<DragonType>(&s) {
-            return Killer::Dragon(dragon);
+        // SRU_Dragon_{type}{camp}
+        if let Some(dragon) = s.strip_prefix("SRU_Dragon_") {
+            let types = [
+                "Fire", "Water", "Earth", "Air", "Hextech", "Chemtech", "Elder",
+            ];
+            if let Some((camp, dragon_type)) = types.iter().find_map(|name| {
+                let camp = dragon.strip_prefix(name)?;
+                Some((camp, dragon_type(name)?))
+            }) {
+                return Killer::Dragon(dragon_type, camp.to_string());
+            }
         }
         // Turret_{}_{}_{}_{}
-        if let Ok(turret) = serde_json::from_str::<Turret>(&s) {
-            return Killer::Turret(turret);
+        match Turret::deserialize(serde_json::Value::String(s.clone())) {
+            Ok(Turret::Unknown) | Err(_) => {}
+            Ok(turret) => return Killer::Turret(turret),
         }
         // Minion_{}
         if s.starts_with("Minion") {
-            return Killer::Minion;
-        }
-        // SRU_RiftHerald{}
-        if s.contains("RiftHerald") {
-            return Killer::RiftHerald;
-        }
-        // SRU_Baron{}
-        if s.contains("Baron") {
-            return Killer::RiftHerald;
-        }
-        // SRU_Gromp{}
-        if s.contains("Gromp") {
-            return Killer::Gromp;
+            return Killer::Minion(s);
         }
-        // SRU_Blue{}
-        if s.contains("Blue") {
-            return Killer::Blue;
-        }
-        // SRU_Murkwolf{}
-        if s.contains("Murkwolf") {
-            return Killer::Murkwolf;
-        }
-        // SRU_Razorbeak{}
-        if s.contains("Razorbeak") {
-            return Killer::Razorbeak;
-        }
-        // SRU_Red{}
-        if s.contains("Red") {
-            return Killer::Red;
-        }
-        // SRU_Krug{}
-        if s.contains("Krug") {
-            return Killer::Krug;
+        // SRU_{monster}{camp}
+        if let Some(name) = s.strip_prefix("SRU_") {
+            let monsters = [
+                ("RiftHerald", Killer::RiftHerald as fn(String) -> Killer),
+                ("Baron", Killer::Baron),
+                ("Gromp", Killer::Gromp),
+                ("Blue", Killer::Blue),
+                ("Murkwolf", Killer::Murkwolf),
+                ("Razorbeak", Killer::Razorbeak),
+                ("Red", Killer::Red),
+                ("Krug", Killer::Krug),
+            ];
+            if let Some((camp, monster)) = monsters
+                .into_iter()
+                .find_map(|(monster, killer)| Some((name.strip_prefix(monster)?, killer)))
+            {
+                return monster(camp.to_string());
+            }
         }
 
         // if none of the others its 99% the summoner name
-        Killer::Summoner(s.to_string())
+        Killer::Summoner(s)
+    }
+}
+
+impl From<Killer> for String {
+    fn from(killer: Killer) -> Self {
+        match killer {
+            Killer::Minion(name) | Killer::Summoner(name) => name,
+            Killer::Dragon(dragon, camp) => format!("SRU_Dragon_{}{camp}", String::from(dragon)),
+            Killer::Turret(turret) => serde_json::to_value(turret)
+                .ok()
+                .and_then(|turret| turret.as_str().map(ToString::to_string))
+                .unwrap_or_default(),
+            Killer::Gromp(camp) => format!("SRU_Gromp{camp}"),
+            Killer::Blue(camp) => format!("SRU_Blue{camp}"),
+            Killer::Murkwolf(camp) => format!("SRU_Murkwolf{camp}"),
+            Killer::Razorbeak(camp) => format!("SRU_Razorbeak{camp}"),
+            Killer::Red(camp) => format!("SRU_Red{camp}"),
+            Killer::Krug(camp) => format!("SRU_Krug{camp}"),
+            Killer::RiftHerald(camp) => format!("SRU_RiftHerald{camp}"),
+            Killer::Baron(camp) => format!("SRU_Baron{camp}"),
+        }
     }
 }
 
 /// Blue Team is the left side / Red Team is the right side \
-/// These don't have better names since the names are shared between game modes
-#[derive(Debug, Display, Clone, Serialize, Deserialize)]
+/// These don't have better names since the names are shared between game modes, \
+/// their lane, tier and position on a map are resolved in the [map](crate::model::map) module
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Turret {
     // --- TEAM1 ---
     /// *Summoner's Rift*: Blue Team (left) Upper Nexus Turret
@@ -853,8 +984,9 @@ pub enum Turret {
 }
 
 /// Blue Team is the left side / Red Team is the right side \
-/// These don't have better names since the names are shared between game modes
-#[derive(Debug, Display, Clone, Serialize, Deserialize)]
+/// These don't have better names since the names are shared between game modes, \
+/// their lane, tier and position on a map are resolved in the [map](crate::model::map) module
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Inhibitor {
     /// *Summoner's Rift*: Blue Team Top Inhibitor
     #[serde(rename = "Barracks_T1_L1")]
@@ -889,6 +1021,15 @@ fn deserialize_bool<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool,
     })
 }
 
+fn serialize_bool<S: Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(if *value { "True" } else { "False" })
+}
+
+/// the API sends the f32 values widened to f64, serializing them as f32 would shorten the digits
+fn serialize_f32<S: Serializer>(value: &f32, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(f64::from(*value))
+}
+
 pub type MapNumber = i32;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
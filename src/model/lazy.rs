@@ -0,0 +1,83 @@
+use serde::{Deserialize, Deserializer};
+use serde_json::value::RawValue;
+
+use crate::model::ingame::*;
+
+/// The [AllGameData] with the rarely used parts kept as raw JSON, \
+/// parsed on demand by the accessor methods
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LazyGameData {
+    active_player: Box<RawValue>,
+    #[serde(deserialize_with = "deserialize_lazy_players")]
+    pub all_players: Vec<LazyPlayer>,
+    /// only the events since the requested `event_id`
+    #[serde(deserialize_with = "deserialize_events")]
+    pub events: Vec<GameEvent>,
+    pub game_data: GameStats,
+    /// the `event_id` the data was requested with
+    #[serde(skip)]
+    pub(crate) requested_event_id: EventId,
+}
+
+impl LazyGameData {
+    /// only available in live game - None in spectator mode
+    pub fn active_player(&self) -> Option<ActivePlayer> {
+        serde_json::from_str(self.active_player.get()).ok()
+    }
+
+    /// The `event_id` to request to only get events after these, the requested one if there are no new events \
+    /// Events unknown to the crate are skipped, so a trailing one gets requested again
+    pub fn next_event_id(&self) -> EventId {
+        self.events
+            .last()
+            .map_or(self.requested_event_id, |e| e.get_event_id() + 1)
+    }
+}
+
+/// A [Player] with the items, runes and summoner spells kept as raw JSON
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LazyPlayer {
+    pub summoner_name: SummonerName,
+    #[serde(flatten)]
+    pub riot_id: RiotId,
+    pub champion_name: ChampionName,
+    pub is_dead: bool,
+    pub level: Level,
+    pub position: Position,
+    pub respawn_timer: Time,
+    pub scores: PlayerScores,
+    pub team: TeamId,
+    items: Box<RawValue>,
+    runes: Box<RawValue>,
+    summoner_spells: Box<RawValue>,
+}
+
+impl LazyPlayer {
+    pub fn items(&self) -> Result<Vec<PlayerItem>, serde_json::Error> {
+        serde_json::from_str(self.items.get())
+    }
+
+    pub fn runes(&self) -> Result<Option<PlayerRunes>, serde_json::Error> {
+        serde_json::from_str(self.runes.get())
+    }
+
+    pub fn summoner_spells(&self) -> Result<SummonerSpells, serde_json::Error> {
+        serde_json::from_str(self.summoner_spells.get())
+    }
+}
+
+/// Like [deserialize_players] the Arena special events are skipped, \
+/// the players go through [RawValue] as the untagged workaround can't buffer one
+fn deserialize_lazy_players<'de, D>(deserializer: D) -> Result<Vec<LazyPlayer>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let players = Vec::<Box<RawValue>>::deserialize(deserializer)?
+        .iter()
+        .filter_map(|player| serde_json::from_str(player.get()).ok())
+        .collect::<Vec<_>>();
+
+    Ok(players)
+}
@@ -0,0 +1,240 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// `GET /lol-champ-select/v1/session`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChampSelectSession {
+    pub game_id: u64,
+    pub timer: ChampSelectTimer,
+    pub local_player_cell_id: i64,
+    pub my_team: Vec<ChampSelectPlayer>,
+    pub their_team: Vec<ChampSelectPlayer>,
+    /// the pick, ban and ten bans reveal turns, every group is played at the same time
+    pub actions: Vec<Vec<ChampSelectAction>>,
+    pub bans: ChampSelectBans,
+    pub bench_enabled: bool,
+    /// the champions that can be swapped in with a reroll, e.g. in ARAM
+    pub bench_champions: Vec<BenchChampion>,
+    pub allow_rerolling: bool,
+    pub rerolls_remaining: i32,
+    /// trades of champions with a teammate, after the picks
+    pub trades: Vec<ChampSelectSwap>,
+    /// swaps of the pick turn with a teammate, before the picks
+    pub pick_order_swaps: Vec<ChampSelectSwap>,
+    /// swaps of the assigned position with a teammate, in queues with positions
+    pub position_swaps: Vec<ChampSelectSwap>,
+    pub has_simultaneous_bans: bool,
+    pub has_simultaneous_picks: bool,
+    pub is_custom_game: bool,
+    pub is_spectating: bool,
+    pub skip_champion_select: bool,
+    /// increases with every update of the session
+    pub counter: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChampSelectPlayer {
+    pub cell_id: i64,
+    /// 0 while no champion is picked
+    pub champion_id: i32,
+    /// the hovered champion before the pick turn of the player
+    pub champion_pick_intent: i32,
+    /// e.g. `top`, `jungle`, `middle`, `bottom` or `utility`, empty in queues without positions
+    pub assigned_position: String,
+    pub summoner_id: u64,
+    pub puuid: String,
+    pub game_name: String,
+    pub tag_line: String,
+    pub spell1_id: u64,
+    pub spell2_id: u64,
+    pub selected_skin_id: i32,
+    pub ward_skin_id: i64,
+    /// 1 for the blue and 2 for the red side
+    pub team: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChampSelectAction {
+    pub id: i64,
+    pub actor_cell_id: i64,
+    pub champion_id: i32,
+    pub completed: bool,
+    pub is_ally_action: bool,
+    pub is_in_progress: bool,
+    pub pick_turn: i32,
+    #[serde(rename = "type")]
+    pub action_type: ChampSelectActionType,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChampSelectActionType {
+    #[default]
+    Pick,
+    Ban,
+    /// Shows the bans of both teams after simultaneous bans
+    TenBansReveal,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChampSelectTimer {
+    /// milliseconds left in the phase at `internal_now_in_epoch_ms`
+    pub adjusted_time_left_in_phase: i64,
+    pub total_time_in_phase: i64,
+    pub internal_now_in_epoch_ms: i64,
+    pub is_infinite: bool,
+    pub phase: ChampSelectPhase,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChampSelectPhase {
+    /// Hovering champions before the bans, only in draft queues
+    Planning,
+    #[default]
+    BanPick,
+    /// All champions are picked, trades and skins can be changed until the game starts
+    Finalization,
+    GameStarting,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChampSelectBans {
+    pub my_team_bans: Vec<i32>,
+    pub their_team_bans: Vec<i32>,
+    pub num_bans: i32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BenchChampion {
+    pub champion_id: i32,
+    pub is_priority: bool,
+}
+
+/// A trade, pick order swap or position swap with the teammate in `cell_id`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChampSelectSwap {
+    pub id: i64,
+    pub cell_id: i64,
+    pub state: SwapState,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SwapState {
+    /// The swap can be requested
+    Available,
+    /// Another swap is pending
+    Busy,
+    #[default]
+    Invalid,
+    /// The teammate requested the swap, it can be accepted or declined
+    Received,
+    /// The swap was requested and waits for the teammate
+    Sent,
+    Accepted,
+    Declined,
+    Cancelled,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The kinds of [ChampSelectSwap]s, each with its own endpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwapKind {
+    Trade,
+    PickOrder,
+    Position,
+}
+
+impl SwapKind {
+    /// The path segment of the swap endpoints, e.g. `/lol-champ-select/v1/session/trades/{id}/request`
+    pub(crate) fn endpoint(&self) -> &'static str {
+        match self {
+            SwapKind::Trade => "trades",
+            SwapKind::PickOrder => "pick-order-swaps",
+            SwapKind::Position => "position-swaps",
+        }
+    }
+}
+
+impl ChampSelectTimer {
+    /// The time the client sent the timer at
+    pub fn sent_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.internal_now_in_epoch_ms.max(0) as u64)
+    }
+
+    /// The time left in the phase at `now`, `None` if the phase has no time limit
+    pub fn time_left_at(&self, now: SystemTime) -> Option<Duration> {
+        if self.is_infinite {
+            return None;
+        }
+        let elapsed = now.duration_since(self.sent_at()).unwrap_or_default();
+        Some(
+            Duration::from_millis(self.adjusted_time_left_in_phase.max(0) as u64)
+                .saturating_sub(elapsed),
+        )
+    }
+}
+
+/// An update of the [ChampSelectSession] received over the websocket
+#[derive(Debug, Clone)]
+pub enum ChampSelectUpdate {
+    /// Champ select started or the session changed
+    Session(Box<ChampSelectSession>),
+    /// Champ select ended, because the game starts or someone dodged
+    Ended,
+}
+
+impl ChampSelectSession {
+    /// The player of the client, `None` while spectating
+    pub fn local_player(&self) -> Option<&ChampSelectPlayer> {
+        self.my_team
+            .iter()
+            .find(|p| p.cell_id == self.local_player_cell_id)
+    }
+
+    /// All actions in the order they are played
+    pub fn all_actions(&self) -> impl Iterator<Item = &ChampSelectAction> {
+        self.actions.iter().flatten()
+    }
+
+    /// The action the local player has to complete now, e.g. to hover and lock in a champion
+    pub fn current_action(&self) -> Option<&ChampSelectAction> {
+        self.all_actions().find(|a| {
+            a.actor_cell_id == self.local_player_cell_id && a.is_in_progress && !a.completed
+        })
+    }
+
+    /// The champion ids banned by both teams
+    pub fn banned_champions(&self) -> impl Iterator<Item = i32> + '_ {
+        self.all_actions()
+            .filter(|a| a.action_type == ChampSelectActionType::Ban && a.completed)
+            .map(|a| a.champion_id)
+            .filter(|id| *id != 0)
+    }
+
+    /// The swaps of all kinds the local player has received and can accept or decline
+    pub fn received_swaps(&self) -> impl Iterator<Item = (SwapKind, &ChampSelectSwap)> {
+        [
+            (SwapKind::Trade, &self.trades),
+            (SwapKind::PickOrder, &self.pick_order_swaps),
+            (SwapKind::Position, &self.position_swaps),
+        ]
+        .into_iter()
+        .flat_map(|(kind, swaps)| swaps.iter().map(move |swap| (kind, swap)))
+        .filter(|(_, swap)| swap.state == SwapState::Received)
+    }
+}
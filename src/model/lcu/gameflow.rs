@@ -0,0 +1,100 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The phase of the client, `GET /lol-gameflow/v1/gameflow-phase`
+#[derive(Debug, Display, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GameflowPhase {
+    /// Not in a lobby or game
+    #[default]
+    None,
+    Lobby,
+    Matchmaking,
+    CheckedIntoTournament,
+    /// A match was found and has to be accepted
+    ReadyCheck,
+    ChampSelect,
+    /// The game client is launching
+    GameStart,
+    FailedToLaunch,
+    InProgress,
+    /// The game client was closed during the game
+    Reconnect,
+    WaitingForStats,
+    PreEndOfGame,
+    EndOfGame,
+    TerminatedInError,
+    /// The riot documentation specifying the phases is incomplete => add Unknown to catch deserialization errors
+    #[serde(other)]
+    Unknown,
+}
+
+/// `GET /lol-gameflow/v1/session`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GameflowSession {
+    pub phase: GameflowPhase,
+    pub game_client: GameflowGameClient,
+    pub game_data: GameflowGameData,
+    pub map: GameflowMap,
+}
+
+/// The connection of the game client
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GameflowGameClient {
+    pub observer_server_ip: String,
+    pub observer_server_port: u16,
+    pub running: bool,
+    pub server_ip: String,
+    pub server_port: u16,
+    pub visible: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GameflowGameData {
+    /// 0 before the game was created
+    pub game_id: u64,
+    pub game_name: String,
+    pub is_custom_game: bool,
+    pub password: String,
+    pub queue: GameflowQueue,
+    pub spectators_allowed: bool,
+    /// the players of the teams, their fields differ between queues and phases
+    pub team_one: Vec<Value>,
+    pub team_two: Vec<Value>,
+    pub player_champion_selections: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GameflowQueue {
+    /// -1 outside of a queue, e.g. 420 for Ranked Solo/Duo or 450 for ARAM
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    /// e.g. `CLASSIC` or `ARAM`
+    pub game_mode: String,
+    pub map_id: i32,
+    pub is_ranked: bool,
+    /// e.g. `RANKED_SOLO_5x5`
+    #[serde(rename = "type")]
+    pub queue_type: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GameflowMap {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub game_mode: String,
+}
+
+/// A change of the [GameflowPhase]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameflowTransition {
+    pub previous: GameflowPhase,
+    pub next: GameflowPhase,
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+/// `GET /lol-lobby/v2/lobby`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Lobby {
+    pub party_id: String,
+    pub party_type: String,
+    pub can_start_activity: bool,
+    pub game_config: LobbyGameConfig,
+    pub local_member: LobbyMember,
+    pub members: Vec<LobbyMember>,
+    /// the invitations sent from the lobby
+    pub invitations: Vec<LobbyInvitation>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LobbyGameConfig {
+    pub queue_id: i32,
+    pub game_mode: String,
+    pub map_id: i32,
+    pub is_custom: bool,
+    pub max_lobby_size: i32,
+    pub max_team_size: i32,
+    /// whether the members choose their [LobbyPosition]s
+    pub show_position_selector: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LobbyMember {
+    pub summoner_id: u64,
+    pub puuid: String,
+    pub game_name: String,
+    pub tag_line: String,
+    pub is_leader: bool,
+    pub is_bot: bool,
+    pub ready: bool,
+    pub first_position_preference: LobbyPosition,
+    pub second_position_preference: LobbyPosition,
+    pub allowed_invite_others: bool,
+    pub allowed_kick_others: bool,
+    pub allowed_start_activity: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LobbyPosition {
+    Top,
+    Jungle,
+    Middle,
+    Bottom,
+    Utility,
+    Fill,
+    #[default]
+    Unselected,
+    #[serde(other)]
+    Unknown,
+}
+
+/// An invitation sent from the lobby
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LobbyInvitation {
+    pub invitation_id: String,
+    pub to_summoner_id: u64,
+    pub to_summoner_name: String,
+    pub state: InvitationState,
+    pub timestamp: String,
+}
+
+/// `GET /lol-lobby/v2/received-invitations`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ReceivedInvitation {
+    pub invitation_id: String,
+    pub from_summoner_id: u64,
+    pub from_summoner_name: String,
+    pub can_accept_invitation: bool,
+    pub game_config: LobbyGameConfig,
+    pub state: InvitationState,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InvitationState {
+    #[default]
+    Pending,
+    Requested,
+    Accepted,
+    Joined,
+    Declined,
+    Kicked,
+    OnHold,
+    Error,
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+/// `GET /lol-matchmaking/v1/ready-check`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ReadyCheck {
+    pub state: ReadyCheckState,
+    pub player_response: ReadyCheckResponse,
+    /// seconds since the match was found
+    pub timer: f64,
+    /// the summoner ids of the players that declined
+    pub decliner_ids: Vec<u64>,
+    pub dodge_warning: String,
+    pub suppress_ux: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReadyCheckState {
+    /// No match was found
+    #[default]
+    Invalid,
+    /// The match waits for the players to accept
+    InProgress,
+    EveryoneReady,
+    /// A player outside of the party declined
+    StrangerNotReady,
+    /// A player of the party declined
+    PartyNotReady,
+    Error,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReadyCheckResponse {
+    #[default]
+    None,
+    Accepted,
+    Declined,
+    #[serde(other)]
+    Unknown,
+}
+
+/// `GET /lol-matchmaking/v1/search`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MatchmakingSearch {
+    pub search_state: SearchState,
+    pub queue_id: i32,
+    pub is_currently_in_queue: bool,
+    /// seconds
+    pub estimated_queue_time: f64,
+    /// seconds
+    pub time_in_queue: f64,
+    pub errors: Vec<SearchError>,
+    pub low_priority_data: LowPriorityData,
+    pub dodge_data: DodgeData,
+    pub ready_check: ReadyCheck,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SearchState {
+    #[default]
+    Invalid,
+    /// The search was abandoned because of the low priority queue
+    AbandonedLowPriorityQueue,
+    Canceled,
+    Searching,
+    Found,
+    Error,
+    ServiceError,
+    ServiceShutdown,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Why the search couldn't be started, e.g. a queue dodge penalty
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SearchError {
+    pub id: i64,
+    pub error_type: String,
+    pub message: String,
+    pub penalized_summoner_id: u64,
+    /// seconds
+    pub penalty_time_remaining: f64,
+}
+
+/// The low priority queue a member of the lobby has to wait in after leaving games
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LowPriorityData {
+    pub busted_leaver_access_token: String,
+    pub penalized_summoner_ids: Vec<u64>,
+    /// seconds
+    pub penalty_time: f64,
+    /// seconds
+    pub penalty_time_remaining: f64,
+    pub reason: String,
+}
+
+impl LowPriorityData {
+    /// Whether the lobby has to wait before the search starts
+    pub fn is_penalized(&self) -> bool {
+        !self.penalized_summoner_ids.is_empty() && self.penalty_time_remaining > 0.0
+    }
+}
+
+/// Who dodged the last found match
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DodgeData {
+    pub dodger_id: u64,
+    pub state: String,
+}
@@ -0,0 +1,10 @@
+/// The champ select session with its actions, swaps and bench
+pub mod champ_select;
+/// The phase and session of the gameflow, from the lobby to the end of game screen
+pub mod gameflow;
+/// The lobby, its members and invitations
+pub mod lobby;
+/// The ready check and search of the matchmaking
+pub mod matchmaking;
+/// The summoner of the client and summoner lookups
+pub mod summoner;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// `GET /lol-summoner/v1/current-summoner` or `GET /lol-summoner/v1/summoners`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Summoner {
+    pub summoner_id: u64,
+    pub account_id: u64,
+    pub puuid: String,
+    pub game_name: String,
+    pub tag_line: String,
+    pub summoner_level: u32,
+    pub profile_icon_id: i32,
+}
@@ -0,0 +1,241 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::model::ingame::{Inhibitor, MapName, TeamId, Turret};
+use Lane::{Bot, Mid, Top};
+use TurretTier::{Fountain, Inner, Nexus, Outer};
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Lane {
+    Top,
+    Mid,
+    Bot,
+}
+
+/// The tiers of the turrets from the outermost to the fountain
+#[derive(
+    Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum TurretTier {
+    Outer,
+    Inner,
+    Inhibitor,
+    Nexus,
+    Fountain,
+}
+
+/// A position in game units with the origin at the bottom left corner, the blue side
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MapCoordinates {
+    pub x: f64,
+    pub y: f64,
+}
+
+const fn at(x: f64, y: f64) -> Option<MapCoordinates> {
+    Some(MapCoordinates { x, y })
+}
+
+/// A turret of a map, the fountain turrets have no fixed position
+struct TurretInfo {
+    turret: Turret,
+    lane: Option<Lane>,
+    tier: TurretTier,
+    position: Option<MapCoordinates>,
+}
+
+type InhibitorInfo = (Inhibitor, Lane, MapCoordinates);
+
+const fn turret(
+    turret: Turret,
+    lane: Option<Lane>,
+    tier: TurretTier,
+    position: Option<MapCoordinates>,
+) -> TurretInfo {
+    TurretInfo {
+        turret,
+        lane,
+        tier,
+        position,
+    }
+}
+
+const SUMMONERS_RIFT_TURRETS: [TurretInfo; 24] = [
+    turret(Turret::Team1L03A, Some(Top), Outer, at(981.0, 10441.0)),
+    turret(Turret::Team1L02A, Some(Top), Inner, at(1512.0, 6699.0)),
+    turret(
+        Turret::Team1C06A,
+        Some(Top),
+        TurretTier::Inhibitor,
+        at(1169.0, 4287.0),
+    ),
+    turret(Turret::Team1C05A, Some(Mid), Outer, at(5846.0, 6396.0)),
+    turret(Turret::Team1C04A, Some(Mid), Inner, at(5048.0, 4812.0)),
+    turret(
+        Turret::Team1C03A,
+        Some(Mid),
+        TurretTier::Inhibitor,
+        at(3651.0, 3696.0),
+    ),
+    turret(Turret::Team1R03A, Some(Bot), Outer, at(10504.0, 1029.0)),
+    turret(Turret::Team1R02A, Some(Bot), Inner, at(6919.0, 1483.0)),
+    turret(
+        Turret::Team1C07A,
+        Some(Bot),
+        TurretTier::Inhibitor,
+        at(4281.0, 1253.0),
+    ),
+    turret(Turret::Team1C01A, None, Nexus, at(1748.0, 2270.0)),
+    turret(Turret::Team1C02A, None, Nexus, at(2177.0, 1807.0)),
+    turret(Turret::Team1Fountain, None, Fountain, None),
+    turret(Turret::Team2L03A, Some(Top), Outer, at(4318.0, 13875.0)),
+    turret(Turret::Team2L02A, Some(Top), Inner, at(7943.0, 13411.0)),
+    turret(
+        Turret::Team2L01A,
+        Some(Top),
+        TurretTier::Inhibitor,
+        at(10481.0, 13650.0),
+    ),
+    turret(Turret::Team2C05A, Some(Mid), Outer, at(8955.0, 8510.0)),
+    turret(Turret::Team2C04A, Some(Mid), Inner, at(9767.0, 10113.0)),
+    turret(
+        Turret::Team2C03A,
+        Some(Mid),
+        TurretTier::Inhibitor,
+        at(11134.0, 11207.0),
+    ),
+    turret(Turret::Team2R03A, Some(Bot), Outer, at(13866.0, 4505.0)),
+    turret(Turret::Team2R02A, Some(Bot), Inner, at(13327.0, 8226.0)),
+    turret(
+        Turret::Team2R01A,
+        Some(Bot),
+        TurretTier::Inhibitor,
+        at(13624.0, 10572.0),
+    ),
+    turret(Turret::Team2C01A, None, Nexus, at(13052.0, 12612.0)),
+    turret(Turret::Team2C02A, None, Nexus, at(12611.0, 13084.0)),
+    turret(Turret::Team2Fountain, None, Fountain, None),
+];
+
+const HOWLING_ABYSS_TURRETS: [TurretInfo; 10] = [
+    turret(Turret::Team1C08A, Some(Mid), Outer, at(5448.0, 6169.0)),
+    turret(Turret::Team1C07A, Some(Mid), Inner, at(4361.0, 4650.0)),
+    turret(Turret::Team1C10A, None, Nexus, at(2036.0, 2553.0)),
+    turret(Turret::Team1C09A, None, Nexus, at(2493.0, 2101.0)),
+    turret(Turret::Team1Fountain, None, Fountain, None),
+    turret(Turret::Team2L01A, Some(Mid), Outer, at(7582.0, 6785.0)),
+    turret(Turret::Team2L02A, Some(Mid), Inner, at(8548.0, 8289.0)),
+    turret(Turret::Team2L04A, None, Nexus, at(10481.0, 10868.0)),
+    turret(Turret::Team2L03A, None, Nexus, at(10929.0, 10391.0)),
+    turret(Turret::Team2Fountain, None, Fountain, None),
+];
+
+const fn inhibitor(inhibitor: Inhibitor, lane: Lane, x: f64, y: f64) -> InhibitorInfo {
+    (inhibitor, lane, MapCoordinates { x, y })
+}
+
+const SUMMONERS_RIFT_INHIBITORS: [InhibitorInfo; 6] = [
+    inhibitor(Inhibitor::Team1L1, Top, 1171.0, 3571.0),
+    inhibitor(Inhibitor::Team1C1, Mid, 3203.0, 3208.0),
+    inhibitor(Inhibitor::Team1R1, Bot, 3452.0, 1236.0),
+    inhibitor(Inhibitor::Team2L1, Top, 11261.0, 13676.0),
+    inhibitor(Inhibitor::Team2C1, Mid, 11598.0, 11667.0),
+    inhibitor(Inhibitor::Team2R1, Bot, 13604.0, 11316.0),
+];
+
+const HOWLING_ABYSS_INHIBITORS: [InhibitorInfo; 2] = [
+    inhibitor(Inhibitor::Team1C1, Mid, 3110.0, 3189.0),
+    inhibitor(Inhibitor::Team2C1, Mid, 9689.0, 9524.0),
+];
+
+/// The lanes of a map, none for maps without structure tables
+pub fn lanes(map: &MapName) -> &'static [Lane] {
+    match map {
+        MapName::Map11 => &[Top, Mid, Bot],
+        MapName::Map12 => &[Mid],
+        _ => &[],
+    }
+}
+
+fn turret_table(map: &MapName) -> &'static [TurretInfo] {
+    match map {
+        MapName::Map11 => &SUMMONERS_RIFT_TURRETS,
+        MapName::Map12 => &HOWLING_ABYSS_TURRETS,
+        _ => &[],
+    }
+}
+
+fn inhibitor_table(map: &MapName) -> &'static [InhibitorInfo] {
+    match map {
+        MapName::Map11 => &SUMMONERS_RIFT_INHIBITORS,
+        MapName::Map12 => &HOWLING_ABYSS_INHIBITORS,
+        _ => &[],
+    }
+}
+
+fn team_of_variant(variant: &str) -> Option<TeamId> {
+    if variant.starts_with("Team1") {
+        Some(TeamId::Order)
+    } else if variant.starts_with("Team2") {
+        Some(TeamId::Chaos)
+    } else {
+        None
+    }
+}
+
+/// The lanes, tiers and positions are known for Summoner's Rift and Howling Abyss, \
+/// the same turret can have different ones on the two maps
+impl Turret {
+    /// The turrets of a map from blue to red, outer to inner
+    pub fn on_map(map: &MapName) -> impl Iterator<Item = Turret> {
+        turret_table(map).iter().map(|t| t.turret)
+    }
+
+    /// The team the turret belongs to, `None` for the [Turret::Obelisk] and unknown turrets
+    pub fn team(&self) -> Option<TeamId> {
+        team_of_variant(&self.to_string())
+    }
+
+    /// The lane the turret guards, `None` for the nexus and fountain turrets
+    pub fn lane(&self, map: &MapName) -> Option<Lane> {
+        self.info(map)?.lane
+    }
+
+    pub fn tier(&self, map: &MapName) -> Option<TurretTier> {
+        self.info(map).map(|t| t.tier)
+    }
+
+    /// `None` for the fountain turrets
+    pub fn map_coordinates(&self, map: &MapName) -> Option<MapCoordinates> {
+        self.info(map)?.position
+    }
+
+    fn info(&self, map: &MapName) -> Option<&'static TurretInfo> {
+        turret_table(map).iter().find(|t| t.turret == *self)
+    }
+}
+
+impl Inhibitor {
+    /// The inhibitors of a map from blue to red, top to bottom
+    pub fn on_map(map: &MapName) -> impl Iterator<Item = Inhibitor> {
+        inhibitor_table(map)
+            .iter()
+            .map(|(inhibitor, _, _)| *inhibitor)
+    }
+
+    /// The team the inhibitor belongs to, `None` for unknown inhibitors
+    pub fn team(&self) -> Option<TeamId> {
+        team_of_variant(&self.to_string())
+    }
+
+    pub fn lane(&self, map: &MapName) -> Option<Lane> {
+        self.info(map).map(|(_, lane, _)| *lane)
+    }
+
+    pub fn map_coordinates(&self, map: &MapName) -> Option<MapCoordinates> {
+        self.info(map).map(|(_, _, position)| *position)
+    }
+
+    fn info(&self, map: &MapName) -> Option<&'static InhibitorInfo> {
+        inhibitor_table(map).iter().find(|(i, _, _)| i == self)
+    }
+}
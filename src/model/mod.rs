@@ -1,4 +1,12 @@
 /// Type defintions for the LoL-Ingame API
 pub mod ingame;
+/// Lazily parsed variants of the ingame types for frequent polling
+pub mod lazy;
+/// Type definitions for the League-Client(LCU) REST API
+pub mod lcu;
+/// Lanes, tiers and positions of the turrets and inhibitors
+pub mod map;
+/// Type definitions for the replay API served next to the LoL-Ingame API
+pub mod replay;
 /// Type definitions for the League-Client(LCU) Websocket API
 pub mod ws;
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use super::ingame::Time;
+
+/// The state of the playback from `/replay/playback` \
+/// The replay API is only served in spectated games and replays with `EnableReplayApi=1` in the `game.cfg`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayPlayback {
+    /// the game time up to which the game can be watched
+    pub length: Time,
+    pub paused: bool,
+    pub seeking: bool,
+    pub speed: f32,
+    pub time: Time,
+}
+
+/// The camera and interface from `/replay/render`, reduced to the fields used by this crate
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ReplayRender {
+    pub camera_attached: bool,
+    pub camera_mode: String,
+    pub fog_of_war: bool,
+    pub interface_all: bool,
+    /// the name of the selected unit, empty if nothing is selected
+    pub selection_name: String,
+}
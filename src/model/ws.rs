@@ -73,6 +73,16 @@ impl Display for LcuSubscriptionType {
     }
 }
 
+impl LcuSubscriptionType {
+    /// Whether events received with this type belong to the [LcuSubscriptionType::JsonApiEvent] of `endpoint`, \
+    /// they name the endpoint like `lol-gameflow_v1_gameflow-phase` instead of `/lol-gameflow/v1/gameflow-phase`
+    pub(crate) fn is_json_api_event(&self, endpoint: &str) -> bool {
+        matches!(self, LcuSubscriptionType::JsonApiEvent(_))
+            && self.to_string()
+                == LcuSubscriptionType::JsonApiEvent(endpoint.to_string()).to_string()
+    }
+}
+
 /// Custom deserializer to differentiate between the different subscription types
 impl<'de> Deserialize<'de> for LcuSubscriptionType {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -0,0 +1,189 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::ingame::RouteStyle;
+
+const PLAYER_FIELDS: &[&str] = &[
+    "championName",
+    "isBot",
+    "isDead",
+    "items",
+    "level",
+    "position",
+    "rawChampionName",
+    "rawSkinName",
+    "respawnTimer",
+    "riotId",
+    "riotIdGameName",
+    "riotIdTagLine",
+    "runes",
+    "scores",
+    "skinID",
+    "skinName",
+    "summonerName",
+    "summonerSpells",
+    "team",
+];
+
+/// The endpoints by their legacy name and the fields the models of this crate read from their responses \
+/// `fields_match_the_models` in the tests checks them against the serialized models
+const ENDPOINTS: &[(&str, &[&str])] = &[
+    (
+        "GetLiveclientdataAllgamedata",
+        &["activePlayer", "allPlayers", "events", "gameData"],
+    ),
+    ("GetLiveclientdataEventdata", &["Events"]),
+    (
+        "GetLiveclientdataGamestats",
+        &["gameMode", "gameTime", "mapName", "mapNumber", "mapTerrain"],
+    ),
+    ("GetLiveclientdataPlayerlist", PLAYER_FIELDS),
+    (
+        "GetLiveclientdataPlayeritems",
+        &[
+            "canUse",
+            "consumable",
+            "count",
+            "displayName",
+            "itemID",
+            "price",
+            "rawDescription",
+            "rawDisplayName",
+            "slot",
+        ],
+    ),
+    (
+        "GetLiveclientdataPlayermainrunes",
+        &["keystone", "primaryRuneTree", "secondaryRuneTree"],
+    ),
+    (
+        "GetLiveclientdataPlayerscores",
+        &["assists", "creepScore", "deaths", "kills", "wardScore"],
+    ),
+    (
+        "GetLiveclientdataPlayersummonerspells",
+        &["summonerSpellOne", "summonerSpellTwo"],
+    ),
+    (
+        "GetLiveclientdataActiveplayer",
+        &[
+            "abilities",
+            "championStats",
+            "currentGold",
+            "fullRunes",
+            "level",
+            "riotId",
+            "riotIdGameName",
+            "riotIdTagLine",
+            "summonerName",
+            "teamRelativeColors",
+        ],
+    ),
+    (
+        "GetLiveclientdataActiveplayerabilities",
+        &["E", "Passive", "Q", "R", "W"],
+    ),
+    ("GetLiveclientdataActiveplayername", &[]),
+    (
+        "GetLiveclientdataActiveplayerrunes",
+        &[
+            "generalRunes",
+            "keystone",
+            "primaryRuneTree",
+            "secondaryRuneTree",
+            "statRunes",
+        ],
+    ),
+];
+
+/// The differences between an OpenAPI description of the ingame API and the models of this crate
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaDrift {
+    /// endpoints the crate calls that the description has in neither route style
+    pub missing_endpoints: Vec<String>,
+    pub endpoints: Vec<EndpointDrift>,
+}
+
+/// The differences of the top-level fields of one endpoint's response
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointDrift {
+    /// the legacy name, e.g. `GetLiveclientdataGamestats`
+    pub endpoint: String,
+    /// read by the crate but not described, likely to fail deserializing
+    pub missing_fields: Vec<String>,
+    /// described but unknown to the crate
+    pub unknown_fields: Vec<String>,
+}
+
+impl SchemaDrift {
+    pub fn is_empty(&self) -> bool {
+        self.missing_endpoints.is_empty() && self.endpoints.is_empty()
+    }
+}
+
+/// Compare an OpenAPI 3 or Swagger 2 description, e.g. from [IngameClient::openapi_spec](crate::ingame::IngameClient::openapi_spec), \
+/// against the models of this crate \
+/// Responses without described properties are only checked for their endpoint
+pub fn compare_spec(spec: &Value) -> SchemaDrift {
+    let mut drift = SchemaDrift::default();
+    for (endpoint, fields) in ENDPOINTS {
+        let operation = [RouteStyle::Modern, RouteStyle::Legacy]
+            .iter()
+            .find_map(|style| spec["paths"].get(format!("/{}", style.path(endpoint))));
+        let Some(operation) = operation else {
+            drift.missing_endpoints.push(endpoint.to_string());
+            continue;
+        };
+        let Some(properties) = response_properties(spec, operation) else {
+            continue;
+        };
+
+        let missing_fields = fields
+            .iter()
+            .filter(|f| !properties.contains(&f.to_string()))
+            .map(|f| f.to_string())
+            .collect::<Vec<_>>();
+        let unknown_fields = properties
+            .into_iter()
+            .filter(|p| !fields.contains(&p.as_str()))
+            .collect::<Vec<_>>();
+        if !missing_fields.is_empty() || !unknown_fields.is_empty() {
+            drift.endpoints.push(EndpointDrift {
+                endpoint: endpoint.to_string(),
+                missing_fields,
+                unknown_fields,
+            });
+        }
+    }
+    drift
+}
+
+/// The property names of the successful response of a GET operation, \
+/// of the items if the response is an array
+fn response_properties(spec: &Value, operation: &Value) -> Option<Vec<String>> {
+    let response = &operation["get"]["responses"]["200"];
+    // OpenAPI 3 nests the schema in the content, Swagger 2 doesn't
+    let schema = response["content"]
+        .as_object()
+        .and_then(|content| content.values().next())
+        .map_or(&response["schema"], |media| &media["schema"]);
+
+    let mut schema = resolve(spec, schema);
+    if schema["type"] == "array" {
+        schema = resolve(spec, &schema["items"]);
+    }
+    let properties = schema["properties"].as_object()?;
+    Some(properties.keys().cloned().collect())
+}
+
+/// Follow a `$ref` like `#/components/schemas/GameStats`
+fn resolve<'a>(spec: &'a Value, schema: &'a Value) -> &'a Value {
+    match schema["$ref"].as_str() {
+        Some(reference) => spec
+            .pointer(reference.trim_start_matches('#'))
+            .unwrap_or(&Value::Null),
+        None => schema,
+    }
+}
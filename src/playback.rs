@@ -0,0 +1,344 @@
+use std::{
+    io::BufRead,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::time::Instant;
+
+use crate::{
+    error::{IngameClientError, RecordingError},
+    ingame::IngameApi,
+    model::ingame::*,
+    recorder::{RecordedEntry, RecordingReader},
+};
+
+const DEFAULT_LINGER_MILLIS: u64 = 1000;
+
+/// The virtual game clock of a [Playback]
+#[derive(Debug)]
+struct Clock {
+    /// game time at `anchor`
+    game_time: Time,
+    anchor: Instant,
+    speed: f64,
+    paused: bool,
+    /// how long the end of the recording is served before the playback finishes
+    linger: Duration,
+}
+
+impl Clock {
+    fn now(&self) -> Time {
+        if self.paused {
+            return self.game_time;
+        }
+        self.game_time + self.anchor.elapsed().as_secs_f64() * self.speed
+    }
+
+    /// restart the clock at the current game time so speed changes don't affect the past
+    fn reanchor(&mut self, end: Time) {
+        if self.game_time <= end {
+            self.game_time = self.now().min(end);
+        }
+        self.anchor = Instant::now();
+    }
+
+    fn is_finished(&self, end: Time) -> bool {
+        if self.game_time > end {
+            return true;
+        }
+        if self.paused || self.speed <= 0.0 {
+            return false;
+        }
+        let end_reached =
+            self.anchor + Duration::from_secs_f64((end - self.game_time) / self.speed);
+        Instant::now() >= end_reached + self.linger
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    /// sorted by game time
+    snapshots: Vec<AllGameData>,
+    /// sorted by event id
+    events: Vec<GameEvent>,
+    start: Time,
+    end: Time,
+    clock: Mutex<Clock>,
+}
+
+/// Plays back recorded games through the [IngameApi] \
+/// The data served is the latest recorded snapshot at the current virtual game time,
+/// with only the events that already happened at that time \
+/// The end of the recording is served for a short while, afterwards every call fails like the API of a closed game
+///
+/// Clones share the same recording and clock, so a clone can be handed to an [EventStream](crate::ingame::EventStream) while the playback is controlled from elsewhere
+#[derive(Debug, Clone)]
+pub struct Playback(Arc<Inner>);
+
+impl Playback {
+    /// Create a [Playback] from snapshots of [AllGameData], e.g. the fixture JSON of `GetLiveclientdataAllgamedata` \
+    /// The playback starts at a game time of 0 and ends with the latest snapshot or event
+    pub fn from_snapshots<I: IntoIterator<Item = AllGameData>>(snapshots: I) -> Self {
+        Self::build(snapshots.into_iter().collect(), Vec::new(), Some(0.0))
+    }
+
+    /// Create a [Playback] from a recording made with the [Recorder](crate::recorder::Recorder) \
+    /// The playback starts at the game time of the first snapshot
+    pub fn from_recording<R: BufRead>(
+        recording: RecordingReader<R>,
+    ) -> Result<Self, RecordingError> {
+        let mut snapshots = Vec::new();
+        let mut events = Vec::new();
+        for entry in recording {
+            match entry? {
+                RecordedEntry::Snapshot { data, .. } => snapshots.push(*data),
+                RecordedEntry::Event { event, .. } => events.push(event),
+            }
+        }
+        Ok(Self::build(snapshots, events, None))
+    }
+
+    fn build(
+        mut snapshots: Vec<AllGameData>,
+        mut events: Vec<GameEvent>,
+        start: Option<Time>,
+    ) -> Self {
+        snapshots.sort_by(|a, b| a.game_data.game_time.total_cmp(&b.game_data.game_time));
+
+        // events are served separately from the snapshots they were recorded with
+        for snapshot in snapshots.iter_mut() {
+            events.append(&mut snapshot.events);
+        }
+        events.sort_by_key(GameEvent::get_event_id);
+        events.dedup_by_key(|event| event.get_event_id());
+
+        let start = start
+            .or(snapshots.first().map(|s| s.game_data.game_time))
+            .unwrap_or(0.0);
+        let end = snapshots
+            .iter()
+            .map(|s| s.game_data.game_time)
+            .chain(events.iter().map(GameEvent::get_event_time))
+            .fold(start, f64::max);
+
+        Self(Arc::new(Inner {
+            snapshots,
+            events,
+            start,
+            end,
+            clock: Mutex::new(Clock {
+                game_time: start,
+                anchor: Instant::now(),
+                speed: 1.0,
+                paused: false,
+                linger: Duration::from_millis(DEFAULT_LINGER_MILLIS),
+            }),
+        }))
+    }
+
+    /// The current virtual game time
+    pub fn game_time(&self) -> Time {
+        self.0.clock.lock().unwrap().now().min(self.0.end)
+    }
+
+    /// The game time the playback starts at
+    pub fn start_time(&self) -> Time {
+        self.0.start
+    }
+
+    /// The game time the recording ends at
+    pub fn end_time(&self) -> Time {
+        self.0.end
+    }
+
+    /// Whether the playback has finished and the API calls fail
+    pub fn is_finished(&self) -> bool {
+        self.0.clock.lock().unwrap().is_finished(self.0.end)
+    }
+
+    /// Set the playback speed, 1.0 is real time
+    pub fn set_speed(&self, speed: f64) {
+        let mut clock = self.0.clock.lock().unwrap();
+        clock.reanchor(self.0.end);
+        clock.speed = speed.max(0.0);
+    }
+
+    /// Set how long the end of the recording is served before the playback finishes \
+    /// The default [Duration] is 1s of real time
+    pub fn set_linger(&self, linger: Duration) {
+        self.0.clock.lock().unwrap().linger = linger;
+    }
+
+    /// Pause the virtual game time
+    pub fn pause(&self) {
+        let mut clock = self.0.clock.lock().unwrap();
+        clock.reanchor(self.0.end);
+        clock.paused = true;
+    }
+
+    /// Resume the virtual game time after a [Playback::pause]
+    pub fn resume(&self) {
+        let mut clock = self.0.clock.lock().unwrap();
+        clock.anchor = Instant::now();
+        clock.paused = false;
+    }
+
+    /// Jump to a game time \
+    /// Seeking past the end of the recording finishes the playback
+    pub fn seek(&self, game_time: Time) {
+        let mut clock = self.0.clock.lock().unwrap();
+        clock.game_time = game_time.max(self.0.start);
+        clock.anchor = Instant::now();
+    }
+
+    /// The current game time or an error if the playback is finished
+    fn now(&self) -> Result<Time, IngameClientError> {
+        let clock = self.0.clock.lock().unwrap();
+        if clock.is_finished(self.0.end) {
+            return Err(IngameClientError::ConnectionError(
+                "playback has finished".to_string(),
+            ));
+        }
+        Ok(clock.now().min(self.0.end))
+    }
+
+    /// The latest snapshot at the current game time
+    fn snapshot(&self) -> Result<(Time, &AllGameData), IngameClientError> {
+        let now = self.now()?;
+        let snapshots = &self.0.snapshots;
+        let index = snapshots
+            .partition_point(|s| s.game_data.game_time <= now)
+            .saturating_sub(1);
+        snapshots
+            .get(index)
+            .map(|snapshot| (now, snapshot))
+            .ok_or(IngameClientError::ApiNotAvailableDuringLoadingScreen)
+    }
+
+    fn events(&self, now: Time, event_id: Option<u32>) -> Vec<GameEvent> {
+        let event_id = event_id.unwrap_or(0);
+        self.0
+            .events
+            .iter()
+            .filter(|e| e.get_event_id() >= event_id && e.get_event_time() <= now)
+            .cloned()
+            .collect()
+    }
+
+    fn player<S: AsRef<str>>(&self, summoner_name: S) -> Result<Player, IngameClientError> {
+        let (_, snapshot) = self.snapshot()?;
+        let summoner_name = summoner_name.as_ref();
+        snapshot
+            .all_players
+            .iter()
+            .find(|p| p.riot_id.riot_id == summoner_name || p.summoner_name == summoner_name)
+            .cloned()
+            .ok_or(IngameClientError::ClientError(format!(
+                "unknown player {summoner_name}"
+            )))
+    }
+
+    fn active(&self) -> Result<ActivePlayer, IngameClientError> {
+        let (_, snapshot) = self.snapshot()?;
+        snapshot
+            .active_player
+            .clone()
+            .ok_or(IngameClientError::ApiNotAvailableInSpectatorMode)
+    }
+}
+
+impl IngameApi for Playback {
+    async fn active_game(&self) -> bool {
+        self.snapshot().is_ok()
+    }
+
+    async fn active_game_loadingscreen(&self) -> bool {
+        self.snapshot().is_ok()
+    }
+
+    async fn is_spectator_mode(&self) -> Result<bool, IngameClientError> {
+        self.snapshot()
+            .map(|(_, snapshot)| snapshot.active_player.is_none())
+    }
+
+    async fn all_game_data(&self, event_id: Option<u32>) -> Result<AllGameData, IngameClientError> {
+        let (now, snapshot) = self.snapshot()?;
+        let mut all_game_data = AllGameData {
+            active_player: snapshot.active_player.clone(),
+            all_players: snapshot.all_players.clone(),
+            events: self.events(now, event_id),
+            game_data: snapshot.game_data.clone(),
+        };
+        all_game_data.game_data.game_time = now;
+        Ok(all_game_data)
+    }
+
+    async fn event_data(&self, event_id: Option<u32>) -> Result<Vec<GameEvent>, IngameClientError> {
+        let now = self.now()?;
+        Ok(self.events(now, event_id))
+    }
+
+    async fn game_stats(&self) -> Result<GameStats, IngameClientError> {
+        let (now, snapshot) = self.snapshot()?;
+        let mut game_stats = snapshot.game_data.clone();
+        game_stats.game_time = now;
+        Ok(game_stats)
+    }
+
+    async fn player_items<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> Result<Vec<PlayerItem>, IngameClientError> {
+        self.player(summoner_name).map(|p| p.items)
+    }
+
+    async fn player_list(&self, team_id: Option<TeamId>) -> Result<Vec<Player>, IngameClientError> {
+        let (_, snapshot) = self.snapshot()?;
+        Ok(snapshot
+            .all_players
+            .iter()
+            .filter(|p| team_id.is_none_or(|team_id| p.team == team_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn player_main_runes<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> Result<PlayerRunes, IngameClientError> {
+        self.player(summoner_name)?
+            .runes
+            .ok_or(IngameClientError::ApiNotAvailableInSpectatorMode)
+    }
+
+    async fn player_scores<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> Result<PlayerScores, IngameClientError> {
+        self.player(summoner_name).map(|p| p.scores)
+    }
+
+    async fn player_summoner_spells<S: AsRef<str> + Send>(
+        &self,
+        summoner_name: S,
+    ) -> Result<SummonerSpells, IngameClientError> {
+        self.player(summoner_name).map(|p| p.summoner_spells)
+    }
+
+    async fn active_player(&self) -> Result<ActivePlayer, IngameClientError> {
+        self.active()
+    }
+
+    async fn active_player_abilities(&self) -> Result<PlayerAbilities, IngameClientError> {
+        self.active().map(|p| p.abilities)
+    }
+
+    async fn active_player_name(&self) -> Result<String, IngameClientError> {
+        self.active().map(|p| p.riot_id.riot_id)
+    }
+
+    async fn active_player_runes(&self) -> Result<FullPlayerRunes, IngameClientError> {
+        self.active().map(|p| p.runes)
+    }
+}
@@ -0,0 +1,354 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::Poll,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::{Stream, StreamExt};
+use serde::Deserialize;
+use tokio::{
+    sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+    task::JoinHandle,
+};
+
+use crate::{
+    error::LcuError,
+    model::{
+        lcu::matchmaking::{ReadyCheck, ReadyCheckResponse, ReadyCheckState},
+        ws::LcuEvent,
+    },
+    rest::RESTClient,
+    ws::{connect_to_client, JsonApiEvents, LcuWebsocketClient},
+};
+
+pub(crate) const READY_CHECK_ENDPOINT: &str = "/lol-matchmaking/v1/ready-check";
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReadyCheckDecision {
+    Accept,
+    Decline,
+    /// Leave the ready check to the player
+    Ignore,
+}
+
+/// What a [ReadyCheckPolicy] decides on
+#[derive(Debug, Clone)]
+pub struct ReadyCheckContext {
+    pub ready_check: ReadyCheck,
+    /// the queue of the search, `None` if it couldn't be requested
+    pub queue_id: Option<i32>,
+}
+
+type ReadyCheckCallback = Arc<
+    dyn Fn(ReadyCheckContext) -> Pin<Box<dyn Future<Output = ReadyCheckDecision> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// When a [ReadyCheckWatcher] accepts or declines a found match
+#[derive(Clone)]
+pub enum ReadyCheckPolicy {
+    /// Accept every match
+    Always,
+    /// Accept the matches of the queues with these ids, leave the others to the player
+    Queues(Vec<i32>),
+    /// Accept the matches found between `start` and `end` after midnight, leave the others to the player \
+    /// The window wraps around midnight if `end` is before `start`, `utc_offset_secs` is the offset of the local time zone
+    TimeWindow {
+        start: Duration,
+        end: Duration,
+        utc_offset_secs: i32,
+    },
+    /// Let a callback decide, see [ReadyCheckPolicy::callback]
+    Callback {
+        callback: ReadyCheckCallback,
+        /// the time after the match was found to wait for the callback
+        deadline: Duration,
+        /// the decision when the callback misses the deadline
+        on_timeout: ReadyCheckDecision,
+    },
+}
+
+impl ReadyCheckPolicy {
+    /// Let `callback` decide, e.g. by asking the user \
+    /// If it doesn't decide within `deadline` after the match was found, `on_timeout` is used
+    pub fn callback<F, Fut>(deadline: Duration, on_timeout: ReadyCheckDecision, callback: F) -> Self
+    where
+        F: Fn(ReadyCheckContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ReadyCheckDecision> + Send + 'static,
+    {
+        Self::Callback {
+            callback: Arc::new(move |context| Box::pin(callback(context))),
+            deadline,
+            on_timeout,
+        }
+    }
+
+    /// Decide on a ready check received at `now`
+    pub async fn decide(&self, context: ReadyCheckContext, now: SystemTime) -> ReadyCheckDecision {
+        match self {
+            Self::Always => ReadyCheckDecision::Accept,
+            Self::Queues(queues) => match context.queue_id {
+                Some(queue_id) if queues.contains(&queue_id) => ReadyCheckDecision::Accept,
+                _ => ReadyCheckDecision::Ignore,
+            },
+            Self::TimeWindow {
+                start,
+                end,
+                utc_offset_secs,
+            } => {
+                let time_of_day = time_of_day(now, *utc_offset_secs);
+                let inside = if start <= end {
+                    *start <= time_of_day && time_of_day < *end
+                } else {
+                    *start <= time_of_day || time_of_day < *end
+                };
+                if inside {
+                    ReadyCheckDecision::Accept
+                } else {
+                    ReadyCheckDecision::Ignore
+                }
+            }
+            Self::Callback {
+                callback,
+                deadline,
+                on_timeout,
+            } => {
+                // the timer counts the seconds since the match was found
+                let elapsed =
+                    Duration::try_from_secs_f64(context.ready_check.timer).unwrap_or_default();
+                let remaining = deadline.saturating_sub(elapsed);
+                tokio::time::timeout(remaining, callback(context))
+                    .await
+                    .unwrap_or(*on_timeout)
+            }
+        }
+    }
+}
+
+/// The time since midnight in the time zone with `utc_offset_secs`
+fn time_of_day(time: SystemTime, utc_offset_secs: i32) -> Duration {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let local = since_epoch.as_secs() as i64 + utc_offset_secs as i64;
+    let seconds = local.rem_euclid(SECONDS_PER_DAY as i64) as u64;
+    Duration::new(seconds, since_epoch.subsec_nanos())
+}
+
+/// What happened to a found match
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReadyCheckOutcome {
+    /// A match was found and the policy decided, the decision was sent to the client unless it is [ReadyCheckDecision::Ignore]
+    Decided {
+        queue_id: Option<i32>,
+        decision: ReadyCheckDecision,
+    },
+    /// Sending the decision to the client failed
+    Failed {
+        decision: ReadyCheckDecision,
+        error: String,
+    },
+    /// Everyone accepted, champ select starts
+    EveryoneReady,
+    /// A player declined or didn't answer in time
+    Dodged {
+        /// the summoner ids of the players that declined
+        decliner_ids: Vec<u64>,
+        /// whether the player was in the own party, or the local player
+        by_party: bool,
+        /// whether the client searches again, which it does after accepting a match a stranger declined
+        requeued: bool,
+    },
+}
+
+/// Watches the ready check and answers it according to a [ReadyCheckPolicy] \
+/// Answers in a background task until it is dropped and implements [Stream] with the [ReadyCheckOutcome]s
+pub struct ReadyCheckWatcher {
+    watch_task_handle: JoinHandle<()>,
+    outcomes_rx: UnboundedReceiver<ReadyCheckOutcome>,
+}
+
+impl ReadyCheckWatcher {
+    /// Subscribe `ws_client` to the ready check and answer it with `rest_client`
+    pub async fn new(
+        rest_client: RESTClient,
+        ws_client: LcuWebsocketClient,
+        policy: ReadyCheckPolicy,
+    ) -> Result<Self, LcuError> {
+        let mut events = JsonApiEvents::subscribe(ws_client, READY_CHECK_ENDPOINT).await?;
+        let (mut responder, outcomes_rx) = ReadyCheckResponder::new(rest_client, policy);
+
+        let watch_task_handle = tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                if !responder.handle(&event) {
+                    return;
+                }
+            }
+        });
+
+        Ok(Self {
+            watch_task_handle,
+            outcomes_rx,
+        })
+    }
+
+    /// Connect to the running League client and watch the ready check
+    pub async fn connect(policy: ReadyCheckPolicy) -> Result<Self, LcuError> {
+        let (rest_client, ws_client) = connect_to_client().await?;
+        Self::new(rest_client, ws_client, policy).await
+    }
+}
+
+/// Answers the ready checks of the events it is given for the [ReadyCheckWatcher] and the
+/// [ChampSelectAutomation](crate::automation::ChampSelectAutomation) \
+/// The policy decides in a task of its own, so a slow callback doesn't hold up the other events
+pub(crate) struct ReadyCheckResponder {
+    rest_client: RESTClient,
+    policy: ReadyCheckPolicy,
+    last_state: ReadyCheckState,
+    deciding: Option<JoinHandle<()>>,
+    outcomes_tx: UnboundedSender<ReadyCheckOutcome>,
+}
+
+impl ReadyCheckResponder {
+    pub(crate) fn new(
+        rest_client: RESTClient,
+        policy: ReadyCheckPolicy,
+    ) -> (Self, UnboundedReceiver<ReadyCheckOutcome>) {
+        let (outcomes_tx, outcomes_rx) = unbounded_channel();
+        let responder = Self {
+            rest_client,
+            policy,
+            last_state: ReadyCheckState::Invalid,
+            deciding: None,
+            outcomes_tx,
+        };
+        (responder, outcomes_rx)
+    }
+
+    /// Handle an event, events of other endpoints are ignored \
+    /// Returns false once the outcomes aren't received anymore
+    pub(crate) fn handle(&mut self, event: &LcuEvent) -> bool {
+        if !event
+            .subscription_type
+            .is_json_api_event(READY_CHECK_ENDPOINT)
+        {
+            return true;
+        }
+        let ready_check: ReadyCheck = match event.event_type.as_str() {
+            "Delete" => ReadyCheck::default(),
+            _ => match ReadyCheck::deserialize(&event.data) {
+                Ok(ready_check) => ready_check,
+                Err(_) => return true,
+            },
+        };
+        let state = ready_check.state;
+        if state == self.last_state {
+            return true;
+        }
+        self.last_state = state;
+        // the decision is too late for a ready check that is over
+        if let Some(deciding) = self.deciding.take() {
+            deciding.abort();
+        }
+
+        let outcome = match state {
+            // answered by the player already
+            ReadyCheckState::InProgress
+                if ready_check.player_response != ReadyCheckResponse::None =>
+            {
+                return true
+            }
+            ReadyCheckState::InProgress => {
+                self.deciding = Some(tokio::spawn(answer(
+                    self.rest_client.clone(),
+                    self.policy.clone(),
+                    ready_check,
+                    self.outcomes_tx.clone(),
+                )));
+                return true;
+            }
+            ReadyCheckState::EveryoneReady => ReadyCheckOutcome::EveryoneReady,
+            ReadyCheckState::StrangerNotReady | ReadyCheckState::PartyNotReady => {
+                ReadyCheckOutcome::Dodged {
+                    by_party: state == ReadyCheckState::PartyNotReady,
+                    requeued: state == ReadyCheckState::StrangerNotReady
+                        && ready_check.player_response == ReadyCheckResponse::Accepted,
+                    decliner_ids: ready_check.decliner_ids,
+                }
+            }
+            _ => return true,
+        };
+        self.outcomes_tx.send(outcome).is_ok()
+    }
+}
+
+impl Drop for ReadyCheckResponder {
+    fn drop(&mut self) {
+        if let Some(deciding) = self.deciding.take() {
+            deciding.abort();
+        }
+    }
+}
+
+/// Let the policy decide on the ready check and send the decision if the ready check is still waiting for it
+async fn answer(
+    rest_client: RESTClient,
+    policy: ReadyCheckPolicy,
+    ready_check: ReadyCheck,
+    outcomes_tx: UnboundedSender<ReadyCheckOutcome>,
+) {
+    let queue_id = rest_client
+        .search_state()
+        .await
+        .ok()
+        .map(|search| search.queue_id);
+    let context = ReadyCheckContext {
+        ready_check,
+        queue_id,
+    };
+    let decision = policy.decide(context, SystemTime::now()).await;
+    let result = match decision {
+        ReadyCheckDecision::Ignore => Ok(()),
+        _ => match rest_client.ready_check().await {
+            // answered by the player or over while the policy decided
+            Ok(current)
+                if current.state != ReadyCheckState::InProgress
+                    || current.player_response != ReadyCheckResponse::None =>
+            {
+                return
+            }
+            Ok(_) if decision == ReadyCheckDecision::Accept => {
+                rest_client.accept_ready_check().await
+            }
+            Ok(_) => rest_client.decline_ready_check().await,
+            Err(e) => Err(e),
+        },
+    };
+    _ = outcomes_tx.send(match result {
+        Ok(()) => ReadyCheckOutcome::Decided { queue_id, decision },
+        Err(e) => ReadyCheckOutcome::Failed {
+            decision,
+            error: e.to_string(),
+        },
+    });
+}
+
+impl Stream for ReadyCheckWatcher {
+    type Item = ReadyCheckOutcome;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.outcomes_rx.poll_recv(cx)
+    }
+}
+
+impl Drop for ReadyCheckWatcher {
+    fn drop(&mut self) {
+        self.watch_task_handle.abort()
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Lines, Write},
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    error::RecordingError,
+    ingame::IngameClient,
+    model::ingame::{AllGameData, EventId, GameEvent, GameStats},
+};
+
+/// Version of the recording format written by the [RecordingWriter]
+pub const FORMAT_VERSION: u32 = 1;
+
+const DEFAULT_SAMPLING_RATE_MILLIS: u64 = 1000;
+
+/// A single line of a recording \
+/// Game data is stored exactly as the API returned it so recordings can be replayed as fixtures
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum Line {
+    Header {
+        version: u32,
+        /// unix timestamp in milliseconds
+        #[serde(rename = "recordedAt")]
+        recorded_at: u64,
+        #[serde(rename = "gameStats")]
+        game_stats: Value,
+    },
+    /// a single entry of `GetLiveclientdataEventdata`
+    Event { elapsed: u64, event: Value },
+    /// `GetLiveclientdataAllgamedata` without the events, which are stored as separate [Line::Event]s
+    Snapshot { elapsed: u64, data: Value },
+}
+
+/// The first line of every recording
+#[derive(Debug, Clone)]
+pub struct RecordingHeader {
+    pub version: u32,
+    /// When the recording was started
+    pub recorded_at: SystemTime,
+    /// The [GameStats] at the start of the recording
+    pub game_stats: GameStats,
+}
+
+/// An entry of a recording \
+/// `elapsed` is the wall-clock time since the start of the recording
+#[derive(Debug, Clone)]
+pub enum RecordedEntry {
+    /// A sample of [IngameClient::all_game_data] including all events up to this point
+    Snapshot {
+        elapsed: Duration,
+        data: Box<AllGameData>,
+    },
+    /// A [GameEvent] that happened since the previous snapshot
+    Event { elapsed: Duration, event: GameEvent },
+}
+
+/// Writes recordings in the JSON Lines format \
+/// Every line is a JSON object tagged with a `type` of either `header`, `event` or `snapshot`
+pub struct RecordingWriter<W: Write> {
+    writer: W,
+    next_event_id: EventId,
+    game_ended: bool,
+}
+
+impl<W: Write> RecordingWriter<W> {
+    /// Create a new [RecordingWriter] and write the header \
+    /// `game_stats` is the JSON returned by `GetLiveclientdataGamestats`
+    pub fn new(mut writer: W, game_stats: Value) -> Result<Self, RecordingError> {
+        let recorded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        write_line(
+            &mut writer,
+            &Line::Header {
+                version: FORMAT_VERSION,
+                recorded_at,
+                game_stats,
+            },
+        )?;
+
+        Ok(Self {
+            writer,
+            next_event_id: 0,
+            game_ended: false,
+        })
+    }
+
+    /// Write a sample of `GetLiveclientdataAllgamedata` \
+    /// Events that already have been written are skipped, so the data can contain either all or only the new events
+    pub fn write_snapshot(
+        &mut self,
+        elapsed: Duration,
+        mut all_game_data: Value,
+    ) -> Result<(), RecordingError> {
+        let elapsed = elapsed.as_millis() as u64;
+
+        let events = all_game_data
+            .as_object_mut()
+            .and_then(|data| data.remove("events"))
+            .and_then(|mut events| events.get_mut("Events").map(Value::take))
+            .and_then(|events| match events {
+                Value::Array(events) => Some(events),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        for event in events {
+            let Some(event_id) = event
+                .get("EventID")
+                .and_then(Value::as_u64)
+                .and_then(|id| EventId::try_from(id).ok())
+            else {
+                continue;
+            };
+            if event_id < self.next_event_id {
+                continue;
+            }
+            self.next_event_id = event_id.saturating_add(1);
+            if event.get("EventName").and_then(Value::as_str) == Some("GameEnd") {
+                self.game_ended = true;
+            }
+            write_line(&mut self.writer, &Line::Event { elapsed, event })?;
+        }
+
+        write_line(
+            &mut self.writer,
+            &Line::Snapshot {
+                elapsed,
+                data: all_game_data,
+            },
+        )
+    }
+
+    /// The event id of the next event that has not been written yet
+    pub fn next_event_id(&self) -> EventId {
+        self.next_event_id
+    }
+
+    /// Whether a [GameEvent::GameEnd] has been written
+    pub fn game_ended(&self) -> bool {
+        self.game_ended
+    }
+
+    /// Flush and return the wrapped writer
+    pub fn into_inner(mut self) -> Result<W, RecordingError> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+fn write_line<W: Write>(writer: &mut W, line: &Line) -> Result<(), RecordingError> {
+    serde_json::to_writer(&mut *writer, line)?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+/// Records a whole game by sampling an [IngameClient] at a set rate
+pub struct Recorder<W: Write> {
+    ingame_client: IngameClient,
+    writer: W,
+    sampling_rate: Duration,
+}
+
+impl Recorder<BufWriter<File>> {
+    /// Create a [Recorder] that writes to a newly created file at `path`
+    pub fn create<P: AsRef<Path>>(
+        ingame_client: IngameClient,
+        path: P,
+        sampling_rate: Option<Duration>,
+    ) -> Result<Self, RecordingError> {
+        let file = File::create(path)?;
+        Ok(Self::new(
+            ingame_client,
+            BufWriter::new(file),
+            sampling_rate,
+        ))
+    }
+}
+
+impl<W: Write> Recorder<W> {
+    /// Create a [Recorder] from an [IngameClient] \
+    /// Takes an [Option] that specifies how often the game gets sampled \
+    /// The default [Duration] is 1s
+    pub fn new(ingame_client: IngameClient, writer: W, sampling_rate: Option<Duration>) -> Self {
+        Self {
+            ingame_client,
+            writer,
+            sampling_rate: sampling_rate
+                .unwrap_or(Duration::from_millis(DEFAULT_SAMPLING_RATE_MILLIS)),
+        }
+    }
+
+    /// Wait for a game to start and record it until it ends \
+    /// The recording stops after the [GameEvent::GameEnd] or as soon as the API is not reachable anymore \
+    /// Returns the flushed writer
+    pub async fn record(self) -> Result<W, RecordingError> {
+        let mut timer = tokio::time::interval(self.sampling_rate);
+
+        // wait for a game to start
+        loop {
+            timer.tick().await;
+            if self.ingame_client.active_game().await {
+                break;
+            }
+        }
+
+        let game_stats = self
+            .ingame_client
+            .get_raw("GetLiveclientdataGamestats")
+            .await?;
+        let mut writer = RecordingWriter::new(self.writer, game_stats)?;
+        let start = Instant::now();
+
+        // record for as long as api calls are successful
+        while !writer.game_ended() {
+            timer.tick().await;
+            match self
+                .ingame_client
+                .all_game_data_raw(writer.next_event_id())
+                .await
+            {
+                Ok(data) => writer.write_snapshot(start.elapsed(), data)?,
+                Err(_) => break,
+            }
+        }
+
+        writer.into_inner()
+    }
+}
+
+/// Reads recordings written by a [Recorder] or [RecordingWriter] \
+/// Yields the entries in the order they were recorded
+pub struct RecordingReader<R: BufRead> {
+    lines: Lines<R>,
+    header: RecordingHeader,
+    /// the events read so far, attached to every snapshot
+    events: Vec<GameEvent>,
+}
+
+impl RecordingReader<BufReader<File>> {
+    /// Open the recording at `path`
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, RecordingError> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: BufRead> RecordingReader<R> {
+    /// Create a [RecordingReader] and read the header \
+    /// Returns a [RecordingError::Format] if the header is missing or has an unsupported version
+    pub fn new(reader: R) -> Result<Self, RecordingError> {
+        let mut lines = reader.lines();
+        let first_line = lines
+            .next()
+            .ok_or(RecordingError::Format("empty recording".to_string()))??;
+
+        let Line::Header {
+            version,
+            recorded_at,
+            game_stats,
+        } = serde_json::from_str(&first_line)?
+        else {
+            return Err(RecordingError::Format(
+                "recording does not start with a header".to_string(),
+            ));
+        };
+        if version != FORMAT_VERSION {
+            return Err(RecordingError::Format(format!(
+                "unsupported recording version {version}"
+            )));
+        }
+
+        Ok(Self {
+            lines,
+            header: RecordingHeader {
+                version,
+                recorded_at: UNIX_EPOCH + Duration::from_millis(recorded_at),
+                game_stats: GameStats::deserialize(&game_stats)?,
+            },
+            events: Vec::new(),
+        })
+    }
+
+    /// The header of the recording
+    pub fn header(&self) -> &RecordingHeader {
+        &self.header
+    }
+}
+
+impl<R: BufRead> Iterator for RecordingReader<R> {
+    type Item = Result<RecordedEntry, RecordingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let line = match serde_json::from_str::<Line>(&line) {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+
+            return match line {
+                Line::Header { .. } => {
+                    Some(Err(RecordingError::Format("unexpected header".to_string())))
+                }
+                Line::Event { elapsed, event } => {
+                    // events the crate doesn't know are skipped, like in the snapshots of the API
+                    let Ok(event) = GameEvent::deserialize(&event) else {
+                        continue;
+                    };
+                    self.events.push(event.clone());
+                    Some(Ok(RecordedEntry::Event {
+                        elapsed: Duration::from_millis(elapsed),
+                        event,
+                    }))
+                }
+                Line::Snapshot { elapsed, mut data } => {
+                    // the events are attached after deserializing instead of parsing them again
+                    if let Some(data) = data.as_object_mut() {
+                        data.insert("events".to_string(), serde_json::json!({ "Events": [] }));
+                    }
+                    Some(
+                        AllGameData::deserialize(&data)
+                            .map(|mut data| {
+                                data.events = self.events.clone();
+                                RecordedEntry::Snapshot {
+                                    elapsed: Duration::from_millis(elapsed),
+                                    data: Box::new(data),
+                                }
+                            })
+                            .map_err(RecordingError::from),
+                    )
+                }
+            };
+        }
+    }
+}
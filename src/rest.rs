@@ -1,8 +1,22 @@
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::json;
 
-use crate::utils::{process_info, request::build_reqwest_client};
+use crate::{
+    model::lcu::{
+        champ_select::{ChampSelectSession, SwapKind},
+        gameflow::{GameflowPhase, GameflowSession},
+        lobby::{Lobby, LobbyPosition, ReceivedInvitation},
+        matchmaking::{MatchmakingSearch, ReadyCheck},
+        summoner::Summoner,
+    },
+    utils::{
+        process_info,
+        request::{build_reqwest_client, build_reqwest_client_with_root},
+    },
+};
 
 /// A client for the League-Client(LCU) REST API
+#[derive(Clone)]
 pub struct RESTClient {
     port: String,
     reqwest_client: reqwest::Client,
@@ -21,6 +35,18 @@ impl RESTClient {
         })
     }
 
+    /// Create a client for an LCU API that is served on another port, e.g. by a mock server \
+    /// `auth_token` is the remoting auth token and `root_certificate` the PEM encoded certificate
+    /// the server gets verified with, in addition to the Riot Games certificate
+    pub fn with_auth(port: u16, auth_token: &str, root_certificate: &[u8]) -> Result<Self, Error> {
+        let auth_token = process_info::encode_auth_token(auth_token);
+        let reqwest_client = build_reqwest_client_with_root(Some(auth_token), root_certificate)?;
+        Ok(Self {
+            port: port.to_string(),
+            reqwest_client,
+        })
+    }
+
     /// Make a get request to the specified endpoint
     pub async fn get(&self, endpoint: &str) -> Result<serde_json::Value, reqwest::Error> {
         self.reqwest_client
@@ -95,4 +121,311 @@ impl RESTClient {
             .await
             .or_else(|_| Ok(serde_json::Value::Null))
     }
+
+    /// Make a get request to the specified endpoint and deserialize the response into `T`
+    pub(crate) async fn get_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+    ) -> Result<T, reqwest::Error> {
+        self.reqwest_client
+            .get(format!("https://127.0.0.1:{}{}", self.port, endpoint))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+    }
+
+    /// Make a post request to the specified endpoint and deserialize the response into `T`
+    pub(crate) async fn post_json<B: Serialize, T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: B,
+    ) -> Result<T, reqwest::Error> {
+        self.reqwest_client
+            .post(format!("https://127.0.0.1:{}{}", self.port, endpoint))
+            .json(&body)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+    }
+
+    /// Get the current [GameflowPhase]
+    pub async fn gameflow_phase(&self) -> Result<GameflowPhase, reqwest::Error> {
+        self.get_json("/lol-gameflow/v1/gameflow-phase").await
+    }
+
+    /// Get the current [GameflowSession], the queue, map and game client of the lobby or game
+    pub async fn gameflow_session(&self) -> Result<GameflowSession, reqwest::Error> {
+        self.get_json("/lol-gameflow/v1/session").await
+    }
+
+    /// Get the current [ChampSelectSession], fails with a 404 outside of champ select
+    pub async fn champ_select_session(&self) -> Result<ChampSelectSession, reqwest::Error> {
+        self.get_json("/lol-champ-select/v1/session").await
+    }
+
+    /// Hover a champion in the pick or ban action with `action_id`, see [ChampSelectSession::current_action]
+    pub async fn hover_champion(
+        &self,
+        action_id: i64,
+        champion_id: i32,
+    ) -> Result<(), reqwest::Error> {
+        self.patch(
+            &format!("/lol-champ-select/v1/session/actions/{action_id}"),
+            json!({ "championId": champion_id }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Hover and lock in a champion in the pick action with `action_id`
+    pub async fn lock_champion(
+        &self,
+        action_id: i64,
+        champion_id: i32,
+    ) -> Result<(), reqwest::Error> {
+        self.hover_champion(action_id, champion_id).await?;
+        self.post(
+            &format!("/lol-champ-select/v1/session/actions/{action_id}/complete"),
+            json!({}),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Hover and lock in a ban in the ban action with `action_id`
+    pub async fn ban_champion(
+        &self,
+        action_id: i64,
+        champion_id: i32,
+    ) -> Result<(), reqwest::Error> {
+        self.lock_champion(action_id, champion_id).await
+    }
+
+    /// Request the trade or swap with `id` from the teammate
+    pub async fn request_swap(&self, kind: SwapKind, id: i64) -> Result<(), reqwest::Error> {
+        self.swap_action(kind, id, "request").await
+    }
+
+    /// Accept a received trade or swap
+    pub async fn accept_swap(&self, kind: SwapKind, id: i64) -> Result<(), reqwest::Error> {
+        self.swap_action(kind, id, "accept").await
+    }
+
+    /// Decline a received trade or swap
+    pub async fn decline_swap(&self, kind: SwapKind, id: i64) -> Result<(), reqwest::Error> {
+        self.swap_action(kind, id, "decline").await
+    }
+
+    /// Cancel a sent trade or swap
+    pub async fn cancel_swap(&self, kind: SwapKind, id: i64) -> Result<(), reqwest::Error> {
+        self.swap_action(kind, id, "cancel").await
+    }
+
+    async fn swap_action(
+        &self,
+        kind: SwapKind,
+        id: i64,
+        action: &str,
+    ) -> Result<(), reqwest::Error> {
+        self.post(
+            &format!(
+                "/lol-champ-select/v1/session/{}/{id}/{action}",
+                kind.endpoint()
+            ),
+            json!({}),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Swap the own champion with a champion of the bench
+    pub async fn swap_with_bench(&self, champion_id: i32) -> Result<(), reqwest::Error> {
+        self.post(
+            &format!("/lol-champ-select/v1/session/bench/swap/{champion_id}"),
+            json!({}),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Set the summoner spells of the local player by their ids, e.g. 4 for Flash
+    pub async fn set_summoner_spells(
+        &self,
+        spell1_id: u64,
+        spell2_id: u64,
+    ) -> Result<(), reqwest::Error> {
+        self.patch(
+            "/lol-champ-select/v1/session/my-selection",
+            json!({ "spell1Id": spell1_id, "spell2Id": spell2_id }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Select a skin of the picked champion, e.g. 103001 for the first skin of Ahri
+    pub async fn set_skin(&self, skin_id: i32) -> Result<(), reqwest::Error> {
+        self.patch(
+            "/lol-champ-select/v1/session/my-selection",
+            json!({ "selectedSkinId": skin_id }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Make the rune page with `page_id` the current page
+    pub async fn set_current_rune_page(&self, page_id: u64) -> Result<(), reqwest::Error> {
+        self.put("/lol-perks/v1/currentpage", page_id)
+            .await
+            .map(|_| ())
+    }
+
+    /// Get the current [ReadyCheck]
+    pub async fn ready_check(&self) -> Result<ReadyCheck, reqwest::Error> {
+        self.get_json("/lol-matchmaking/v1/ready-check").await
+    }
+
+    /// Accept the found match
+    pub async fn accept_ready_check(&self) -> Result<(), reqwest::Error> {
+        self.post("/lol-matchmaking/v1/ready-check/accept", json!({}))
+            .await
+            .map(|_| ())
+    }
+
+    /// Decline the found match
+    pub async fn decline_ready_check(&self) -> Result<(), reqwest::Error> {
+        self.post("/lol-matchmaking/v1/ready-check/decline", json!({}))
+            .await
+            .map(|_| ())
+    }
+
+    /// Get the current [Lobby], fails with a 404 outside of a lobby
+    pub async fn lobby(&self) -> Result<Lobby, reqwest::Error> {
+        self.get_json("/lol-lobby/v2/lobby").await
+    }
+
+    /// Create a lobby for the queue with `queue_id`, e.g. 420 for Ranked Solo/Duo, and leave the current one
+    pub async fn create_lobby(&self, queue_id: i32) -> Result<Lobby, reqwest::Error> {
+        self.post_json("/lol-lobby/v2/lobby", json!({ "queueId": queue_id }))
+            .await
+    }
+
+    /// Leave the current lobby
+    pub async fn leave_lobby(&self) -> Result<(), reqwest::Error> {
+        self.delete("/lol-lobby/v2/lobby").await.map(|_| ())
+    }
+
+    /// Set the positions of the local member in queues with a position selector
+    pub async fn set_position_preferences(
+        &self,
+        first: LobbyPosition,
+        second: LobbyPosition,
+    ) -> Result<(), reqwest::Error> {
+        self.put(
+            "/lol-lobby/v2/lobby/members/localMember/position-preferences",
+            json!({ "firstPreference": first, "secondPreference": second }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Invite the summoners with `summoner_ids` to the lobby
+    pub async fn invite_summoners(&self, summoner_ids: &[u64]) -> Result<(), reqwest::Error> {
+        let invitations = summoner_ids
+            .iter()
+            .map(|id| json!({ "toSummonerId": id }))
+            .collect::<Vec<_>>();
+        self.post("/lol-lobby/v2/lobby/invitations", invitations)
+            .await
+            .map(|_| ())
+    }
+
+    /// Look up the summoner with a riot id like `name#tag`
+    pub async fn summoner_by_riot_id(&self, riot_id: &str) -> Result<Summoner, reqwest::Error> {
+        self.reqwest_client
+            .get(format!(
+                "https://127.0.0.1:{}/lol-summoner/v1/summoners",
+                self.port
+            ))
+            .query(&[("name", riot_id)])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+    }
+
+    /// Look up the summoner with a riot id like `name#tag` and invite it to the lobby
+    pub async fn invite_by_riot_id(&self, riot_id: &str) -> Result<Summoner, reqwest::Error> {
+        let summoner = self.summoner_by_riot_id(riot_id).await?;
+        self.invite_summoners(&[summoner.summoner_id]).await?;
+        Ok(summoner)
+    }
+
+    /// Get the invitations to other lobbies
+    pub async fn received_invitations(&self) -> Result<Vec<ReceivedInvitation>, reqwest::Error> {
+        self.get_json("/lol-lobby/v2/received-invitations").await
+    }
+
+    /// Accept the invitation with `invitation_id` and join its lobby, see [RESTClient::received_invitations]
+    pub async fn accept_invitation(&self, invitation_id: &str) -> Result<(), reqwest::Error> {
+        self.post(
+            &format!("/lol-lobby/v2/received-invitations/{invitation_id}/accept"),
+            json!({}),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Decline the invitation with `invitation_id`
+    pub async fn decline_invitation(&self, invitation_id: &str) -> Result<(), reqwest::Error> {
+        self.post(
+            &format!("/lol-lobby/v2/received-invitations/{invitation_id}/decline"),
+            json!({}),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Make the member with `summoner_id` the leader of the lobby
+    pub async fn promote_member(&self, summoner_id: u64) -> Result<(), reqwest::Error> {
+        self.post(
+            &format!("/lol-lobby/v2/lobby/members/{summoner_id}/promote"),
+            json!({}),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Remove the member with `summoner_id` from the lobby
+    pub async fn kick_member(&self, summoner_id: u64) -> Result<(), reqwest::Error> {
+        self.post(
+            &format!("/lol-lobby/v2/lobby/members/{summoner_id}/kick"),
+            json!({}),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Start searching a match for the lobby
+    pub async fn start_search(&self) -> Result<(), reqwest::Error> {
+        self.post("/lol-lobby/v2/lobby/matchmaking/search", json!({}))
+            .await
+            .map(|_| ())
+    }
+
+    /// Stop searching a match for the lobby
+    pub async fn cancel_search(&self) -> Result<(), reqwest::Error> {
+        self.delete("/lol-lobby/v2/lobby/matchmaking/search")
+            .await
+            .map(|_| ())
+    }
+
+    /// Get the state of the search, with the estimated queue time and penalties
+    pub async fn search_state(&self) -> Result<MatchmakingSearch, reqwest::Error> {
+        self.get_json("/lol-matchmaking/v1/search").await
+    }
 }
@@ -0,0 +1,155 @@
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    analysis::find_player,
+    error::IngameClientError,
+    ingame::{IngameClient, IngameEndpoint},
+    model::{
+        ingame::*,
+        replay::{ReplayPlayback, ReplayRender},
+    },
+};
+
+/// The [AllGameData] without the active player, which spectators don't have
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpectatorGameData {
+    pub all_players: Vec<Player>,
+    pub events: Vec<GameEvent>,
+    pub game_data: GameStats,
+}
+
+impl From<AllGameData> for SpectatorGameData {
+    fn from(data: AllGameData) -> Self {
+        Self {
+            all_players: data.all_players,
+            events: data.events,
+            game_data: data.game_data,
+        }
+    }
+}
+
+/// An [IngameClient] limited to the endpoints available while spectating, \
+/// with the selected player and the playback of the replay API on top \
+/// The players have no `skin_name` while spectating
+pub struct SpectatorView {
+    client: IngameClient,
+}
+
+impl SpectatorView {
+    pub fn new(client: IngameClient) -> Self {
+        Self { client }
+    }
+
+    pub fn into_client(self) -> IngameClient {
+        self.client
+    }
+
+    /// Get all current game data
+    pub async fn all_game_data(
+        &self,
+        event_id: Option<u32>,
+    ) -> Result<SpectatorGameData, IngameClientError> {
+        self.client.all_game_data(event_id).await.map(Into::into)
+    }
+
+    /// Get event data for the active game
+    pub async fn event_data(
+        &self,
+        event_id: Option<u32>,
+    ) -> Result<Vec<GameEvent>, IngameClientError> {
+        self.client.event_data(event_id).await
+    }
+
+    /// Get the active games stats
+    pub async fn game_stats(&self) -> Result<GameStats, IngameClientError> {
+        self.client.game_stats().await
+    }
+
+    /// Get a list of players in game
+    pub async fn player_list(
+        &self,
+        team_id: Option<TeamId>,
+    ) -> Result<Vec<Player>, IngameClientError> {
+        self.client.player_list(team_id).await
+    }
+
+    /// Get a specified players items
+    pub async fn player_items<S: AsRef<str>>(
+        &self,
+        summoner_name: S,
+    ) -> Result<Vec<PlayerItem>, IngameClientError> {
+        self.client.player_items(summoner_name).await
+    }
+
+    /// Get a specified players main runes
+    pub async fn player_main_runes<S: AsRef<str>>(
+        &self,
+        summoner_name: S,
+    ) -> Result<PlayerRunes, IngameClientError> {
+        self.client.player_main_runes(summoner_name).await
+    }
+
+    /// Get a specified players score
+    pub async fn player_scores<S: AsRef<str>>(
+        &self,
+        summoner_name: S,
+    ) -> Result<PlayerScores, IngameClientError> {
+        self.client.player_scores(summoner_name).await
+    }
+
+    /// Get specified players summoner spells
+    pub async fn player_summoner_spells<S: AsRef<str>>(
+        &self,
+        summoner_name: S,
+    ) -> Result<SummonerSpells, IngameClientError> {
+        self.client.player_summoner_spells(summoner_name).await
+    }
+
+    /// Get the playback of the replay API \
+    /// Requires the replay API to be enabled
+    pub async fn playback(&self) -> Result<ReplayPlayback, IngameClientError> {
+        self.client
+            .get_json(IngameEndpoint::ReplayPlayback.route())
+            .await
+    }
+
+    /// Get the camera and interface of the replay API \
+    /// Requires the replay API to be enabled
+    pub async fn render(&self) -> Result<ReplayRender, IngameClientError> {
+        self.client
+            .get_json(IngameEndpoint::ReplayRender.route())
+            .await
+    }
+
+    /// Get the player the observer has selected, `None` if nothing or no player is selected \
+    /// Requires the replay API to be enabled
+    pub async fn selected_player(&self) -> Result<Option<Player>, IngameClientError> {
+        let render = self.render().await?;
+        if render.selection_name.is_empty() {
+            return Ok(None);
+        }
+        let players = self.player_list(None).await?;
+        let player = find_player(&players, &render.selection_name)
+            .or_else(|| {
+                players
+                    .iter()
+                    .find(|p| p.champion_name == render.selection_name)
+            })
+            .cloned();
+        Ok(player)
+    }
+
+    /// Select a player by their name and attach the camera to them \
+    /// Requires the replay API to be enabled
+    pub async fn select_player(&self, player: &Player) -> Result<ReplayRender, IngameClientError> {
+        let body = json!({
+            "selectionName": player.summoner_name,
+            "cameraAttached": true,
+        });
+        self.client
+            .post_json(IngameEndpoint::ReplayRender.route(), &body)
+            .await
+    }
+}
@@ -0,0 +1,372 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+
+use crate::{
+    analysis::combat::BaseStats,
+    error::StaticDataError,
+    model::ingame::{
+        ItemId, Player, PlayerItem, Price, Rune, RuneId, RuneTree, RuneTreeId, SummonerSpell,
+    },
+};
+
+/// Arena sells copies of the regular items with their id offset by this
+const ARENA_ITEM_OFFSET: ItemId = 220000;
+
+/// Items, runes, summoner spells and champions of a Data Dragon version, \
+/// used to look up what the ingame API only names
+#[derive(Debug, Clone, Default)]
+pub struct StaticData {
+    /// the version of the Data Dragon files, e.g. `14.1.1`
+    pub version: String,
+    items: HashMap<ItemId, ItemData>,
+    runes: HashMap<RuneId, RuneData>,
+    rune_trees: Vec<RuneTreeData>,
+    summoner_spells: HashMap<String, SummonerSpellData>,
+    champions: HashMap<String, ChampionData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemData {
+    /// filled in from the key of the item in `item.json`
+    #[serde(default)]
+    pub id: ItemId,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub plaintext: String,
+    /// the items this item is built from
+    #[serde(default, deserialize_with = "deserialize_ids")]
+    pub from: Vec<ItemId>,
+    /// the items this item builds into
+    #[serde(default, deserialize_with = "deserialize_ids")]
+    pub into: Vec<ItemId>,
+    pub gold: ItemGold,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// the flat stats, e.g. `FlatPhysicalDamageMod`
+    #[serde(default)]
+    pub stats: HashMap<String, f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemGold {
+    /// the cost on top of the components
+    pub base: Price,
+    pub total: Price,
+    pub sell: Price,
+    pub purchasable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuneTreeData {
+    pub id: RuneTreeId,
+    pub key: String,
+    pub icon: String,
+    pub name: String,
+    pub slots: Vec<RuneSlot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuneSlot {
+    pub runes: Vec<RuneData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuneData {
+    pub id: RuneId,
+    pub key: String,
+    pub icon: String,
+    pub name: String,
+    #[serde(default)]
+    pub short_desc: String,
+    #[serde(default)]
+    pub long_desc: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SummonerSpellData {
+    /// e.g. `SummonerFlash`
+    pub id: String,
+    /// the numeric id
+    #[serde(deserialize_with = "deserialize_id")]
+    pub key: i32,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// the cooldown in seconds per rank
+    pub cooldown: Vec<f32>,
+    #[serde(default)]
+    pub summoner_level: i32,
+    /// the game modes the spell is available in, e.g. `CLASSIC` or `ARAM`
+    #[serde(default)]
+    pub modes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampionData {
+    /// e.g. `MonkeyKing`
+    pub id: String,
+    /// the numeric id
+    #[serde(deserialize_with = "deserialize_id")]
+    pub key: i32,
+    /// e.g. `Wukong`
+    pub name: String,
+    pub title: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub stats: ChampionStats,
+}
+
+/// The stats of a champion at level 1 and their growth per level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChampionStats {
+    pub hp: f32,
+    #[serde(rename = "hpperlevel")]
+    pub hp_per_level: f32,
+    pub mp: f32,
+    #[serde(rename = "mpperlevel")]
+    pub mp_per_level: f32,
+    #[serde(rename = "movespeed")]
+    pub move_speed: f32,
+    pub armor: f32,
+    #[serde(rename = "armorperlevel")]
+    pub armor_per_level: f32,
+    #[serde(rename = "spellblock")]
+    pub magic_resist: f32,
+    #[serde(rename = "spellblockperlevel")]
+    pub magic_resist_per_level: f32,
+    #[serde(rename = "attackrange")]
+    pub attack_range: f32,
+    #[serde(rename = "hpregen")]
+    pub hp_regen: f32,
+    #[serde(rename = "hpregenperlevel")]
+    pub hp_regen_per_level: f32,
+    #[serde(rename = "mpregen")]
+    pub mp_regen: f32,
+    #[serde(rename = "mpregenperlevel")]
+    pub mp_regen_per_level: f32,
+    #[serde(rename = "attackdamage")]
+    pub attack_damage: f32,
+    #[serde(rename = "attackdamageperlevel")]
+    pub attack_damage_per_level: f32,
+    /// in percent of the base attack speed
+    #[serde(rename = "attackspeedperlevel")]
+    pub attack_speed_per_level: f32,
+    #[serde(rename = "attackspeed")]
+    pub attack_speed: f32,
+}
+
+impl ChampionData {
+    /// The stats to build a [Target](crate::analysis::combat::Target) of this champion from
+    pub fn base_stats(&self) -> BaseStats {
+        let stats = &self.stats;
+        BaseStats {
+            health: stats.hp,
+            health_per_level: stats.hp_per_level,
+            armor: stats.armor,
+            armor_per_level: stats.armor_per_level,
+            magic_resist: stats.magic_resist,
+            magic_resist_per_level: stats.magic_resist_per_level,
+        }
+    }
+}
+
+/// The Data Dragon files wrap their entries in a `data` map
+#[derive(Deserialize)]
+struct DataFile<T> {
+    #[serde(default)]
+    version: String,
+    data: HashMap<String, T>,
+}
+
+impl StaticData {
+    /// Load `item.json`, `runesReforged.json`, `summoner.json` and `champion.json` \
+    /// from a locale directory of Data Dragon, e.g. `dragontail-14.1.1/14.1.1/data/en_US`
+    pub fn load<P: AsRef<Path>>(dir: P) -> Result<Self, StaticDataError> {
+        let dir = dir.as_ref();
+        let read = |file: &str| fs::read_to_string(dir.join(file));
+        Self::from_json(
+            &read("item.json")?,
+            &read("runesReforged.json")?,
+            &read("summoner.json")?,
+            &read("champion.json")?,
+        )
+    }
+
+    /// Parse the contents of the Data Dragon files
+    pub fn from_json(
+        items: &str,
+        runes: &str,
+        summoner_spells: &str,
+        champions: &str,
+    ) -> Result<Self, StaticDataError> {
+        let items: DataFile<ItemData> = serde_json::from_str(items)?;
+        let rune_trees: Vec<RuneTreeData> = serde_json::from_str(runes)?;
+        let summoner_spells: DataFile<SummonerSpellData> = serde_json::from_str(summoner_spells)?;
+        let champions: DataFile<ChampionData> = serde_json::from_str(champions)?;
+
+        let items = items
+            .data
+            .into_iter()
+            .map(|(id, mut item)| {
+                item.id = id
+                    .parse()
+                    .map_err(|_| StaticDataError::Format(format!("invalid item id {id}")))?;
+                Ok((item.id, item))
+            })
+            .collect::<Result<_, StaticDataError>>()?;
+        let runes = rune_trees
+            .iter()
+            .flat_map(|tree| tree.slots.iter().flat_map(|slot| slot.runes.iter()))
+            .map(|rune| (rune.id, rune.clone()))
+            .collect();
+
+        Ok(Self {
+            version: champions.version,
+            items,
+            runes,
+            rune_trees,
+            summoner_spells: summoner_spells.data,
+            champions: champions.data,
+        })
+    }
+
+    /// The item with `id`, Arena copies of items resolve to the regular item
+    pub fn item(&self, id: ItemId) -> Option<&ItemData> {
+        self.items.get(&id).or_else(|| {
+            let regular = id - ARENA_ITEM_OFFSET;
+            (regular > 0 && regular < 10000)
+                .then(|| self.items.get(&regular))
+                .flatten()
+        })
+    }
+
+    pub fn player_item(&self, item: &PlayerItem) -> Option<&ItemData> {
+        self.item(item.item_id)
+    }
+
+    /// All items `id` is built from, the direct components first
+    pub fn components(&self, id: ItemId) -> Vec<&ItemData> {
+        let mut components = Vec::new();
+        let mut next = self.item(id).map_or(Vec::new(), |item| item.from.clone());
+        while !next.is_empty() {
+            let items = next
+                .iter()
+                .filter_map(|id| self.item(*id))
+                .collect::<Vec<_>>();
+            next = items.iter().flat_map(|item| item.from.clone()).collect();
+            components.extend(items);
+        }
+        components
+    }
+
+    /// The items `id` builds into
+    pub fn builds_into(&self, id: ItemId) -> Vec<&ItemData> {
+        self.item(id).map_or(Vec::new(), |item| {
+            item.into.iter().filter_map(|id| self.item(*id)).collect()
+        })
+    }
+
+    pub fn rune(&self, id: RuneId) -> Option<&RuneData> {
+        self.runes.get(&id)
+    }
+
+    pub fn player_rune(&self, rune: &Rune) -> Option<&RuneData> {
+        self.rune(rune.id)
+    }
+
+    pub fn rune_tree(&self, id: RuneTreeId) -> Option<&RuneTreeData> {
+        self.rune_trees.iter().find(|tree| tree.id == id)
+    }
+
+    pub fn player_rune_tree(&self, tree: &RuneTree) -> Option<&RuneTreeData> {
+        self.rune_tree(tree.id)
+    }
+
+    /// The tree the rune with `id` belongs to
+    pub fn tree_of_rune(&self, id: RuneId) -> Option<&RuneTreeData> {
+        self.rune_trees.iter().find(|tree| {
+            tree.slots
+                .iter()
+                .any(|slot| slot.runes.iter().any(|rune| rune.id == id))
+        })
+    }
+
+    /// The summoner spell with `id`, e.g. `SummonerFlash`
+    pub fn summoner_spell(&self, id: &str) -> Option<&SummonerSpellData> {
+        self.summoner_spells.get(id)
+    }
+
+    /// The summoner spell with the numeric `key`, e.g. 4 for Flash
+    pub fn summoner_spell_by_key(&self, key: i32) -> Option<&SummonerSpellData> {
+        self.summoner_spells.values().find(|spell| spell.key == key)
+    }
+
+    /// The summoner spell by its `rawDisplayName`, \
+    /// e.g. `GeneratedTip_SummonerSpell_SummonerFlash_DisplayName` or `..._SummonerFlash_CD_DisplayName` while on cooldown
+    pub fn player_summoner_spell(&self, spell: &SummonerSpell) -> Option<&SummonerSpellData> {
+        let id = spell
+            .raw_display_name
+            .strip_prefix("GeneratedTip_SummonerSpell_")?
+            .strip_suffix("_DisplayName")?;
+        self.summoner_spell(id.strip_suffix("_CD").unwrap_or(id))
+    }
+
+    /// The champion with `id`, e.g. `MonkeyKing`
+    pub fn champion(&self, id: &str) -> Option<&ChampionData> {
+        self.champions.get(id)
+    }
+
+    /// The champion with the numeric `key`, e.g. 62 for Wukong
+    pub fn champion_by_key(&self, key: i32) -> Option<&ChampionData> {
+        self.champions.values().find(|champion| champion.key == key)
+    }
+
+    /// The champion of `player` by its `rawChampionName`, e.g. `game_character_displayname_MonkeyKing`
+    pub fn player_champion(&self, player: &Player) -> Option<&ChampionData> {
+        let id = player
+            .raw_champion_name
+            .strip_prefix("game_character_displayname_")?;
+        self.champion(id)
+    }
+}
+
+/// Data Dragon sends numeric ids as strings
+fn deserialize_id<'de, D>(deserializer: D) -> Result<i32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Id {
+        Number(i32),
+        String(String),
+    }
+
+    match Id::deserialize(deserializer)? {
+        Id::Number(id) => Ok(id),
+        Id::String(id) => id.parse().map_err(D::Error::custom),
+    }
+}
+
+fn deserialize_ids<'de, D>(deserializer: D) -> Result<Vec<i32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Id(#[serde(deserialize_with = "deserialize_id")] i32);
+
+    Ok(Vec::<Id>::deserialize(deserializer)?
+        .into_iter()
+        .map(|id| id.0)
+        .collect())
+}
@@ -0,0 +1,277 @@
+use std::{
+    collections::HashMap,
+    io,
+    sync::{Arc, Mutex},
+};
+
+use serde_json::{json, Value};
+
+use super::server::{write_response, MockRequest, MockResponse, Server};
+use crate::ingame::IngameClient;
+
+/// The game served by a [MockIngameServer]
+#[derive(Debug, Clone)]
+pub enum MockGame {
+    /// No game is running, every connection gets closed without a response
+    NotRunning,
+    /// Only `/Help` is available, all other routes return 404 \
+    /// Results in [IngameClientError::ApiNotAvailableDuringLoadingScreen](crate::error::IngameClientError::ApiNotAvailableDuringLoadingScreen)
+    LoadingScreen,
+    /// A live game, served from the JSON of `GetLiveclientdataAllgamedata`
+    Live(Value),
+    /// A spectated game, served from the JSON of `GetLiveclientdataAllgamedata` \
+    /// The active player routes return 400 which results in
+    /// [IngameClientError::ApiNotAvailableInSpectatorMode](crate::error::IngameClientError::ApiNotAvailableInSpectatorMode) \
+    /// The replay API serves the playback at the game time and remembers the selection of `POST /replay/render`
+    Spectator(Value),
+}
+
+#[derive(Debug)]
+struct State {
+    game: MockGame,
+    /// scripted responses that take precedence over the game, keyed by path
+    responses: HashMap<String, MockResponse>,
+    /// the unit selected through the replay API
+    selection_name: String,
+}
+
+/// A local stand-in for the LoL-Ingame API (Live Client Data API) \
+/// Serves the `GetLiveclientdata*` and `/liveclientdata/*` routes over HTTPS with a self-signed certificate
+/// on a random port and stops when dropped
+///
+/// ```no_run
+/// # async fn doc() -> std::io::Result<()> {
+/// use shaco::testing::{MockGame, MockIngameServer};
+///
+/// let fixture = serde_json::from_str(include_str!("../../tests/GetLiveclientdataAllgamedata_Arena5.json"))?;
+/// let server = MockIngameServer::start(MockGame::Live(fixture)).await?;
+/// let client = server.ingame_client();
+/// assert!(client.active_game().await);
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockIngameServer {
+    server: Server,
+    state: Arc<Mutex<State>>,
+}
+
+impl MockIngameServer {
+    /// Start a server serving `game`
+    pub async fn start(game: MockGame) -> io::Result<Self> {
+        let state = Arc::new(Mutex::new(State {
+            game,
+            responses: HashMap::new(),
+            selection_name: String::new(),
+        }));
+
+        let handler_state = state.clone();
+        let server = Server::start(move |request, mut stream| {
+            let response = respond(&mut handler_state.lock().unwrap(), &request);
+            async move {
+                if let Some(response) = response {
+                    let head_only = request.method == "HEAD";
+                    _ = write_response(&mut stream, &response, head_only).await;
+                }
+            }
+        })
+        .await?;
+
+        Ok(Self { server, state })
+    }
+
+    /// The port the server is listening on
+    pub fn port(&self) -> u16 {
+        self.server.port
+    }
+
+    /// The PEM encoded self-signed certificate of the server
+    pub fn certificate(&self) -> &str {
+        &self.server.certificate
+    }
+
+    /// An [IngameClient] connected to this server
+    pub fn ingame_client(&self) -> IngameClient {
+        IngameClient::with_port(self.port(), self.certificate().as_bytes())
+            .expect("the generated certificate is valid")
+    }
+
+    /// Change the game that is served
+    pub fn set_game(&self, game: MockGame) {
+        self.state.lock().unwrap().game = game;
+    }
+
+    /// Always answer requests to `path` (e.g. `/GetLiveclientdataGamestats`) with `response`,
+    /// unless no game is running
+    pub fn set_response<S: Into<String>>(&self, path: S, response: MockResponse) {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .insert(path.into(), response);
+    }
+
+    /// Remove all responses set with [MockIngameServer::set_response]
+    pub fn clear_responses(&self) {
+        self.state.lock().unwrap().responses.clear();
+    }
+}
+
+/// Returns [None] if the connection should be closed without a response
+fn respond(state: &mut State, request: &MockRequest) -> Option<MockResponse> {
+    let (data, spectator) = match &state.game {
+        MockGame::NotRunning => return None,
+        _ if state.responses.contains_key(&request.path) => {
+            return state.responses.get(&request.path).cloned()
+        }
+        _ if request.path == "/Help" => return Some(MockResponse::json(&json!({}))),
+        MockGame::LoadingScreen => return Some(not_found()),
+        MockGame::Live(data) => (data, false),
+        MockGame::Spectator(data) => (data, true),
+    };
+    if spectator && request.path.starts_with("/replay/") {
+        return Some(replay(data, &mut state.selection_name, request));
+    }
+
+    // serve the modern routes like their legacy aliases
+    let legacy_route = request
+        .path
+        .strip_prefix("/liveclientdata/")
+        .map(legacy_route);
+    let route = legacy_route
+        .as_deref()
+        .unwrap_or(request.path.trim_start_matches('/'));
+    let response = match route {
+        "GetLiveclientdataAllgamedata" => {
+            let events = events(data, request);
+            let mut data = data.clone();
+            data["events"] = json!({ "Events": events });
+            if spectator {
+                data["activePlayer"] = spectator_error();
+            }
+            MockResponse::json(&data)
+        }
+        "GetLiveclientdataEventdata" => {
+            MockResponse::json(&json!({ "Events": events(data, request) }))
+        }
+        "GetLiveclientdataGamestats" => MockResponse::json(&data["gameData"]),
+        "GetLiveclientdataPlayerlist" => {
+            let team_id = request.query("teamID").unwrap_or_default();
+            let players = players(data)
+                .filter(|p| team_id.is_empty() || p["team"] == team_id)
+                .collect::<Vec<_>>();
+            MockResponse::json(&players)
+        }
+        "GetLiveclientdataPlayeritems"
+        | "GetLiveclientdataPlayermainrunes"
+        | "GetLiveclientdataPlayerscores"
+        | "GetLiveclientdataPlayersummonerspells" => {
+            let riot_id = request.query("riotId").unwrap_or_default();
+            let Some(player) =
+                players(data).find(|p| p["riotId"] == riot_id || p["summonerName"] == riot_id)
+            else {
+                return Some(not_found());
+            };
+            let field = match route {
+                "GetLiveclientdataPlayeritems" => "items",
+                "GetLiveclientdataPlayermainrunes" => "runes",
+                "GetLiveclientdataPlayerscores" => "scores",
+                _ => "summonerSpells",
+            };
+            MockResponse::json(&player[field])
+        }
+        "GetLiveclientdataActiveplayer"
+        | "GetLiveclientdataActiveplayerabilities"
+        | "GetLiveclientdataActiveplayername"
+        | "GetLiveclientdataActiveplayerrunes" => {
+            if spectator {
+                return Some(MockResponse::new(400, spectator_error().to_string()));
+            }
+            let active_player = &data["activePlayer"];
+            match route {
+                "GetLiveclientdataActiveplayer" => MockResponse::json(active_player),
+                "GetLiveclientdataActiveplayerabilities" => {
+                    MockResponse::json(&active_player["abilities"])
+                }
+                "GetLiveclientdataActiveplayername" => MockResponse::json(&active_player["riotId"]),
+                _ => MockResponse::json(&active_player["fullRunes"]),
+            }
+        }
+        _ => not_found(),
+    };
+
+    Some(response)
+}
+
+/// The replay API of a spectated game
+fn replay(data: &Value, selection_name: &mut String, request: &MockRequest) -> MockResponse {
+    match request.path.as_str() {
+        "/replay/playback" => {
+            let game_time = &data["gameData"]["gameTime"];
+            MockResponse::json(&json!({
+                "length": game_time, "paused": false, "seeking": false, "speed": 1.0, "time": game_time
+            }))
+        }
+        "/replay/render" => {
+            if request.method == "POST" {
+                let body = request.json::<Value>().unwrap_or_default();
+                if let Some(name) = body["selectionName"].as_str() {
+                    *selection_name = name.to_string();
+                }
+            }
+            MockResponse::json(&json!({
+                "cameraAttached": !selection_name.is_empty(),
+                "cameraMode": "top",
+                "fogOfWar": true,
+                "interfaceAll": true,
+                "selectionName": selection_name,
+            }))
+        }
+        _ => not_found(),
+    }
+}
+
+/// the events with an `EventID` of at least the requested `eventID`
+fn events(data: &Value, request: &MockRequest) -> Vec<Value> {
+    let event_id = request
+        .query("eventID")
+        .and_then(|id| id.parse::<u64>().ok())
+        .unwrap_or(0);
+    data["events"]["Events"]
+        .as_array()
+        .map(|events| {
+            events
+                .iter()
+                .filter(|e| e["EventID"].as_u64().unwrap_or(0) >= event_id)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// e.g. `GetLiveclientdataPlayeritems` for `playeritems`
+fn legacy_route(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => format!(
+            "GetLiveclientdata{}{}",
+            first.to_ascii_uppercase(),
+            chars.as_str()
+        ),
+        None => String::new(),
+    }
+}
+
+fn players(data: &Value) -> impl Iterator<Item = &Value> {
+    data["allPlayers"].as_array().into_iter().flatten()
+}
+
+fn not_found() -> MockResponse {
+    MockResponse::new(
+        404,
+        json!({ "errorCode": "RESOURCE_NOT_FOUND", "httpStatus": 404 }).to_string(),
+    )
+}
+
+fn spectator_error() -> Value {
+    json!({ "error": "Spectator mode doesn't currently support this feature" })
+}
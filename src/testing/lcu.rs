@@ -0,0 +1,333 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::{io::AsyncWriteExt, sync::broadcast};
+use tokio_tungstenite::{
+    tungstenite::{handshake::derive_accept_key, protocol::Role, Message},
+    WebSocketStream,
+};
+
+use super::server::{write_response, MockRequest, MockResponse, Server, Stream};
+use crate::{
+    error::LcuWebsocketError, model::ws::LcuSubscriptionType, rest::RESTClient,
+    utils::process_info::encode_auth_token, ws::LcuWebsocketClient,
+};
+
+/// WAMP message types used by the LCU
+const SUBSCRIBE: u8 = 5;
+const UNSUBSCRIBE: u8 = 6;
+const EVENT: u8 = 8;
+
+const DEFAULT_AUTH_TOKEN: &str = "shaco-mock-token";
+
+type Handler = Arc<dyn Fn(&MockRequest) -> MockResponse + Send + Sync>;
+
+#[derive(Debug, Clone)]
+enum Broadcast {
+    Event {
+        uri: String,
+        event_type: String,
+        data: Value,
+    },
+    Close,
+}
+
+#[derive(Default)]
+struct State {
+    /// keyed by method and path
+    handlers: HashMap<(String, String), Handler>,
+    /// the subscriptions of all connected websockets
+    subscriptions: HashMap<usize, HashSet<String>>,
+    next_connection_id: usize,
+}
+
+/// A local stand-in for the League-Client(LCU) API \
+/// Serves user registered REST handlers and the WAMP websocket protocol on the same port,
+/// both protected by basic auth with the username `riot`, and stops when dropped
+///
+/// ```no_run
+/// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+/// use shaco::testing::{MockLcuServer, MockResponse};
+///
+/// let server = MockLcuServer::start().await?;
+/// server.route("GET", "/lol-gameflow/v1/gameflow-phase", |_| {
+///     MockResponse::json(&"Lobby")
+/// });
+/// let phase = server.rest_client().get("/lol-gameflow/v1/gameflow-phase").await?;
+/// assert_eq!(phase, "Lobby");
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockLcuServer {
+    server: Server,
+    auth_token: String,
+    state: Arc<Mutex<State>>,
+    broadcast_tx: broadcast::Sender<Broadcast>,
+}
+
+impl MockLcuServer {
+    /// Start a server with a default auth token
+    pub async fn start() -> io::Result<Self> {
+        Self::with_auth_token(DEFAULT_AUTH_TOKEN).await
+    }
+
+    /// Start a server that only accepts `auth_token` as password
+    pub async fn with_auth_token<S: Into<String>>(auth_token: S) -> io::Result<Self> {
+        let auth_token = auth_token.into();
+        let authorization = format!("Basic {}", encode_auth_token(&auth_token));
+        let state = Arc::new(Mutex::new(State::default()));
+        let (broadcast_tx, _) = broadcast::channel(1024);
+
+        let handler_state = state.clone();
+        let handler_tx = broadcast_tx.clone();
+        let server = Server::start(move |request, stream| {
+            let state = handler_state.clone();
+            let broadcast_rx = handler_tx.subscribe();
+            let authorized = request.header("Authorization") == Some(authorization.as_str());
+            async move {
+                handle_connection(request, stream, authorized, state, broadcast_rx).await;
+            }
+        })
+        .await?;
+
+        Ok(Self {
+            server,
+            auth_token,
+            state,
+            broadcast_tx,
+        })
+    }
+
+    /// The port the server is listening on
+    pub fn port(&self) -> u16 {
+        self.server.port
+    }
+
+    /// The PEM encoded self-signed certificate of the server
+    pub fn certificate(&self) -> &str {
+        &self.server.certificate
+    }
+
+    /// The remoting auth token the server accepts
+    pub fn auth_token(&self) -> &str {
+        &self.auth_token
+    }
+
+    /// A [RESTClient] connected to this server
+    pub fn rest_client(&self) -> RESTClient {
+        RESTClient::with_auth(
+            self.port(),
+            self.auth_token(),
+            self.certificate().as_bytes(),
+        )
+        .expect("the generated certificate is valid")
+    }
+
+    /// Connect a [LcuWebsocketClient] to this server
+    pub async fn websocket_client(&self) -> Result<LcuWebsocketClient, LcuWebsocketError> {
+        LcuWebsocketClient::connect_with_auth(
+            self.port(),
+            self.auth_token(),
+            self.certificate().as_bytes(),
+        )
+        .await
+    }
+
+    /// Answer requests with `method` (e.g. `GET`) to `path` (e.g. `/lol-gameflow/v1/session`) with `handler` \
+    /// Requests without a handler get a 404 response
+    pub fn route<F>(&self, method: &str, path: &str, handler: F)
+    where
+        F: Fn(&MockRequest) -> MockResponse + Send + Sync + 'static,
+    {
+        self.state
+            .lock()
+            .unwrap()
+            .handlers
+            .insert((method.to_uppercase(), path.to_string()), Arc::new(handler));
+    }
+
+    /// Push an `OnJsonApiEvent` to all websockets subscribed to the event of `uri` or to all events \
+    /// `event_type` is one of `Create`, `Update` or `Delete`
+    pub fn publish<S: Into<String>>(&self, uri: S, event_type: S, data: Value) {
+        _ = self.broadcast_tx.send(Broadcast::Event {
+            uri: uri.into(),
+            event_type: event_type.into(),
+            data,
+        });
+    }
+
+    /// All events the connected websockets are subscribed to, e.g. `OnJsonApiEvent_lol-gameflow_v1_gameflow-phase`
+    pub fn subscriptions(&self) -> HashSet<String> {
+        self.state
+            .lock()
+            .unwrap()
+            .subscriptions
+            .values()
+            .flatten()
+            .cloned()
+            .collect()
+    }
+
+    /// Wait until a connected websocket is subscribed to the events of `uri`,
+    /// e.g. after handing the websocket to a stream that subscribes to them \
+    /// Panics if no websocket subscribes within a second
+    pub async fn wait_for_subscription(&self, uri: &str) {
+        let topics = event_topics(uri);
+        for _ in 0..100 {
+            if self.subscriptions().iter().any(|s| topics.contains(s)) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("no websocket subscribed to {uri}");
+    }
+
+    /// Close all connected websockets
+    pub fn close_websockets(&self) {
+        _ = self.broadcast_tx.send(Broadcast::Close);
+    }
+}
+
+async fn handle_connection(
+    request: MockRequest,
+    mut stream: Stream,
+    authorized: bool,
+    state: Arc<Mutex<State>>,
+    broadcast_rx: broadcast::Receiver<Broadcast>,
+) {
+    if !authorized {
+        let response = MockResponse::new(
+            401,
+            json!({ "errorCode": "UNAUTHORIZED", "httpStatus": 401 }).to_string(),
+        );
+        _ = write_response(&mut stream, &response, false).await;
+        return;
+    }
+
+    let is_upgrade = request
+        .header("Upgrade")
+        .is_some_and(|upgrade| upgrade.eq_ignore_ascii_case("websocket"));
+    if is_upgrade {
+        handle_websocket(request, stream, state, broadcast_rx).await;
+        return;
+    }
+
+    let handler = state
+        .lock()
+        .unwrap()
+        .handlers
+        .get(&(request.method.clone(), request.path.clone()))
+        .cloned();
+    let response = match handler {
+        Some(handler) => handler(&request),
+        None => MockResponse::new(
+            404,
+            json!({
+                "errorCode": "RESOURCE_NOT_FOUND",
+                "httpStatus": 404,
+                "message": format!("Invalid URI format: {}", request.path),
+            })
+            .to_string(),
+        ),
+    };
+    _ = write_response(&mut stream, &response, request.method == "HEAD").await;
+}
+
+async fn handle_websocket(
+    request: MockRequest,
+    mut stream: Stream,
+    state: Arc<Mutex<State>>,
+    mut broadcast_rx: broadcast::Receiver<Broadcast>,
+) {
+    let Some(key) = request.header("Sec-WebSocket-Key") else {
+        _ = write_response(&mut stream, &MockResponse::status(400), false).await;
+        return;
+    };
+    let handshake = format!(
+        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+        derive_accept_key(key.as_bytes())
+    );
+    if stream.write_all(handshake.as_bytes()).await.is_err() {
+        return;
+    }
+
+    let mut websocket = WebSocketStream::from_raw_socket(stream, Role::Server, None).await;
+    let connection_id = {
+        let mut state = state.lock().unwrap();
+        state.next_connection_id += 1;
+        let connection_id = state.next_connection_id;
+        state.subscriptions.insert(connection_id, HashSet::new());
+        connection_id
+    };
+
+    loop {
+        tokio::select! {
+            message = websocket.next() => {
+                let Some(Ok(message)) = message else {
+                    break;
+                };
+                match message {
+                    Message::Text(text) => {
+                        let Ok((opcode, topic)) = serde_json::from_str::<(u8, String)>(&text) else {
+                            continue;
+                        };
+                        let mut state = state.lock().unwrap();
+                        let subscriptions = state.subscriptions.entry(connection_id).or_default();
+                        match opcode {
+                            SUBSCRIBE => _ = subscriptions.insert(topic),
+                            UNSUBSCRIBE => _ = subscriptions.remove(&topic),
+                            _ => {}
+                        }
+                    }
+                    Message::Close(_) => break,
+                    _ => {}
+                }
+            }
+            broadcast = broadcast_rx.recv() => {
+                match broadcast {
+                    Ok(Broadcast::Event { uri, event_type, data }) => {
+                        let topics = {
+                            let state = state.lock().unwrap();
+                            let subscriptions = &state.subscriptions[&connection_id];
+                            event_topics(&uri)
+                                .into_iter()
+                                .filter(|topic| subscriptions.contains(topic))
+                                .collect::<Vec<_>>()
+                        };
+                        for topic in topics {
+                            let frame = json!([
+                                EVENT,
+                                topic,
+                                { "data": data, "eventType": event_type, "uri": uri }
+                            ]);
+                            if websocket.send(Message::text(frame.to_string())).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    Ok(Broadcast::Close) | Err(broadcast::error::RecvError::Closed) => {
+                        _ = websocket.close(None).await;
+                        break;
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => {}
+                }
+            }
+        }
+    }
+
+    state.lock().unwrap().subscriptions.remove(&connection_id);
+}
+
+/// The topics an event of `uri` gets published to
+fn event_topics(uri: &str) -> [String; 2] {
+    [
+        LcuSubscriptionType::AllJsonApiEvents.to_string(),
+        LcuSubscriptionType::JsonApiEvent(uri.to_string()).to_string(),
+    ]
+}
@@ -0,0 +1,9 @@
+mod ingame;
+mod lcu;
+mod server;
+mod simulator;
+
+pub use ingame::{MockGame, MockIngameServer};
+pub use lcu::MockLcuServer;
+pub use server::{MockRequest, MockResponse};
+pub use simulator::{GameSimulator, SimulatedMap};
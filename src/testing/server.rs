@@ -0,0 +1,276 @@
+use std::{future::Future, io, sync::Arc};
+
+use serde::Serialize;
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+use tokio_native_tls::{native_tls, TlsAcceptor, TlsStream};
+
+const MAX_HEAD_SIZE: usize = 64 * 1024;
+
+/// An HTTP request received by a mock server
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    pub method: String,
+    /// The path without the query, e.g. `/GetLiveclientdataPlayeritems`
+    pub path: String,
+    /// The percent-decoded query parameters
+    pub query: Vec<(String, String)>,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl MockRequest {
+    /// The value of the first query parameter named `key`
+    pub fn query(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// The value of the first header named `name`, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// The body deserialized as JSON
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(&self.body)
+    }
+}
+
+/// An HTTP response returned by a mock server
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    /// Sent with `Content-Type: application/json`
+    pub body: String,
+}
+
+impl MockResponse {
+    /// A response with a status code and a raw body
+    pub fn new<S: Into<String>>(status: u16, body: S) -> Self {
+        Self {
+            status,
+            body: body.into(),
+        }
+    }
+
+    /// A `200 OK` response with `value` serialized as the body
+    pub fn json<T: Serialize>(value: &T) -> Self {
+        Self::new(
+            200,
+            serde_json::to_string(value).expect("mock responses have to serialize to JSON"),
+        )
+    }
+
+    /// A response with an empty body
+    pub fn status(status: u16) -> Self {
+        Self::new(status, "")
+    }
+}
+
+pub(crate) type Stream = TlsStream<TcpStream>;
+
+/// A HTTPS server on a random local port with a freshly generated self-signed certificate \
+/// The server stops when it is dropped
+pub(crate) struct Server {
+    pub(crate) port: u16,
+    pub(crate) certificate: String,
+    accept_task: JoinHandle<()>,
+}
+
+impl Server {
+    /// Start accepting connections, `handler` is called with the first request of every connection
+    pub(crate) async fn start<H, F>(handler: H) -> io::Result<Self>
+    where
+        H: Fn(MockRequest, Stream) -> F + Send + Sync + 'static,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let (certificate, acceptor) = self_signed_acceptor()?;
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let port = listener.local_addr()?.port();
+        let handler = Arc::new(handler);
+
+        let accept_task = tokio::spawn(async move {
+            while let Ok((tcp_stream, _)) = listener.accept().await {
+                let acceptor = acceptor.clone();
+                let handler = handler.clone();
+                tokio::spawn(async move {
+                    let Ok(mut stream) = acceptor.accept(tcp_stream).await else {
+                        return;
+                    };
+                    if let Ok(Some(request)) = read_request(&mut stream).await {
+                        handler(request, stream).await;
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            port,
+            certificate,
+            accept_task,
+        })
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.accept_task.abort()
+    }
+}
+
+fn self_signed_acceptor() -> io::Result<(String, TlsAcceptor)> {
+    let to_io_error = |e: String| io::Error::other(e);
+
+    let certificate =
+        rcgen::generate_simple_self_signed(vec!["127.0.0.1".to_string(), "localhost".to_string()])
+            .map_err(|e| to_io_error(e.to_string()))?;
+    let certificate_pem = certificate
+        .serialize_pem()
+        .map_err(|e| to_io_error(e.to_string()))?;
+    let key_pem = certificate.serialize_private_key_pem();
+
+    let identity = native_tls::Identity::from_pkcs8(certificate_pem.as_bytes(), key_pem.as_bytes())
+        .map_err(|e| to_io_error(e.to_string()))?;
+    let acceptor =
+        native_tls::TlsAcceptor::new(identity).map_err(|e| to_io_error(e.to_string()))?;
+
+    Ok((certificate_pem, acceptor.into()))
+}
+
+/// Read a HTTP/1.1 request \
+/// Returns [None] if the connection was closed before a complete request head was received
+pub(crate) async fn read_request<S: AsyncRead + Unpin>(
+    stream: &mut S,
+) -> io::Result<Option<MockRequest>> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let head_end = loop {
+        if let Some(position) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break position;
+        }
+        if buffer.len() > MAX_HEAD_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "request head too large",
+            ));
+        }
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..head_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split(' ');
+    let method = request_line.next().unwrap_or_default().to_string();
+    let target = request_line.next().unwrap_or_default();
+
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key, true), percent_decode(value, true))
+        })
+        .collect();
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect::<Vec<_>>();
+
+    let content_length = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = buffer[head_end + 4..].to_vec();
+    while body.len() < content_length {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+    body.truncate(content_length);
+
+    Ok(Some(MockRequest {
+        method,
+        path: percent_decode(path, false),
+        query,
+        headers,
+        body,
+    }))
+}
+
+/// Write `response` and close the connection
+pub(crate) async fn write_response<S: AsyncWrite + Unpin>(
+    stream: &mut S,
+    response: &MockResponse,
+    head_only: bool,
+) -> io::Result<()> {
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        reason_phrase(response.status),
+        response.body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    if !head_only {
+        stream.write_all(response.body.as_bytes()).await?;
+    }
+    stream.shutdown().await
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        101 => "Switching Protocols",
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    }
+}
+
+/// `+` only stands for a space in the query, in a path it's kept
+fn percent_decode(s: &str, plus_as_space: bool) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' if plus_as_space => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or_default();
+                match u8::from_str_radix(hex, 16) {
+                    Ok(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    Err(_) => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
@@ -0,0 +1,1703 @@
+use crate::model::ingame::*;
+
+/// The simulation advances in steps of one second of game time
+const TICK: Time = 1.0;
+/// After this game time the leading team ends the game quickly
+const OVERTIME: Time = 3000.0;
+
+/// The maps a [GameSimulator] can simulate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimulatedMap {
+    /// Summoner's Rift 5v5 with lanes, dragons, voidgrubs, the Rift Herald and Baron
+    SummonersRift,
+    /// Howling Abyss ARAM 5v5 with a single lane
+    HowlingAbyss,
+    /// Arena with 8 subteams of 2 players
+    Arena,
+}
+
+/// Generates plausible [AllGameData] timelines, deterministic for a given seed \
+/// Every snapshot contains all events that happened so far, the last snapshot contains the `GameEnd` event
+///
+/// ```
+/// use shaco::{model::ingame::GameEvent, testing::{GameSimulator, SimulatedMap}};
+///
+/// let snapshots = GameSimulator::new(SimulatedMap::HowlingAbyss, 7).collect::<Vec<_>>();
+/// let last = snapshots.last().unwrap();
+/// assert!(matches!(last.events.last(), Some(GameEvent::GameEnd(_))));
+/// ```
+///
+/// The snapshots can be served with a [Playback](crate::playback::Playback) to load-test consumers of the [IngameApi](crate::ingame::IngameApi)
+#[derive(Debug, Clone)]
+pub struct GameSimulator {
+    map: SimulatedMap,
+    rng: Rng,
+    game_time: Time,
+    interval: Time,
+    spectator: bool,
+    players: Vec<SimPlayer>,
+    events: Vec<GameEvent>,
+    next_event_id: EventId,
+    first_blood: bool,
+    first_brick: bool,
+    bases: [Base; 2],
+    objectives: Objectives,
+    arena: Option<ArenaState>,
+    /// the team that destroyed the nexus or the last standing Arena subteam
+    winner: Option<usize>,
+    started: bool,
+    finished: bool,
+}
+
+impl GameSimulator {
+    /// Create a simulator for a game on `map`, the same `seed` always produces the same game \
+    /// Snapshots are taken every 10s of game time from the perspective of the first player
+    pub fn new(map: SimulatedMap, seed: u64) -> Self {
+        let mut rng = Rng(seed);
+        let players = create_players(map, &mut rng);
+        let arena = (map == SimulatedMap::Arena).then(ArenaState::new);
+
+        let mut simulator = Self {
+            map,
+            rng,
+            game_time: 0.0,
+            interval: 10.0,
+            spectator: false,
+            players,
+            events: Vec::new(),
+            next_event_id: 0,
+            first_blood: false,
+            first_brick: false,
+            bases: [Base::new(map, 0), Base::new(map, 1)],
+            objectives: Objectives::new(map),
+            arena,
+            winner: None,
+            started: false,
+            finished: false,
+        };
+        simulator.push(|event_id| {
+            GameEvent::GameStart(GameStart {
+                event_id,
+                event_time: 0.03,
+            })
+        });
+        for index in 0..simulator.players.len() {
+            simulator.shop(index);
+        }
+        simulator
+    }
+
+    /// Set the game time between two snapshots returned by the [Iterator]
+    pub fn set_interval(&mut self, interval: Time) {
+        self.interval = interval.max(TICK);
+    }
+
+    /// Simulate a spectated game without an active player
+    pub fn set_spectator(&mut self, spectator: bool) {
+        self.spectator = spectator;
+    }
+
+    /// The map that is simulated
+    pub fn map(&self) -> SimulatedMap {
+        self.map
+    }
+
+    /// The current game time
+    pub fn game_time(&self) -> Time {
+        self.game_time
+    }
+
+    /// Whether the game has ended
+    pub fn is_finished(&self) -> bool {
+        self.winner.is_some()
+    }
+
+    /// Simulate `seconds` of game time or until the game ends
+    pub fn advance(&mut self, seconds: Time) {
+        let target = self.game_time + seconds;
+        while self.game_time + TICK <= target && !self.is_finished() {
+            self.game_time += TICK;
+            self.tick();
+        }
+    }
+
+    /// Run the simulation until the game ends
+    pub fn run_to_end(&mut self) {
+        while !self.is_finished() {
+            self.advance(self.interval);
+        }
+    }
+
+    /// The current state of the game
+    pub fn snapshot(&self) -> AllGameData {
+        let (game_mode, map_name, map_number) = match self.map {
+            SimulatedMap::SummonersRift => (GameMode::Classic, MapName::Map11, 11),
+            SimulatedMap::HowlingAbyss => (GameMode::Aram, MapName::Map12, 12),
+            SimulatedMap::Arena => (GameMode::Arena, MapName::Map30, 30),
+        };
+        AllGameData {
+            active_player: (!self.spectator).then(|| self.active_player()),
+            all_players: self.players.iter().map(|p| p.to_player(self)).collect(),
+            events: self.events.clone(),
+            game_data: GameStats {
+                game_mode,
+                game_time: self.game_time,
+                map_name,
+                map_number,
+                map_terrain: self.objectives.terrain(),
+            },
+        }
+    }
+
+    fn tick(&mut self) {
+        let t = self.game_time;
+        match self.map {
+            SimulatedMap::SummonersRift if t - TICK < 65.0 && t >= 65.0 => {
+                self.push_minions_spawning(65.0)
+            }
+            SimulatedMap::HowlingAbyss if t - TICK < 45.0 && t >= 45.0 => {
+                self.push_minions_spawning(45.0)
+            }
+            _ => {}
+        }
+
+        self.grow();
+        self.respawn();
+        if self.arena.is_some() {
+            self.arena_tick();
+        } else {
+            self.respawn_inhibitors();
+            self.fight();
+            self.take_objectives();
+            // the game might end here
+            self.push_structures();
+        }
+    }
+
+    fn push(&mut self, make: impl FnOnce(EventId) -> GameEvent) {
+        self.events.push(make(self.next_event_id));
+        self.next_event_id += 1;
+    }
+
+    fn push_minions_spawning(&mut self, event_time: Time) {
+        self.push(|event_id| {
+            GameEvent::MinionsSpawning(MinionsSpawning {
+                event_id,
+                event_time,
+            })
+        });
+    }
+
+    /// passive gold, creeps, experience, wards and shopping
+    fn grow(&mut self) {
+        let t = self.game_time;
+        for index in 0..self.players.len() {
+            let (passive_gold, cs_chance, xp) = match (self.map, &self.players[index].position) {
+                (SimulatedMap::Arena, _) => (0.0, 0.0, 0.0),
+                (SimulatedMap::HowlingAbyss, _) => (5.5, 0.1, 6.0),
+                (SimulatedMap::SummonersRift, _) if t < 90.0 => (0.0, 0.0, 0.0),
+                (SimulatedMap::SummonersRift, Position::Jungle) => (2.04, 0.08, 5.5),
+                (SimulatedMap::SummonersRift, Position::Utility) => (2.04, 0.01, 4.5),
+                (SimulatedMap::SummonersRift, _) => (2.04, 0.13, 6.0),
+            };
+            let chance = self.rng.chance(cs_chance);
+            let player = &mut self.players[index];
+            // players only shop while dead or every 90s, like going back to base
+            if player.dead_until.is_some() {
+                self.shop(index);
+                continue;
+            }
+            player.gold += passive_gold;
+            player.add_xp(xp);
+            if chance {
+                player.scores.creep_score += 1;
+                player.gold += 21.0;
+            }
+            if self.map == SimulatedMap::SummonersRift {
+                player.scores.ward_score += match player.position {
+                    Position::Utility => 0.04,
+                    Position::Jungle => 0.02,
+                    _ => 0.01,
+                };
+            }
+            if (t as i64) % 90 == 0 {
+                self.shop(index);
+            }
+        }
+    }
+
+    fn shop(&mut self, index: usize) {
+        let arena = self.map == SimulatedMap::Arena;
+        let player = &mut self.players[index];
+        while let Some(item) = player.build.items().get(player.next_item) {
+            if player.gold < item.price as f32 {
+                break;
+            }
+            player.gold -= item.price as f32;
+            player.next_item += 1;
+
+            let mut item = item.to_player_item(arena);
+            // the starting item gets sold for the last item
+            let slot = if player.items.len() >= 6 {
+                player.items.remove(0).slot
+            } else {
+                (0..6)
+                    .find(|slot| player.items.iter().all(|i| i.slot != *slot))
+                    .unwrap_or(0)
+            };
+            item.slot = slot;
+            player.items.push(item);
+            player.items.sort_by_key(|i| i.slot);
+        }
+    }
+
+    fn respawn(&mut self) {
+        let t = self.game_time;
+        for player in self.players.iter_mut() {
+            if player.dead_until.is_some_and(|until| until <= t) {
+                player.dead_until = None;
+            }
+        }
+    }
+
+    fn alive(&self, team: usize) -> Vec<usize> {
+        self.players
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.team == team && p.dead_until.is_none())
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn team_gold(&self, team: usize) -> f32 {
+        self.players
+            .iter()
+            .filter(|p| p.team == team)
+            .map(|p| p.total_gold())
+            .sum()
+    }
+
+    /// The chance that the first team wins an encounter
+    fn order_advantage(&self, gold_scale: f32, limit: f32) -> f64 {
+        let gold_difference = self.team_gold(0) - self.team_gold(1);
+        let alive_difference = self.alive(0).len() as f64 - self.alive(1).len() as f64;
+        (0.5 + (gold_difference / gold_scale).clamp(-limit, limit) as f64 + alive_difference * 0.05)
+            .clamp(0.05, 0.95)
+    }
+
+    fn fight(&mut self) {
+        let t = self.game_time;
+        let chance = match self.map {
+            SimulatedMap::HowlingAbyss if t < 60.0 => 0.0,
+            SimulatedMap::HowlingAbyss => 0.04,
+            _ if t < 120.0 => 0.0,
+            _ if t < 600.0 => 0.008,
+            _ if t < 1200.0 => 0.014,
+            _ => 0.02,
+        };
+        if !self.rng.chance(chance) {
+            return;
+        }
+
+        let advantage = self.order_advantage(15000.0, 0.25);
+        let winner = if self.rng.chance(advantage) { 0 } else { 1 };
+        let mut kills = 1;
+        while kills < 5 && self.rng.chance(0.35) {
+            kills += 1;
+        }
+        for kill in 0..kills {
+            let killers = self.alive(winner);
+            let victims = self.alive(1 - winner);
+            if killers.is_empty() || victims.is_empty() {
+                break;
+            }
+            let killer = killers[self.rng.index(killers.len())];
+            let victim = victims[self.rng.index(victims.len())];
+            let assisters = killers
+                .iter()
+                .copied()
+                .filter(|&p| p != killer && self.rng.chance(0.5))
+                .collect::<Vec<_>>();
+            let event_time = t - TICK + 0.15 * (kill + 1) as f64;
+            self.champion_kill(killer, victim, assisters, event_time);
+        }
+    }
+
+    fn champion_kill(
+        &mut self,
+        killer: usize,
+        victim: usize,
+        assisters: Vec<usize>,
+        event_time: Time,
+    ) {
+        let victim_level = self.players[victim].level;
+        let death_timer = self.death_timer(victim_level);
+        let names = assisters
+            .iter()
+            .map(|&p| self.players[p].name.clone())
+            .collect::<Vec<_>>();
+        let killer_name = self.players[killer].name.clone();
+        let victim_name = self.players[victim].name.clone();
+
+        self.push(|event_id| {
+            GameEvent::ChampionKill(ChampionKill {
+                assisters: names,
+                event_id,
+                event_time,
+                killer_name: Killer::Summoner(killer_name.clone()),
+                victim_name,
+            })
+        });
+
+        let mut bounty = 300.0;
+        if !self.first_blood {
+            self.first_blood = true;
+            bounty += 100.0;
+            let recipient = killer_name.clone();
+            self.push(|event_id| {
+                GameEvent::FirstBlood(FirstBlood {
+                    event_id,
+                    event_time,
+                    recipient,
+                })
+            });
+        }
+
+        let victim_player = &mut self.players[victim];
+        victim_player.scores.deaths += 1;
+        victim_player.dead_until = Some(event_time + death_timer);
+        victim_player.streak = 0;
+
+        let xp = 150.0 + 30.0 * victim_level as f64;
+        let killer_player = &mut self.players[killer];
+        killer_player.scores.kills += 1;
+        killer_player.gold += bounty;
+        killer_player.add_xp(xp);
+        if event_time - killer_player.last_kill <= 10.0 && killer_player.streak < 5 {
+            killer_player.streak += 1;
+        } else {
+            killer_player.streak = 1;
+        }
+        killer_player.last_kill = event_time;
+        let kill_streak = killer_player.streak;
+        if kill_streak >= 2 {
+            let killer_name = killer_name.clone();
+            self.push(|event_id| {
+                GameEvent::Multikill(Multikill {
+                    event_id,
+                    event_time,
+                    kill_streak,
+                    killer_name,
+                })
+            });
+        }
+
+        for &assister in assisters.iter() {
+            let share = 150.0 / assisters.len() as f32;
+            let player = &mut self.players[assister];
+            player.scores.assists += 1;
+            player.gold += share;
+            player.add_xp(xp / 2.0);
+        }
+
+        // Arena eliminations are reported by the round results instead
+        let victim_team = self.players[victim].team;
+        if self.map != SimulatedMap::Arena && self.alive(victim_team).is_empty() {
+            let acing_team = team_id(self.players[killer].team);
+            self.push(|event_id| {
+                GameEvent::Ace(Ace {
+                    acer: killer_name,
+                    event_id,
+                    acing_team,
+                    event_time,
+                })
+            });
+        }
+    }
+
+    fn death_timer(&self, level: Level) -> Time {
+        const BASE: [Time; 18] = [
+            10.0, 10.0, 12.0, 12.0, 14.0, 16.0, 20.0, 25.0, 28.0, 32.5, 35.0, 37.5, 40.0, 42.5,
+            45.0, 47.5, 50.0, 52.5,
+        ];
+        let base = BASE[(level.clamp(1, 18) - 1) as usize];
+        match self.map {
+            SimulatedMap::SummonersRift => {
+                base * (1.0 + ((self.game_time - 900.0) / 1800.0).clamp(0.0, 0.5))
+            }
+            SimulatedMap::HowlingAbyss => base * 0.6,
+            // dead until the next round
+            SimulatedMap::Arena => Time::INFINITY,
+        }
+    }
+
+    /// The alive player who takes an objective for `team`, preferring the jungler
+    fn objective_taker(&mut self, team: usize) -> Option<(usize, Vec<usize>)> {
+        let alive = self.alive(team);
+        let killer = alive
+            .iter()
+            .copied()
+            .find(|&p| matches!(self.players[p].position, Position::Jungle))
+            .or_else(|| alive.first().copied())?;
+        let assisters = alive
+            .into_iter()
+            .filter(|&p| p != killer && self.rng.chance(0.6))
+            .collect();
+        Some((killer, assisters))
+    }
+
+    fn take_objectives(&mut self) {
+        if self.map != SimulatedMap::SummonersRift {
+            return;
+        }
+        let t = self.game_time;
+        for objective in [
+            Objective::Dragon,
+            Objective::Voidgrubs,
+            Objective::Herald,
+            Objective::Baron,
+        ] {
+            if !self
+                .objectives
+                .spawn(objective)
+                .is_some_and(|spawn| spawn <= t)
+                || !self.rng.chance(0.02)
+            {
+                continue;
+            }
+            let advantage = self.order_advantage(10000.0, 0.3);
+            let team = if self.rng.chance(advantage) { 0 } else { 1 };
+            let Some((killer, assisters)) = self.objective_taker(team) else {
+                continue;
+            };
+            let stolen = self.rng.chance(0.05);
+            let killer_name = Killer::Summoner(self.players[killer].name.clone());
+            let assisters = assisters
+                .iter()
+                .map(|&p| self.players[p].name.clone())
+                .collect::<Vec<_>>();
+
+            match objective {
+                Objective::Dragon => {
+                    let dragon_type = self.objectives.next_dragon(&mut self.rng);
+                    self.objectives.dragon_taken(team, &dragon_type, t);
+                    self.push(|event_id| {
+                        GameEvent::DragonKill(DragonKill {
+                            assisters,
+                            dragon_type,
+                            event_id,
+                            event_time: t,
+                            killer_name,
+                            stolen,
+                        })
+                    });
+                }
+                Objective::Voidgrubs => {
+                    self.objectives.voidgrubs_taken(t);
+                    for grub in 0..3 {
+                        let assisters = assisters.clone();
+                        let killer_name = killer_name.clone();
+                        self.push(|event_id| {
+                            GameEvent::HordeKill(HordeKill {
+                                assisters,
+                                event_id,
+                                event_time: t - 0.8 + grub as f64 * 0.3,
+                                killer_name,
+                                stolen,
+                            })
+                        });
+                    }
+                }
+                Objective::Herald => {
+                    self.objectives.herald = None;
+                    self.push(|event_id| {
+                        GameEvent::HeraldKill(HeraldKill {
+                            assisters,
+                            event_id,
+                            event_time: t,
+                            killer_name,
+                            stolen,
+                        })
+                    });
+                }
+                Objective::Baron => {
+                    self.objectives.baron = Some(t + 360.0);
+                    self.objectives.baron_buff = Some((team, t + 180.0));
+                    self.push(|event_id| {
+                        GameEvent::BaronKill(BaronKill {
+                            assisters,
+                            event_id,
+                            event_time: t,
+                            killer_name,
+                            stolen,
+                        })
+                    });
+                }
+            }
+        }
+    }
+
+    fn push_structures(&mut self) {
+        let t = self.game_time;
+        let start = match self.map {
+            SimulatedMap::SummonersRift => 480.0,
+            _ => 180.0,
+        };
+        let scale = match self.map {
+            SimulatedMap::SummonersRift => 150000.0,
+            _ => 60000.0,
+        };
+        let mut chance = ((t - start) / scale).clamp(0.0, 0.03);
+        if self
+            .objectives
+            .baron_buff
+            .is_some_and(|(_, until)| until > t)
+        {
+            chance += 0.01;
+        }
+        if t > OVERTIME {
+            chance = 0.1;
+        }
+        if !self.rng.chance(chance) {
+            return;
+        }
+
+        let mut advantage = self.order_advantage(8000.0, 0.4);
+        if let Some((team, until)) = self.objectives.baron_buff {
+            if until > t {
+                advantage = if team == 0 { 0.85 } else { 0.15 };
+            }
+        }
+        let attacker = if self.rng.chance(advantage) { 0 } else { 1 };
+        let Some(target) = self.bases[1 - attacker].next_target(&mut self.rng) else {
+            return;
+        };
+
+        let lane = match target {
+            Target::Turret(lane) | Target::Inhibitor(lane) => lane,
+            _ => 0,
+        };
+        // minions are named by team, lane, wave and a running number
+        let minion = Killer::Minion(format!(
+            "Minion_T{}00L{lane}S{}N{:04}",
+            attacker + 1,
+            (t / 30.0) as u32,
+            self.next_event_id
+        ));
+        let alive = self.alive(attacker);
+        let (killer_name, assisters) = match alive.len() {
+            0 => (minion, Vec::new()),
+            _ if self.rng.chance(0.15) => (minion, Vec::new()),
+            len => {
+                let killer = alive[self.rng.index(len)];
+                let assisters = alive
+                    .iter()
+                    .filter(|&&p| p != killer && self.rng.chance(0.4))
+                    .map(|&p| self.players[p].name.clone())
+                    .collect::<Vec<_>>();
+                (
+                    Killer::Summoner(self.players[killer].name.clone()),
+                    assisters,
+                )
+            }
+        };
+        for &p in alive.iter() {
+            self.players[p].gold += 100.0;
+        }
+
+        match target {
+            Target::Turret(lane) => {
+                let turret = self.bases[1 - attacker].destroy_turret(lane);
+                if !self.first_brick {
+                    self.first_brick = true;
+                    let killer_name = killer_name.clone();
+                    self.push(|event_id| {
+                        GameEvent::FirstBrick(FirstBrick {
+                            event_id,
+                            event_time: t,
+                            killer_name,
+                        })
+                    });
+                }
+                self.push(|event_id| {
+                    GameEvent::TurretKilled(TurretKilled {
+                        assisters,
+                        event_id,
+                        event_time: t,
+                        killer_name,
+                        turret_killed: turret,
+                    })
+                });
+            }
+            Target::Inhibitor(lane) => {
+                let respawn = match self.map {
+                    SimulatedMap::SummonersRift => 300.0,
+                    _ => 240.0,
+                };
+                let inhibitor = self.bases[1 - attacker].destroy_inhibitor(lane, t + respawn);
+                self.push(|event_id| {
+                    GameEvent::InhibKilled(InhibKilled {
+                        assisters,
+                        event_id,
+                        event_time: t,
+                        inhib_killed: inhibitor,
+                        killer_name,
+                    })
+                });
+            }
+            Target::NexusTurret => {
+                let turret = self.bases[1 - attacker].destroy_nexus_turret();
+                self.push(|event_id| {
+                    GameEvent::TurretKilled(TurretKilled {
+                        assisters,
+                        event_id,
+                        event_time: t,
+                        killer_name,
+                        turret_killed: turret,
+                    })
+                });
+            }
+            Target::Nexus => self.end_game(attacker),
+        }
+    }
+
+    fn respawn_inhibitors(&mut self) {
+        let t = self.game_time;
+        for team in 0..2 {
+            for lane in 0..self.bases[team].lanes.len() {
+                let Some(respawn) = self.bases[team].lanes[lane].inhibitor_respawn else {
+                    continue;
+                };
+                let inhibitor = self.bases[team].lanes[lane].inhibitor;
+                if respawn - 15.0 <= t && t - TICK < respawn - 15.0 {
+                    self.push(|event_id| {
+                        GameEvent::InhibRespawningSoon(InhibRespawningSoon {
+                            event_id,
+                            event_time: respawn - 15.0,
+                            inhib_respawning_soon: inhibitor,
+                        })
+                    });
+                }
+                if respawn <= t {
+                    self.bases[team].lanes[lane].inhibitor_respawn = None;
+                    self.push(|event_id| {
+                        GameEvent::InhibRespawned(InhibRespawned {
+                            event_id,
+                            event_time: respawn,
+                            inhib_respawned: inhibitor,
+                        })
+                    });
+                }
+            }
+        }
+    }
+
+    fn arena_tick(&mut self) {
+        let t = self.game_time;
+        let Some(mut arena) = self.arena.take() else {
+            return;
+        };
+
+        if t >= arena.round_start() && arena.duels.is_empty() && !arena.fighting {
+            // shopping phase is over, pair the remaining subteams
+            arena.fighting = true;
+            let mut subteams = arena.alive_subteams();
+            self.rng.shuffle(&mut subteams);
+            arena.duels = subteams
+                .chunks_exact(2)
+                .map(|pair| (pair[0], pair[1]))
+                .collect();
+        }
+
+        if arena.fighting {
+            let force = t >= arena.round_start() + 30.0;
+            let mut remaining = Vec::new();
+            for (a, b) in std::mem::take(&mut arena.duels) {
+                if force || self.rng.chance(0.1) {
+                    self.arena_duel(&mut arena, a, b);
+                } else {
+                    remaining.push((a, b));
+                }
+            }
+            arena.duels = remaining;
+
+            if arena.duels.is_empty() {
+                arena.fighting = false;
+                arena.round += 1;
+                let alive = arena.alive_subteams();
+                if alive.len() <= 1 {
+                    let winner = alive.first().copied().unwrap_or(0);
+                    self.arena = Some(arena);
+                    self.end_game(winner);
+                    return;
+                }
+                self.arena_round_end(&arena);
+            }
+        }
+        self.arena = Some(arena);
+    }
+
+    fn arena_duel(&mut self, arena: &mut ArenaState, a: usize, b: usize) {
+        let power = |players: &[SimPlayer], subteam: usize| {
+            players
+                .iter()
+                .filter(|p| p.subteam == subteam)
+                .map(|p| p.total_gold() + p.level as f32 * 300.0)
+                .sum::<f32>()
+        };
+        let (power_a, power_b) = (power(&self.players, a), power(&self.players, b));
+        let (winner, loser) = if self.rng.chance((power_a / (power_a + power_b)) as f64) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let members = |subteam: usize| {
+            self.players
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| p.subteam == subteam)
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>()
+        };
+        let (winners, losers) = (members(winner), members(loser));
+
+        let mut event_time = self.game_time - 0.9;
+        // sometimes the winners lose a player before taking the round
+        if self.rng.chance(0.3) {
+            let killer = losers[self.rng.index(losers.len())];
+            let victim = winners[self.rng.index(winners.len())];
+            let assisters = losers.iter().copied().filter(|&p| p != killer).collect();
+            self.champion_kill(killer, victim, assisters, event_time);
+            event_time += 0.25;
+        }
+        let mut last_killer = winners[0];
+        for &victim in losers.iter() {
+            let alive = winners
+                .iter()
+                .copied()
+                .filter(|&p| self.players[p].dead_until.is_none())
+                .collect::<Vec<_>>();
+            let killer = alive[self.rng.index(alive.len())];
+            let assisters = alive.iter().copied().filter(|&p| p != killer).collect();
+            self.champion_kill(killer, victim, assisters, event_time);
+            last_killer = killer;
+            event_time += 0.25;
+        }
+
+        let damage = 8 + 2 * arena.round as i32;
+        arena.health[loser] -= damage;
+        if arena.health[loser] <= 0 {
+            let acer = self.players[last_killer].name.clone();
+            let acing_team = team_id(self.players[last_killer].team);
+            self.push(|event_id| {
+                GameEvent::Ace(Ace {
+                    acer,
+                    acing_team,
+                    event_id,
+                    event_time,
+                })
+            });
+        }
+    }
+
+    /// revive the players of the remaining subteams and give them gold and levels for the next round
+    fn arena_round_end(&mut self, arena: &ArenaState) {
+        for index in 0..self.players.len() {
+            let player = &mut self.players[index];
+            if arena.health[player.subteam] <= 0 {
+                player.dead_until = Some(Time::INFINITY);
+                continue;
+            }
+            player.dead_until = None;
+            player.gold += 1000.0 + 100.0 * arena.round as f32;
+            player.level = (player.level + 2).min(18);
+            self.shop(index);
+        }
+    }
+
+    fn end_game(&mut self, winner: usize) {
+        self.winner = Some(winner);
+        let active_won = match self.map {
+            SimulatedMap::Arena => self.players[0].subteam == winner,
+            _ => self.players[0].team == winner,
+        };
+        let result = if active_won {
+            GameResult::Win
+        } else {
+            GameResult::Lose
+        };
+        let event_time = self.game_time;
+        self.push(|event_id| {
+            GameEvent::GameEnd(GameEnd {
+                event_id,
+                event_time,
+                result,
+            })
+        });
+    }
+
+    fn active_player(&self) -> ActivePlayer {
+        let player = &self.players[0];
+        let riot_id = player.riot_id();
+        let runes = match &player.runes {
+            Some(runes) => FullPlayerRunes::Runes {
+                general_runes: vec![runes.keystone.clone()],
+                keystone: runes.keystone.clone(),
+                primary_rune_tree: Box::new(runes.primary_rune_tree.clone()),
+                secondary_rune_tree: Box::new(runes.secondary_rune_tree.clone()),
+                stat_runes: [
+                    (5008, "StatModAdaptive"),
+                    (5008, "StatModAdaptive"),
+                    (5001, "StatModHealthScaling"),
+                ]
+                .into_iter()
+                .map(|(id, name)| StatRune {
+                    id,
+                    raw_description: format!("perk_tooltip_{name}"),
+                })
+                .collect(),
+            },
+            None => FullPlayerRunes::NoRunes {},
+        };
+        ActivePlayer {
+            summoner_name: riot_id.riot_id.clone(),
+            riot_id,
+            abilities: player.abilities(),
+            champion_stats: player.champion_stats(),
+            current_gold: player.gold,
+            runes,
+            level: player.level,
+            team_relative_colors: true,
+        }
+    }
+}
+
+impl Iterator for GameSimulator {
+    type Item = AllGameData;
+
+    /// Advance by the interval and return the snapshot, the last snapshot is taken when the game ends
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        // the first snapshot is taken at the start of the game
+        if self.started {
+            self.advance(self.interval);
+        }
+        self.started = true;
+        self.finished = self.is_finished();
+        Some(self.snapshot())
+    }
+}
+
+fn team_id(team: usize) -> TeamId {
+    match team {
+        0 => TeamId::Order,
+        _ => TeamId::Chaos,
+    }
+}
+
+/// A small SplitMix64 generator so the simulation doesn't depend on a rng crate
+#[derive(Debug, Clone)]
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// uniform in `0.0..1.0`
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn chance(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+
+    fn index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Build {
+    Fighter,
+    Tank,
+    Marksman,
+    Mage,
+    Enchanter,
+    Bruiser,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ItemInfo {
+    id: ItemId,
+    name: &'static str,
+    price: Price,
+}
+
+const fn item(id: ItemId, name: &'static str, price: Price) -> ItemInfo {
+    ItemInfo { id, name, price }
+}
+
+impl ItemInfo {
+    fn to_player_item(self, arena: bool) -> PlayerItem {
+        // Arena uses its own copies of the items
+        let id = if arena { 220000 + self.id } else { self.id };
+        PlayerItem {
+            can_use: false,
+            consumable: false,
+            count: 1,
+            display_name: self.name.to_string(),
+            item_id: id,
+            price: self.price,
+            raw_description: format!("GeneratedTip_Item_{id}_Description"),
+            raw_display_name: format!("Item_{}_Name", self.id),
+            slot: 0,
+        }
+    }
+}
+
+impl Build {
+    /// a starting item followed by 6 items, the starting item gets sold for the last one
+    fn items(self) -> &'static [ItemInfo] {
+        const FIGHTER: [ItemInfo; 7] = [
+            item(1055, "Doran's Blade", 450),
+            item(3047, "Plated Steelcaps", 1200),
+            item(3071, "Black Cleaver", 3000),
+            item(3053, "Sterak's Gage", 3200),
+            item(6333, "Death's Dance", 3300),
+            item(3026, "Guardian Angel", 3200),
+            item(3156, "Maw of Malmortius", 3100),
+        ];
+        const TANK: [ItemInfo; 7] = [
+            item(1054, "Doran's Shield", 450),
+            item(3047, "Plated Steelcaps", 1200),
+            item(3068, "Sunfire Aegis", 2700),
+            item(3075, "Thornmail", 2450),
+            item(3065, "Spirit Visage", 2700),
+            item(3143, "Randuin's Omen", 2700),
+            item(3083, "Warmog's Armor", 3100),
+        ];
+        const MARKSMAN: [ItemInfo; 7] = [
+            item(1055, "Doran's Blade", 450),
+            item(3006, "Berserker's Greaves", 1100),
+            item(6672, "Kraken Slayer", 3000),
+            item(3031, "Infinity Edge", 3450),
+            item(3046, "Phantom Dancer", 2650),
+            item(3036, "Lord Dominik's Regards", 3000),
+            item(3072, "Bloodthirster", 3400),
+        ];
+        const MAGE: [ItemInfo; 7] = [
+            item(1056, "Doran's Ring", 400),
+            item(3020, "Sorcerer's Shoes", 1100),
+            item(6655, "Luden's Companion", 2850),
+            item(3157, "Zhonya's Hourglass", 3250),
+            item(3089, "Rabadon's Deathcap", 3500),
+            item(3135, "Void Staff", 3000),
+            item(4645, "Shadowflame", 3200),
+        ];
+        const ENCHANTER: [ItemInfo; 7] = [
+            item(3865, "World Atlas", 400),
+            item(3158, "Ionian Boots of Lucidity", 900),
+            item(6617, "Moonstone Renewer", 2200),
+            item(3107, "Redemption", 2300),
+            item(3190, "Locket of the Iron Solari", 2200),
+            item(3109, "Knight's Vow", 2300),
+            item(3050, "Zeke's Convergence", 2200),
+        ];
+        const BRUISER: [ItemInfo; 7] = [
+            item(1102, "Gustwalker Hatchling", 450),
+            item(3047, "Plated Steelcaps", 1200),
+            item(3071, "Black Cleaver", 3000),
+            item(3053, "Sterak's Gage", 3200),
+            item(3065, "Spirit Visage", 2700),
+            item(6333, "Death's Dance", 3300),
+            item(3026, "Guardian Angel", 3200),
+        ];
+        match self {
+            Build::Fighter => &FIGHTER,
+            Build::Tank => &TANK,
+            Build::Marksman => &MARKSMAN,
+            Build::Mage => &MAGE,
+            Build::Enchanter => &ENCHANTER,
+            Build::Bruiser => &BRUISER,
+        }
+    }
+}
+
+/// a champion with its build and resource
+type Champion = (&'static str, Build, ResourceType);
+
+/// champions per position
+const CHAMPIONS: [(Position, [Champion; 5]); 5] = [
+    (
+        Position::Top,
+        [
+            ("Darius", Build::Fighter, ResourceType::Mana),
+            ("Garen", Build::Fighter, ResourceType::None),
+            ("Ornn", Build::Tank, ResourceType::Mana),
+            ("Fiora", Build::Fighter, ResourceType::Mana),
+            ("Sett", Build::Fighter, ResourceType::Gnarfury),
+        ],
+    ),
+    (
+        Position::Jungle,
+        [
+            ("Lee Sin", Build::Bruiser, ResourceType::Energy),
+            ("Vi", Build::Bruiser, ResourceType::Mana),
+            ("Amumu", Build::Tank, ResourceType::Mana),
+            ("Kha'Zix", Build::Bruiser, ResourceType::Mana),
+            ("Graves", Build::Bruiser, ResourceType::Mana),
+        ],
+    ),
+    (
+        Position::Middle,
+        [
+            ("Ahri", Build::Mage, ResourceType::Mana),
+            ("Syndra", Build::Mage, ResourceType::Mana),
+            ("Zed", Build::Fighter, ResourceType::Energy),
+            ("Orianna", Build::Mage, ResourceType::Mana),
+            ("Viktor", Build::Mage, ResourceType::Mana),
+        ],
+    ),
+    (
+        Position::Bottom,
+        [
+            ("Jinx", Build::Marksman, ResourceType::Mana),
+            ("Kai'Sa", Build::Marksman, ResourceType::Mana),
+            ("Ezreal", Build::Marksman, ResourceType::Mana),
+            ("Caitlyn", Build::Marksman, ResourceType::Mana),
+            ("Jhin", Build::Marksman, ResourceType::Mana),
+        ],
+    ),
+    (
+        Position::Utility,
+        [
+            ("Thresh", Build::Tank, ResourceType::Mana),
+            ("Lulu", Build::Enchanter, ResourceType::Mana),
+            ("Leona", Build::Tank, ResourceType::Mana),
+            ("Nami", Build::Enchanter, ResourceType::Mana),
+            ("Nautilus", Build::Tank, ResourceType::Mana),
+        ],
+    ),
+];
+
+/// keystone, primary tree and secondary tree
+type RunePage = (
+    (RuneId, &'static str),
+    (RuneTreeId, &'static str),
+    (RuneTreeId, &'static str),
+);
+
+const RUNES: [RunePage; 5] = [
+    (
+        (8005, "Press the Attack"),
+        (8000, "Precision"),
+        (8400, "Resolve"),
+    ),
+    (
+        (8112, "Electrocute"),
+        (8100, "Domination"),
+        (8200, "Sorcery"),
+    ),
+    (
+        (8437, "Grasp of the Undying"),
+        (8400, "Resolve"),
+        (8300, "Inspiration"),
+    ),
+    ((8214, "Summon Aery"), (8200, "Sorcery"), (8400, "Resolve")),
+    (
+        (8351, "Glacial Augment"),
+        (8300, "Inspiration"),
+        (8000, "Precision"),
+    ),
+];
+
+fn create_players(map: SimulatedMap, rng: &mut Rng) -> Vec<SimPlayer> {
+    let mut pool = CHAMPIONS
+        .iter()
+        .flat_map(|(position, champions)| champions.iter().map(move |c| (position, c)))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut pool);
+
+    let count = match map {
+        SimulatedMap::Arena => 16,
+        _ => 10,
+    };
+    (0..count)
+        .map(|index| {
+            let (team, subteam, position, champion) = match map {
+                SimulatedMap::SummonersRift => {
+                    // every team gets one champion of every position
+                    let (position, champions) = &CHAMPIONS[index % 5];
+                    let champion = &champions[rng.index(5)];
+                    (index / 5, index / 5, position.clone(), champion)
+                }
+                SimulatedMap::HowlingAbyss => (index / 5, index / 5, Position::None, pool[index].1),
+                SimulatedMap::Arena => (index / 8, index / 2, Position::None, pool[index].1),
+            };
+            SimPlayer::new(map, index, team, subteam, position, champion, rng)
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+struct SimPlayer {
+    name: String,
+    champion: &'static str,
+    build: Build,
+    resource: ResourceType,
+    team: usize,
+    /// the Arena subteam, otherwise the same as `team`
+    subteam: usize,
+    position: Position,
+    summoner_spells: [&'static str; 2],
+    runes: Option<PlayerRunes>,
+    skin_id: SkinId,
+    level: Level,
+    xp: f64,
+    gold: Gold,
+    next_item: usize,
+    items: Vec<PlayerItem>,
+    scores: PlayerScores,
+    dead_until: Option<Time>,
+    last_kill: Time,
+    streak: KillStreak,
+}
+
+impl SimPlayer {
+    fn new(
+        map: SimulatedMap,
+        index: usize,
+        team: usize,
+        subteam: usize,
+        position: Position,
+        (champion, build, resource): &Champion,
+        rng: &mut Rng,
+    ) -> Self {
+        let second_spell = match (map, &position) {
+            (SimulatedMap::Arena, _) => "Flee",
+            (SimulatedMap::HowlingAbyss, _) => "Mark",
+            (_, Position::Top) => "Teleport",
+            (_, Position::Jungle) => "Smite",
+            (_, Position::Middle) => "Ignite",
+            (_, Position::Bottom) => "Heal",
+            _ => "Exhaust",
+        };
+        let runes = (map != SimulatedMap::Arena).then(|| {
+            let ((keystone_id, keystone), (primary_id, primary), (secondary_id, secondary)) =
+                RUNES[rng.index(RUNES.len())];
+            let tree = |id: RuneTreeId, name: &str| RuneTree {
+                display_name: name.to_string(),
+                id,
+                raw_description: format!("perkstyle_tooltip_{id}"),
+                raw_display_name: format!("perkstyle_displayname_{id}"),
+            };
+            let name = keystone.replace(' ', "");
+            PlayerRunes {
+                keystone: Rune {
+                    display_name: keystone.to_string(),
+                    id: keystone_id,
+                    raw_description: format!("perk_tooltip_{name}"),
+                    raw_display_name: format!("perk_displayname_{name}"),
+                },
+                primary_rune_tree: tree(primary_id, primary),
+                secondary_rune_tree: tree(secondary_id, secondary),
+            }
+        });
+        let (level, gold) = match map {
+            SimulatedMap::SummonersRift => (1, 500.0),
+            SimulatedMap::HowlingAbyss => (3, 1400.0),
+            SimulatedMap::Arena => (3, 1400.0),
+        };
+
+        Self {
+            name: format!("Player {}", index + 1),
+            champion,
+            build: *build,
+            resource: resource.clone(),
+            team,
+            subteam,
+            position,
+            summoner_spells: ["Flash", second_spell],
+            runes,
+            skin_id: rng.index(4) as SkinId,
+            level,
+            xp: XP_TABLE[(level - 1) as usize],
+            gold,
+            next_item: 0,
+            items: Vec::new(),
+            scores: PlayerScores {
+                kills: 0,
+                deaths: 0,
+                assists: 0,
+                creep_score: 0,
+                ward_score: 0.0,
+            },
+            dead_until: None,
+            last_kill: Time::NEG_INFINITY,
+            streak: 0,
+        }
+    }
+
+    fn add_xp(&mut self, xp: f64) {
+        self.xp += xp;
+        while self.level < 18 && self.xp >= XP_TABLE[self.level as usize] {
+            self.level += 1;
+        }
+    }
+
+    fn total_gold(&self) -> Gold {
+        self.gold + self.items.iter().map(|i| i.price as f32).sum::<f32>()
+    }
+
+    fn riot_id(&self) -> RiotId {
+        RiotId {
+            riot_id: format!("{}#SIM", self.name),
+            game_name: self.name.clone(),
+            tag_line: "SIM".to_string(),
+        }
+    }
+
+    fn raw_champion(&self) -> String {
+        self.champion.replace([' ', '\''], "")
+    }
+
+    fn to_player(&self, simulator: &GameSimulator) -> Player {
+        let riot_id = self.riot_id();
+        let raw_champion = self.raw_champion();
+        let spell = |name: &str| SummonerSpell {
+            display_name: name.to_string(),
+            raw_description: format!("GeneratedTip_SummonerSpell_Summoner{name}_Description"),
+            raw_display_name: format!("GeneratedTip_SummonerSpell_Summoner{name}_DisplayName"),
+        };
+        let mut items = self.items.clone();
+        match simulator.map {
+            SimulatedMap::SummonersRift => items.push(trinket(3340, "Stealth Ward", 1)),
+            SimulatedMap::HowlingAbyss => items.push(trinket(2052, "Poro-Snax", 2)),
+            SimulatedMap::Arena => {}
+        }
+
+        Player {
+            summoner_name: riot_id.riot_id.clone(),
+            riot_id,
+            champion_name: self.champion.to_string(),
+            raw_champion_name: format!("game_character_displayname_{raw_champion}"),
+            skin_name: (self.skin_id != 0)
+                .then(|| format!("{} Skin {}", self.champion, self.skin_id)),
+            raw_skin_name: (self.skin_id != 0).then(|| {
+                format!(
+                    "game_character_skin_displayname_{raw_champion}_{}",
+                    self.skin_id
+                )
+            }),
+            skin_id: self.skin_id,
+            is_bot: false,
+            is_dead: self.dead_until.is_some(),
+            items,
+            level: self.level,
+            position: self.position.clone(),
+            respawn_timer: self
+                .dead_until
+                .filter(|until| until.is_finite())
+                .map(|until| until - simulator.game_time)
+                .unwrap_or(0.0),
+            runes: self.runes.clone(),
+            scores: self.scores.clone(),
+            summoner_spells: SummonerSpells::SummonerSpells {
+                summoner_spell_one: spell(self.summoner_spells[0]),
+                summoner_spell_two: spell(self.summoner_spells[1]),
+            },
+            team: team_id(self.team),
+        }
+    }
+
+    fn abilities(&self) -> PlayerAbilities {
+        let champion = self.raw_champion();
+        // ultimate at 6, 11 and 16, one point in every basic ability first and then maxed one after another
+        let r = match self.level {
+            ..=5 => 0,
+            6..=10 => 1,
+            11..=15 => 2,
+            _ => 3,
+        };
+        let mut points = self.level - r;
+        let mut level_up = |first: bool| {
+            let level = if first { points.min(1) } else { points.min(4) };
+            points -= level;
+            level
+        };
+        let (q, w, e) = (level_up(true), level_up(true), level_up(true));
+        let (q, w, e) = (
+            q + level_up(false),
+            w + level_up(false),
+            e + level_up(false),
+        );
+        let ability = |key: &str, level: AbilityLevel| Ability {
+            ability_level: level,
+            display_name: format!("{} {key}", self.champion),
+            id: format!("{champion}{key}"),
+            raw_description: format!("GeneratedTip_Spell_{champion}{key}_Description"),
+            raw_display_name: format!("GeneratedTip_Spell_{champion}{key}_DisplayName"),
+        };
+        PlayerAbilities {
+            e: ability("E", e),
+            passive: Passive {
+                display_name: format!("{} Passive", self.champion),
+                id: format!("{champion}Passive"),
+                raw_description: format!("GeneratedTip_Passive_{champion}Passive_Description"),
+                raw_display_name: format!("GeneratedTip_Passive_{champion}Passive_DisplayName"),
+            },
+            q: ability("Q", q),
+            r: ability("R", r),
+            w: ability("W", w),
+        }
+    }
+
+    fn champion_stats(&self) -> PlayerChampionStats {
+        let level = (self.level - 1) as f32;
+        let items = self.items.len().saturating_sub(1) as f32;
+        let (physical, magic, tank) = match self.build {
+            Build::Tank => (0.0, 0.0, items),
+            Build::Mage | Build::Enchanter => (0.0, items, 0.0),
+            Build::Fighter | Build::Marksman | Build::Bruiser => (items, 0.0, 0.0),
+        };
+        let max_health = 600.0 + 100.0 * level + 350.0 * tank + 150.0 * physical;
+        let (resource_max, resource_value) = match self.resource {
+            ResourceType::Mana => (300.0 + 50.0 * level, 300.0 + 50.0 * level),
+            ResourceType::Energy => (200.0, 200.0),
+            ResourceType::Gnarfury => (100.0, 0.0),
+            _ => (0.0, 0.0),
+        };
+        let crit_chance = match self.build {
+            Build::Marksman => (physical * 0.25 - 0.25).clamp(0.0, 1.0),
+            _ => 0.0,
+        };
+
+        PlayerChampionStats {
+            ability_haste: 10.0 * (tank + magic),
+            ability_power: 80.0 * magic,
+            armor: 32.0 + 4.5 * level + 50.0 * tank,
+            armor_penetration_flat: 0.0,
+            armor_penetration_percent: 1.0,
+            attack_damage: 60.0 + 3.5 * level + 45.0 * physical,
+            attack_range: match self.build {
+                Build::Marksman => 550.0,
+                Build::Mage | Build::Enchanter => 525.0,
+                _ => 125.0,
+            },
+            attack_speed: 0.65 + 0.02 * level + 0.15 * physical,
+            bonus_armor_penetration_percent: 0.0,
+            bonus_magic_penetration_percent: 0.0,
+            crit_chance,
+            crit_damage: 175.0,
+            current_health: if self.dead_until.is_some() {
+                0.0
+            } else {
+                max_health
+            },
+            heal_shield_power: 0.0,
+            health_regen_rate: 1.5 + 0.1 * level,
+            life_steal: 0.0,
+            magic_lethality: 0.0,
+            magic_penetration_flat: 0.0,
+            magic_penetration_percent: 1.0,
+            magic_resist: 32.0 + 2.0 * level + 40.0 * tank,
+            max_health,
+            move_speed: 340.0 + if self.items.len() > 1 { 45.0 } else { 0.0 },
+            omnivamp: 0.0,
+            physical_lethality: 0.0,
+            physical_vamp: 0.0,
+            resource_max,
+            resource_regen_rate: 1.5,
+            resource_type: self.resource.clone(),
+            resource_value,
+            spell_vamp: 0.0,
+            tenacity: 0.0,
+        }
+    }
+}
+
+fn trinket(id: ItemId, name: &str, count: ItemCount) -> PlayerItem {
+    PlayerItem {
+        can_use: true,
+        consumable: count > 1,
+        count,
+        display_name: name.to_string(),
+        item_id: id,
+        price: 0,
+        raw_description: format!("GeneratedTip_Item_{id}_Description"),
+        raw_display_name: format!("Item_{id}_Name"),
+        slot: 6,
+    }
+}
+
+/// the total experience needed for every level
+const XP_TABLE: [f64; 18] = [
+    0.0, 280.0, 660.0, 1140.0, 1720.0, 2400.0, 3180.0, 4060.0, 5040.0, 6120.0, 7300.0, 8580.0,
+    9960.0, 11440.0, 13020.0, 14700.0, 16480.0, 18360.0,
+];
+
+#[derive(Debug, Clone)]
+struct Lane {
+    /// from the outer turret to the inhibitor turret
+    turrets: Vec<Turret>,
+    destroyed: usize,
+    inhibitor: Inhibitor,
+    inhibitor_respawn: Option<Time>,
+}
+
+#[derive(Debug, Clone)]
+struct Base {
+    lanes: Vec<Lane>,
+    nexus_turrets: Vec<Turret>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    Turret(usize),
+    Inhibitor(usize),
+    NexusTurret,
+    Nexus,
+}
+
+impl Base {
+    fn new(map: SimulatedMap, team: usize) -> Self {
+        use Inhibitor as I;
+        use Turret as T;
+        let lane = |turrets: Vec<Turret>, inhibitor| Lane {
+            turrets,
+            destroyed: 0,
+            inhibitor,
+            inhibitor_respawn: None,
+        };
+        let (lanes, nexus_turrets) = match (map, team) {
+            (SimulatedMap::SummonersRift, 0) => (
+                vec![
+                    lane(vec![T::Team1L03A, T::Team1L02A, T::Team1C06A], I::Team1L1),
+                    lane(vec![T::Team1C05A, T::Team1C04A, T::Team1C03A], I::Team1C1),
+                    lane(vec![T::Team1R03A, T::Team1R02A, T::Team1C07A], I::Team1R1),
+                ],
+                vec![T::Team1C01A, T::Team1C02A],
+            ),
+            (SimulatedMap::SummonersRift, _) => (
+                vec![
+                    lane(vec![T::Team2L03A, T::Team2L02A, T::Team2L01A], I::Team2L1),
+                    lane(vec![T::Team2C05A, T::Team2C04A, T::Team2C03A], I::Team2C1),
+                    lane(vec![T::Team2R03A, T::Team2R02A, T::Team2R01A], I::Team2R1),
+                ],
+                vec![T::Team2C01A, T::Team2C02A],
+            ),
+            (_, 0) => (
+                vec![lane(vec![T::Team1C08A, T::Team1C07A], I::Team1C1)],
+                vec![T::Team1C09A, T::Team1C10A],
+            ),
+            (_, _) => (
+                vec![lane(vec![T::Team2L01A, T::Team2L02A], I::Team2C1)],
+                vec![T::Team2L03A, T::Team2L04A],
+            ),
+        };
+        Self {
+            lanes,
+            nexus_turrets,
+        }
+    }
+
+    fn next_target(&self, rng: &mut Rng) -> Option<Target> {
+        let open = self.lanes.iter().any(|l| l.inhibitor_respawn.is_some());
+        if open && rng.chance(0.5) {
+            return Some(if self.nexus_turrets.is_empty() {
+                Target::Nexus
+            } else {
+                Target::NexusTurret
+            });
+        }
+
+        let targets = self
+            .lanes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, lane)| match lane {
+                lane if lane.destroyed < lane.turrets.len() => Some(Target::Turret(index)),
+                lane if lane.inhibitor_respawn.is_none() => Some(Target::Inhibitor(index)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        match targets.len() {
+            0 if open => Some(Target::NexusTurret).filter(|_| !self.nexus_turrets.is_empty()),
+            0 => None,
+            len => Some(targets[rng.index(len)]),
+        }
+    }
+
+    fn destroy_turret(&mut self, lane: usize) -> Turret {
+        let lane = &mut self.lanes[lane];
+        lane.destroyed += 1;
+        lane.turrets[lane.destroyed - 1]
+    }
+
+    fn destroy_inhibitor(&mut self, lane: usize, respawn: Time) -> Inhibitor {
+        let lane = &mut self.lanes[lane];
+        lane.inhibitor_respawn = Some(respawn);
+        lane.inhibitor
+    }
+
+    fn destroy_nexus_turret(&mut self) -> Turret {
+        self.nexus_turrets.remove(0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Objective {
+    Dragon,
+    Voidgrubs,
+    Herald,
+    Baron,
+}
+
+#[derive(Debug, Clone)]
+struct Objectives {
+    dragon: Option<Time>,
+    voidgrubs: Option<Time>,
+    voidgrub_waves: u8,
+    herald: Option<Time>,
+    baron: Option<Time>,
+    baron_buff: Option<(usize, Time)>,
+    dragons: [u8; 2],
+    /// the first two dragons are random, all following elemental dragons are of this type
+    rift_type: Option<DragonType>,
+    first_dragon: Option<DragonType>,
+}
+
+impl Objectives {
+    fn new(map: SimulatedMap) -> Self {
+        let rift = map == SimulatedMap::SummonersRift;
+        Self {
+            dragon: rift.then_some(300.0),
+            voidgrubs: rift.then_some(360.0),
+            voidgrub_waves: 0,
+            herald: rift.then_some(900.0),
+            baron: rift.then_some(1200.0),
+            baron_buff: None,
+            dragons: [0, 0],
+            rift_type: None,
+            first_dragon: None,
+        }
+    }
+
+    fn spawn(&self, objective: Objective) -> Option<Time> {
+        match objective {
+            Objective::Dragon => self.dragon,
+            Objective::Voidgrubs => self.voidgrubs,
+            Objective::Herald => self.herald,
+            Objective::Baron => self.baron,
+        }
+    }
+
+    fn next_dragon(&mut self, rng: &mut Rng) -> DragonType {
+        const ELEMENTS: [DragonType; 6] = [
+            DragonType::Infernal,
+            DragonType::Ocean,
+            DragonType::Mountain,
+            DragonType::Cloud,
+            DragonType::Hextech,
+            DragonType::Chemtech,
+        ];
+        if self.dragons.iter().any(|&d| d >= 4) {
+            return DragonType::Elder;
+        }
+        if let Some(rift_type) = &self.rift_type {
+            return rift_type.clone();
+        }
+        let dragon = ELEMENTS[rng.index(ELEMENTS.len())].clone();
+        match self.first_dragon {
+            None => self.first_dragon = Some(dragon.clone()),
+            Some(_) => {
+                let first = self.first_dragon.as_ref().map(ToString::to_string);
+                let rift_type = ELEMENTS
+                    .iter()
+                    .cycle()
+                    .skip(rng.index(ELEMENTS.len()))
+                    .find(|d| Some(d.to_string()) != first && d.to_string() != dragon.to_string())
+                    .cloned();
+                self.rift_type = rift_type;
+            }
+        }
+        dragon
+    }
+
+    fn dragon_taken(&mut self, team: usize, dragon_type: &DragonType, t: Time) {
+        let elder = matches!(dragon_type, DragonType::Elder);
+        if !elder {
+            self.dragons[team] += 1;
+        }
+        let respawn = if elder || self.dragons.iter().any(|&d| d >= 4) {
+            360.0
+        } else {
+            300.0
+        };
+        self.dragon = Some(t + respawn);
+    }
+
+    fn voidgrubs_taken(&mut self, t: Time) {
+        self.voidgrub_waves += 1;
+        self.voidgrubs = (self.voidgrub_waves < 2).then_some((t + 240.0).max(600.0));
+    }
+
+    /// the map changes after the second dragon
+    fn terrain(&self) -> MapTerrain {
+        match self.rift_type {
+            Some(DragonType::Infernal) => MapTerrain::Infernal,
+            Some(DragonType::Ocean) => MapTerrain::Ocean,
+            Some(DragonType::Mountain) => MapTerrain::Mountain,
+            Some(DragonType::Cloud) => MapTerrain::Cloud,
+            Some(DragonType::Hextech) => MapTerrain::Hextech,
+            Some(DragonType::Chemtech) => MapTerrain::Chemtech,
+            _ => MapTerrain::Default,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ArenaState {
+    round: u32,
+    /// health of every subteam, a subteam is eliminated at 0
+    health: [i32; 8],
+    duels: Vec<(usize, usize)>,
+    fighting: bool,
+}
+
+impl ArenaState {
+    fn new() -> Self {
+        Self {
+            round: 1,
+            health: [100; 8],
+            duels: Vec::new(),
+            fighting: false,
+        }
+    }
+
+    /// the game time the combat of the current round starts, after 30s of shopping
+    fn round_start(&self) -> Time {
+        60.0 * self.round as f64
+    }
+
+    fn alive_subteams(&self) -> Vec<usize> {
+        (0..self.health.len())
+            .filter(|&subteam| self.health[subteam] > 0)
+            .collect()
+    }
+}
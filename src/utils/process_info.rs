@@ -36,8 +36,10 @@ pub(crate) fn get_auth_info() -> Result<(String, String), ProcessInfoError> {
         })
         .ok_or(ProcessInfoError::AuthTokenNotFound)?;
 
-    Ok((
-        general_purpose::STANDARD.encode(format!("riot:{auth_token}")),
-        port,
-    ))
+    Ok((encode_auth_token(&auth_token), port))
+}
+
+/// The LCU uses basic auth with the username `riot` and the remoting auth token as password
+pub(crate) fn encode_auth_token(auth_token: &str) -> String {
+    general_purpose::STANDARD.encode(format!("riot:{auth_token}"))
 }
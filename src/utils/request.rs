@@ -3,6 +3,21 @@ use std::time::Duration;
 use reqwest::{header, Certificate};
 
 pub(crate) fn build_reqwest_client(auth_token: Option<String>) -> reqwest::Client {
+    client_builder(auth_token).build().unwrap()
+}
+
+/// Same as [build_reqwest_client] but additionally trusts `root_certificate` \
+/// Used to connect to servers other than the League client, e.g. mock servers
+pub(crate) fn build_reqwest_client_with_root(
+    auth_token: Option<String>,
+    root_certificate: &[u8],
+) -> Result<reqwest::Client, reqwest::Error> {
+    client_builder(auth_token)
+        .add_root_certificate(Certificate::from_pem(root_certificate)?)
+        .build()
+}
+
+fn client_builder(auth_token: Option<String>) -> reqwest::ClientBuilder {
     let cert = Certificate::from_pem(include_bytes!("../riotgames.pem")).unwrap();
     let mut headers = header::HeaderMap::new();
 
@@ -16,6 +31,4 @@ pub(crate) fn build_reqwest_client(auth_token: Option<String>) -> reqwest::Clien
         .add_root_certificate(cert)
         .default_headers(headers)
         .timeout(Duration::from_millis(200))
-        .build()
-        .unwrap()
 }
@@ -11,8 +11,9 @@ use tokio_tungstenite::{
 };
 
 use crate::{
-    error::LcuWebsocketError,
+    error::{LcuError, LcuWebsocketError},
     model::ws::{LcuEvent, LcuSubscriptionType},
+    rest::RESTClient,
     utils::process_info,
 };
 
@@ -26,12 +27,39 @@ impl LcuWebsocketClient {
         let (auth_token, port) = process_info::get_auth_info()
             .map_err(|e| LcuWebsocketError::LcuNotAvailable(e.to_string()))?;
 
+        Self::connect_to(port, auth_token, None).await
+    }
+
+    /// Tries to establish a connection to an LCU Websocket API that is served on another port, e.g. by a mock server \
+    /// `auth_token` is the remoting auth token and `root_certificate` the PEM encoded certificate
+    /// the server gets verified with, in addition to the Riot Games certificate
+    pub async fn connect_with_auth(
+        port: u16,
+        auth_token: &str,
+        root_certificate: &[u8],
+    ) -> Result<Self, LcuWebsocketError> {
+        let root_certificate = native_tls::Certificate::from_pem(root_certificate)
+            .map_err(|e| LcuWebsocketError::LcuNotAvailable(e.to_string()))?;
+        Self::connect_to(
+            port.to_string(),
+            process_info::encode_auth_token(auth_token),
+            Some(root_certificate),
+        )
+        .await
+    }
+
+    async fn connect_to(
+        port: String,
+        auth_token: String,
+        root_certificate: Option<native_tls::Certificate>,
+    ) -> Result<Self, LcuWebsocketError> {
         let cert = native_tls::Certificate::from_pem(include_bytes!("./riotgames.pem")).unwrap();
-        let tls = native_tls::TlsConnector::builder()
-            .add_root_certificate(cert)
-            .build()
-            .unwrap();
-        let connector = Connector::NativeTls(tls);
+        let mut tls = native_tls::TlsConnector::builder();
+        tls.add_root_certificate(cert);
+        if let Some(root_certificate) = root_certificate {
+            tls.add_root_certificate(root_certificate);
+        }
+        let connector = Connector::NativeTls(tls.build().unwrap());
 
         let mut url = format!("wss://127.0.0.1:{port}")
             .into_client_request()
@@ -108,3 +136,49 @@ impl Stream for LcuWebsocketClient {
         }
     }
 }
+
+/// An [LcuWebsocketClient] subscribed to the [LcuSubscriptionType::JsonApiEvent] of one endpoint, \
+/// yielding only its events even if the websocket is subscribed to others as well
+pub(crate) struct JsonApiEvents {
+    ws_client: LcuWebsocketClient,
+    endpoint: String,
+}
+
+impl JsonApiEvents {
+    pub(crate) async fn subscribe(
+        mut ws_client: LcuWebsocketClient,
+        endpoint: &str,
+    ) -> Result<Self, LcuWebsocketError> {
+        ws_client
+            .subscribe(LcuSubscriptionType::JsonApiEvent(endpoint.to_string()))
+            .await?;
+        Ok(Self {
+            ws_client,
+            endpoint: endpoint.to_string(),
+        })
+    }
+}
+
+impl Stream for JsonApiEvents {
+    type Item = LcuEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            return match self.ws_client.poll_next_unpin(cx) {
+                Poll::Ready(Some(event))
+                    if !event.subscription_type.is_json_api_event(&self.endpoint) =>
+                {
+                    continue
+                }
+                poll => poll,
+            };
+        }
+    }
+}
+
+/// Connect to the REST and Websocket API of the running League client
+pub(crate) async fn connect_to_client() -> Result<(RESTClient, LcuWebsocketClient), LcuError> {
+    let rest_client = RESTClient::new().map_err(|e| LcuError::Request(e.to_string()))?;
+    let ws_client = LcuWebsocketClient::connect().await?;
+    Ok((rest_client, ws_client))
+}
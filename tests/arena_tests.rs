@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use shaco::{
+    analysis::arena::ArenaView,
+    model::ingame::{AllGameData, GameEvent, GameResult},
+    testing::{GameSimulator, SimulatedMap},
+};
+
+fn arena_view(json: &str) -> ArenaView {
+    let data: AllGameData = serde_json::from_str(json).unwrap();
+    ArenaView::new(&data).unwrap()
+}
+
+fn riot_ids(view: &ArenaView, id: usize) -> Vec<&str> {
+    view.subteams[id - 1]
+        .members
+        .iter()
+        .map(|m| m.riot_id.as_str())
+        .collect()
+}
+
+#[test]
+fn arena_view_of_early_game() {
+    let view = arena_view(include_str!("GetLiveclientdataAllgamedata_Arena2.json"));
+
+    assert_eq!(view.round, 2);
+    assert!(!view.finished);
+    assert_eq!(view.subteams.len(), 8);
+    assert!(view.subteams.iter().all(|s| s.members.len() == 2));
+    assert!(view
+        .subteams
+        .iter()
+        .all(|s| s.elimination.is_none() && s.placement.is_none()));
+    assert_eq!(riot_ids(&view, 1), ["hacka#EUW", "BERTRAND NDONGO#8343"]);
+    assert_eq!(
+        view.subteam("PJSàlt#EUW").unwrap().members[0].riot_id,
+        "DeFlooo#NAB"
+    );
+    // the subteams still playing are ordered by their kills
+    assert_eq!(view.standings()[0].id, 3);
+    assert!(view.rounds.iter().all(|r| r.alive.len() == 8));
+}
+
+#[test]
+fn arena_view_of_finished_game() {
+    let view = arena_view(include_str!("GetLiveclientdataAllgamedata_Arena5.json"));
+
+    assert_eq!(view.round, 13);
+    assert!(view.finished);
+    let standings = view.standings();
+    let placements = standings
+        .iter()
+        .map(|s| s.placement.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(placements, (1..=8).collect::<Vec<_>>());
+    assert_eq!(
+        riot_ids(&view, standings[0].id),
+        ["DDDDDDDDDDDDDD#EUW", "Caashual#EUW"]
+    );
+    assert!(standings[0].elimination.is_none());
+
+    // the only Ace of the game eliminated the last subteam
+    let last = standings[7].elimination.as_ref().unwrap();
+    assert!(last.ace);
+    assert_eq!(last.round, 7);
+    assert_eq!(
+        riot_ids(&view, standings[7].id),
+        ["AncientBoso#EUW", "Reicken#EUW"]
+    );
+    assert_eq!(view.rounds[6].eliminated, [standings[7].id]);
+    assert_eq!(view.rounds[7].alive.len(), 7);
+    assert_eq!(view.rounds[12].alive.len(), 2);
+
+    let kills = view.rounds.iter().map(|r| r.kills.len()).sum::<usize>();
+    let deaths = view.subteams.iter().map(|s| s.deaths).sum::<u32>();
+    assert_eq!(kills, deaths as usize);
+}
+
+#[test]
+fn arena_view_is_stable_while_the_game_goes_on() {
+    let ongoing = arena_view(include_str!("GetLiveclientdataAllgamedata_Arena4.json"));
+    let finished = arena_view(include_str!("GetLiveclientdataAllgamedata_Arena5.json"));
+
+    for (a, b) in ongoing.subteams.iter().zip(finished.subteams.iter()) {
+        assert_eq!(riot_ids(&ongoing, a.id), riot_ids(&finished, b.id));
+        if a.elimination.is_some() {
+            assert_eq!(a.placement, b.placement);
+        }
+    }
+    assert_eq!(
+        ongoing
+            .standings()
+            .iter()
+            .filter(|s| s.elimination.is_none())
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn arena_view_of_simulated_games() {
+    let mut long_duels = 0;
+    for seed in 0..50 {
+        let mut simulator = GameSimulator::new(SimulatedMap::Arena, seed);
+        simulator.run_to_end();
+        let data = simulator.snapshot();
+        let view = ArenaView::new(&data).unwrap();
+
+        // the simulator puts neighbouring players into a subteam
+        for (index, player) in data.all_players.iter().enumerate() {
+            let subteam = view.subteam(&player.riot_id.riot_id).unwrap();
+            assert_eq!(subteam.id, index / 2 + 1);
+        }
+        assert!(view.finished);
+        assert_eq!(
+            view.subteams
+                .iter()
+                .filter(|s| s.elimination.as_ref().is_some_and(|e| e.ace))
+                .count(),
+            7
+        );
+        let Some(GameEvent::GameEnd(end)) = data.events.last() else {
+            panic!("the game has ended");
+        };
+        let won = view.subteams[0].placement == Some(1);
+        assert_eq!(won, matches!(end.result, GameResult::Win));
+        let eliminated = view
+            .rounds
+            .iter()
+            .map(|r| r.eliminated.len())
+            .sum::<usize>();
+        assert_eq!(eliminated, 7);
+
+        // every subteam alive fights in every round, also when a duel takes longer than the pause between kills
+        for round in view.rounds.iter() {
+            let fought = round
+                .kills
+                .iter()
+                .flat_map(|k| k.killer_subteam.into_iter().chain([k.victim_subteam]))
+                .collect::<HashSet<_>>();
+            assert_eq!(round.alive.len() / 2 * 2, fought.len());
+            long_duels += round
+                .kills
+                .windows(2)
+                .filter(|k| k[1].time - k[0].time > 25.0)
+                .count();
+        }
+    }
+    assert!(long_duels > 0);
+    let data = GameSimulator::new(SimulatedMap::SummonersRift, 0).snapshot();
+    assert!(ArenaView::new(&data).is_none());
+}
@@ -0,0 +1,387 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::{json, Value};
+use shaco::{
+    automation::{
+        AutomationAction, AutomationConfig, AutomationRule, ChampSelectAutomation, DecisionOutcome,
+    },
+    model::lcu::champ_select::ChampSelectSession,
+    testing::{MockLcuServer, MockResponse},
+};
+
+fn config() -> AutomationConfig {
+    serde_json::from_value(json!({
+        "acceptReadyCheck": true,
+        "lockMarginMs": 5000,
+        "rules": [
+            {
+                "queues": [450],
+                "picks": [1]
+            },
+            {
+                "positions": ["middle"],
+                "picks": [266, 103, 7],
+                "bans": [103, 238],
+                "summonerSpells": [4, 14],
+                "runePage": 55,
+                "skins": { "7": 7001 }
+            }
+        ]
+    }))
+    .unwrap()
+}
+
+/// A session of the local player in cell 1 in the middle, the teammate in cell 0 hovers 103 and 266 is banned
+fn session(time_left_ms: i64, actions: Value) -> ChampSelectSession {
+    serde_json::from_value(json!({
+        "localPlayerCellId": 1,
+        "timer": {
+            "adjustedTimeLeftInPhase": time_left_ms,
+            "internalNowInEpochMs": 1_700_000_000_000u64,
+            "phase": "BAN_PICK"
+        },
+        "myTeam": [
+            { "cellId": 0, "championPickIntent": 103, "assignedPosition": "top", "spell1Id": 4, "spell2Id": 12 },
+            { "cellId": 1, "assignedPosition": "middle", "spell1Id": 4, "spell2Id": 12 }
+        ],
+        "theirTeam": [{ "cellId": 5 }],
+        "actions": actions,
+        "bans": { "myTeamBans": [], "theirTeamBans": [266] }
+    }))
+    .unwrap()
+}
+
+fn action(id: i64, cell_id: i64, action_type: &str, champion_id: i32, state: &str) -> Value {
+    json!({
+        "id": id,
+        "actorCellId": cell_id,
+        "championId": champion_id,
+        "completed": state == "completed",
+        "isInProgress": state == "in progress",
+        "type": action_type
+    })
+}
+
+#[test]
+fn replay_recorded_sessions() {
+    let ban = |champion, state| action(1, 1, "ban", champion, state);
+    let pick = |champion, state| action(2, 1, "pick", champion, state);
+    let sessions = [
+        session(30000, json!([[ban(0, "in progress")], [pick(0, "")]])),
+        // still hovered, nothing to do until the lock margin
+        session(20000, json!([[ban(238, "in progress")], [pick(0, "")]])),
+        session(4000, json!([[ban(238, "in progress")], [pick(0, "")]])),
+        session(
+            30000,
+            json!([[ban(238, "completed")], [pick(0, "in progress")]]),
+        ),
+        session(
+            3000,
+            json!([[ban(238, "completed")], [pick(7, "in progress")]]),
+        ),
+        session(
+            3000,
+            json!([[ban(238, "completed")], [pick(7, "completed")]]),
+        ),
+    ];
+    let mut sessions = sessions.to_vec();
+    sessions[5].my_team[1].champion_id = 7;
+
+    let logged = Arc::new(Mutex::new(0));
+    let counter = logged.clone();
+    let mut automation =
+        ChampSelectAutomation::new(config()).on_decision(move |_| *counter.lock().unwrap() += 1);
+    let decisions = automation.replay(Some(420), &sessions);
+    let actions = decisions
+        .iter()
+        .map(|d| d.action.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actions,
+        [
+            AutomationAction::SetSummonerSpells {
+                spell1_id: 4,
+                spell2_id: 14
+            },
+            // 103 is hovered by the teammate
+            AutomationAction::Hover {
+                action_id: 1,
+                champion_id: 238
+            },
+            AutomationAction::Ban {
+                action_id: 1,
+                champion_id: 238
+            },
+            // 266 is banned, 103 hovered by the teammate and 238 banned now
+            AutomationAction::Hover {
+                action_id: 2,
+                champion_id: 7
+            },
+            AutomationAction::Lock {
+                action_id: 2,
+                champion_id: 7
+            },
+            AutomationAction::SetRunePage { page_id: 55 },
+            AutomationAction::SetSkin { skin_id: 7001 },
+        ]
+    );
+    assert!(decisions[3].reason.contains("[266, 103]"));
+    assert!(decisions
+        .iter()
+        .all(|d| d.outcome == DecisionOutcome::DryRun));
+    assert_eq!(*logged.lock().unwrap(), decisions.len());
+    assert_eq!(automation.decisions().len(), decisions.len());
+    assert_eq!(
+        decisions[0].time,
+        UNIX_EPOCH + Duration::from_millis(1_700_000_000_000)
+    );
+
+    // the first rule applies to ARAM, where the only pick is taken
+    automation.reset();
+    let mut aram = session(30000, json!([[action(2, 1, "pick", 0, "in progress")]]));
+    aram.their_team[0].champion_id = 1;
+    let decisions = automation.decide(&aram, Some(450), SystemTime::now());
+    assert_eq!(
+        decisions
+            .iter()
+            .map(|d| d.action.clone())
+            .collect::<Vec<_>>(),
+        [AutomationAction::NoChampionAvailable { action_id: 2 }]
+    );
+    // no rule for the top lane
+    let mut top = session(30000, json!([[action(2, 1, "pick", 0, "in progress")]]));
+    top.my_team[1].assigned_position = "top".to_string();
+    assert!(automation
+        .decide(&top, Some(420), SystemTime::now())
+        .is_empty());
+}
+
+#[test]
+fn lock_deadlines() {
+    let mut config = config();
+    config.rules[1].bans.clear();
+    let mut automation = ChampSelectAutomation::new(config);
+    let ban = session(
+        30000,
+        json!([
+            [action(1, 1, "ban", 0, "in progress")],
+            [action(2, 1, "pick", 0, "")]
+        ]),
+    );
+    let now = ban.timer.sent_at();
+    automation.decide(&ban, Some(420), now);
+    // nothing to ban, so nothing to wake up for
+    assert_eq!(automation.lock_deadline(&ban, now), None);
+    assert_eq!(
+        automation.lock_deadline(&ban, now + Duration::from_secs(30)),
+        None
+    );
+
+    let pick = session(
+        30000,
+        json!([
+            [action(1, 1, "ban", 0, "completed")],
+            [action(2, 1, "pick", 0, "in progress")]
+        ]),
+    );
+    automation.decide(&pick, Some(420), now);
+    assert_eq!(
+        automation.lock_deadline(&pick, now),
+        Some(Duration::from_secs(25))
+    );
+    // a passed deadline wakes up only once
+    let late = now + Duration::from_secs(26);
+    assert_eq!(automation.lock_deadline(&pick, late), Some(Duration::ZERO));
+    assert_eq!(automation.lock_deadline(&pick, late), None);
+    // locked in, nothing to wait for
+    automation.decide(&pick, Some(420), late);
+    assert_eq!(automation.lock_deadline(&pick, now), None);
+}
+
+#[tokio::test]
+async fn run_against_client() {
+    let server = MockLcuServer::start().await.unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    for (method, path) in [
+        ("PATCH", "/lol-champ-select/v1/session/actions/2"),
+        ("POST", "/lol-champ-select/v1/session/actions/2/complete"),
+        ("PATCH", "/lol-champ-select/v1/session/my-selection"),
+        ("POST", "/lol-matchmaking/v1/ready-check/accept"),
+    ] {
+        let requests = requests.clone();
+        server.route(method, path, move |request| {
+            requests
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", request.method, request.path));
+            MockResponse::status(204)
+        });
+    }
+    server.route("GET", "/lol-gameflow/v1/session", |_| {
+        MockResponse::json(&json!({ "gameData": { "queue": { "id": 420 } } }))
+    });
+    let in_progress = json!({ "state": "InProgress", "playerResponse": "None", "timer": 1.0 });
+    let ready_check = in_progress.clone();
+    server.route("GET", "/lol-matchmaking/v1/ready-check", move |_| {
+        MockResponse::json(&ready_check)
+    });
+
+    let mut config = config();
+    config.lock_margin_ms = None;
+    config.rules[1].rune_page = None;
+    config.rules[1].skins = HashMap::new();
+    let rest_client = server.rest_client();
+    let ws_client = server.websocket_client().await.unwrap();
+    let task = tokio::spawn(async move {
+        let mut automation = ChampSelectAutomation::new(config);
+        automation.run(&rest_client, ws_client).await.unwrap();
+        automation
+    });
+    server
+        .wait_for_subscription("/lol-matchmaking/v1/ready-check")
+        .await;
+
+    server.publish("/lol-matchmaking/v1/ready-check", "Update", in_progress);
+    // the ready check is answered in the background
+    for _ in 0..100 {
+        if !requests.lock().unwrap().is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let mut session = session(30000, json!([[action(2, 1, "pick", 0, "in progress")]]));
+    session.timer.internal_now_in_epoch_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64;
+    server.publish(
+        "/lol-champ-select/v1/session",
+        "Create",
+        serde_json::to_value(&session).unwrap(),
+    );
+    // the same session again must not repeat the actions
+    server.publish(
+        "/lol-champ-select/v1/session",
+        "Update",
+        serde_json::to_value(&session).unwrap(),
+    );
+
+    for _ in 0..100 {
+        if requests.lock().unwrap().len() >= 4 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    server.close_websockets();
+    let automation = task.await.unwrap();
+
+    assert_eq!(
+        *requests.lock().unwrap(),
+        [
+            "POST /lol-matchmaking/v1/ready-check/accept",
+            "PATCH /lol-champ-select/v1/session/my-selection",
+            "PATCH /lol-champ-select/v1/session/actions/2",
+            "POST /lol-champ-select/v1/session/actions/2/complete",
+        ]
+    );
+    assert!(automation
+        .decisions()
+        .iter()
+        .all(|d| d.outcome == DecisionOutcome::Applied));
+    assert_eq!(automation.decisions().len(), 3);
+}
+
+#[tokio::test]
+async fn rejected_lock() {
+    let server = MockLcuServer::start().await.unwrap();
+    let locks = Arc::new(Mutex::new(Vec::new()));
+    let requests = locks.clone();
+    server.route(
+        "PATCH",
+        "/lol-champ-select/v1/session/actions/2",
+        move |request| {
+            let champion_id = request.json::<Value>().unwrap()["championId"].clone();
+            requests.lock().unwrap().push(champion_id.clone());
+            // the client refuses to lock in 7, e.g. because it isn't owned
+            match champion_id.as_i64() {
+                Some(7) => MockResponse::status(500),
+                _ => MockResponse::status(204),
+            }
+        },
+    );
+    server.route(
+        "POST",
+        "/lol-champ-select/v1/session/actions/2/complete",
+        |_| MockResponse::status(204),
+    );
+
+    let mut config = config();
+    config.lock_margin_ms = None;
+    config.rules[1].picks = vec![7, 1];
+    config.rules[1].summoner_spells = None;
+    config.rules[1].rune_page = None;
+    let rest_client = server.rest_client();
+    let ws_client = server.websocket_client().await.unwrap();
+    let task = tokio::spawn(async move {
+        let mut automation = ChampSelectAutomation::new(config);
+        automation.run(&rest_client, ws_client).await.unwrap();
+        automation
+    });
+    server
+        .wait_for_subscription("/lol-champ-select/v1/session")
+        .await;
+
+    let session = session(30000, json!([[action(2, 1, "pick", 0, "in progress")]]));
+    server.publish(
+        "/lol-champ-select/v1/session",
+        "Create",
+        serde_json::to_value(&session).unwrap(),
+    );
+
+    for _ in 0..200 {
+        if locks.lock().unwrap().len() >= 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    server.close_websockets();
+    let automation = task.await.unwrap();
+
+    // 7 is tried once, then the next priority is locked in
+    assert_eq!(*locks.lock().unwrap(), [json!(7), json!(1)]);
+    let outcomes = automation
+        .decisions()
+        .iter()
+        .map(|d| (d.action.clone(), d.outcome.clone()))
+        .collect::<Vec<_>>();
+    assert!(matches!(outcomes[0].1, DecisionOutcome::Failed(_)));
+    assert_eq!(
+        outcomes[1],
+        (
+            AutomationAction::Lock {
+                action_id: 2,
+                champion_id: 1
+            },
+            DecisionOutcome::Applied
+        )
+    );
+    assert_eq!(outcomes.len(), 2);
+}
+
+#[test]
+fn rule_matching() {
+    let rule = AutomationRule {
+        queues: vec![420, 440],
+        ..Default::default()
+    };
+    assert!(rule.applies_to(Some(420), "jungle"));
+    assert!(!rule.applies_to(Some(450), "jungle"));
+    assert!(!rule.applies_to(None, "jungle"));
+    assert!(AutomationRule::default().applies_to(None, ""));
+}
@@ -0,0 +1,193 @@
+use std::sync::{Arc, Mutex};
+
+use futures_util::StreamExt;
+use serde_json::{json, Value};
+use shaco::{
+    champ_select::ChampSelectStream,
+    model::lcu::champ_select::{
+        ChampSelectActionType, ChampSelectPhase, ChampSelectSession, ChampSelectUpdate, SwapKind,
+        SwapState,
+    },
+    testing::{MockLcuServer, MockResponse},
+};
+
+fn session_json() -> Value {
+    json!({
+        "gameId": 0,
+        "localPlayerCellId": 1,
+        "timer": {
+            "adjustedTimeLeftInPhase": 27000,
+            "internalNowInEpochMs": 1700000000000u64,
+            "isInfinite": false,
+            "phase": "BAN_PICK",
+            "totalTimeInPhase": 30000
+        },
+        "myTeam": [
+            {
+                "cellId": 0, "championId": 0, "championPickIntent": 103,
+                "assignedPosition": "top", "gameName": "Ally", "tagLine": "EUW",
+                "spell1Id": 4, "spell2Id": 12, "team": 1
+            },
+            {
+                "cellId": 1, "championId": 0, "championPickIntent": 0,
+                "assignedPosition": "middle", "gameName": "Me", "tagLine": "EUW",
+                "spell1Id": 4, "spell2Id": 14, "team": 1
+            }
+        ],
+        "theirTeam": [{ "cellId": 5, "championId": 0, "team": 2 }],
+        "actions": [
+            [
+                { "id": 1, "actorCellId": 0, "championId": 266, "completed": true, "isAllyAction": true, "isInProgress": false, "pickTurn": 1, "type": "ban" },
+                { "id": 2, "actorCellId": 1, "championId": 0, "completed": true, "isAllyAction": true, "isInProgress": false, "pickTurn": 1, "type": "ban" }
+            ],
+            [
+                { "id": 11, "actorCellId": 5, "championId": 0, "completed": true, "isAllyAction": false, "isInProgress": false, "pickTurn": 2, "type": "ten_bans_reveal" }
+            ],
+            [
+                { "id": 21, "actorCellId": 1, "championId": 0, "completed": false, "isAllyAction": true, "isInProgress": true, "pickTurn": 3, "type": "pick" }
+            ]
+        ],
+        "bans": { "myTeamBans": [266], "theirTeamBans": [], "numBans": 10 },
+        "benchEnabled": false,
+        "benchChampions": [],
+        "trades": [{ "id": 3, "cellId": 0, "state": "INVALID" }],
+        "pickOrderSwaps": [{ "id": 4, "cellId": 0, "state": "RECEIVED" }],
+        "positionSwaps": [{ "id": 5, "cellId": 0, "state": "AVAILABLE" }],
+        "hasSimultaneousBans": true,
+        "hasSimultaneousPicks": false,
+        "isSpectating": false,
+        "chatDetails": { "multiUserChatId": "champ-select" },
+        "counter": 7
+    })
+}
+
+#[test]
+fn champ_select_session() {
+    let session: ChampSelectSession = serde_json::from_value(session_json()).unwrap();
+    assert_eq!(session.timer.phase, ChampSelectPhase::BanPick);
+    assert_eq!(session.local_player().unwrap().assigned_position, "middle");
+    assert_eq!(session.their_team.len(), 1);
+    assert_eq!(session.all_actions().count(), 4);
+    assert_eq!(
+        session.actions[1][0].action_type,
+        ChampSelectActionType::TenBansReveal
+    );
+
+    let current = session.current_action().unwrap();
+    assert_eq!(current.id, 21);
+    assert_eq!(current.action_type, ChampSelectActionType::Pick);
+    // a ban without a champion isn't a ban
+    assert_eq!(session.banned_champions().collect::<Vec<_>>(), [266]);
+
+    let received = session.received_swaps().collect::<Vec<_>>();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].0, SwapKind::PickOrder);
+    assert_eq!(session.position_swaps[0].state, SwapState::Available);
+}
+
+#[tokio::test]
+async fn champ_select_actions() {
+    let server = MockLcuServer::start().await.unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    for (method, path) in [
+        ("PATCH", "/lol-champ-select/v1/session/actions/21"),
+        ("POST", "/lol-champ-select/v1/session/actions/21/complete"),
+        (
+            "POST",
+            "/lol-champ-select/v1/session/pick-order-swaps/4/accept",
+        ),
+        ("POST", "/lol-champ-select/v1/session/trades/3/request"),
+        ("POST", "/lol-champ-select/v1/session/bench/swap/22"),
+        ("PATCH", "/lol-champ-select/v1/session/my-selection"),
+    ] {
+        let requests = requests.clone();
+        server.route(method, path, move |request| {
+            let body = request.json::<Value>().unwrap_or(Value::Null);
+            requests
+                .lock()
+                .unwrap()
+                .push((request.method.clone(), request.path.clone(), body));
+            MockResponse::status(204)
+        });
+    }
+
+    let client = server.rest_client();
+    client.hover_champion(21, 103).await.unwrap();
+    client.lock_champion(21, 103).await.unwrap();
+    client.accept_swap(SwapKind::PickOrder, 4).await.unwrap();
+    client.request_swap(SwapKind::Trade, 3).await.unwrap();
+    client.swap_with_bench(22).await.unwrap();
+    client.set_summoner_spells(4, 14).await.unwrap();
+    client.set_skin(103001).await.unwrap();
+    // not routed
+    assert!(client.decline_swap(SwapKind::Position, 5).await.is_err());
+
+    let requests = requests.lock().unwrap();
+    let paths = requests
+        .iter()
+        .map(|(method, path, _)| format!("{method} {path}"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        [
+            "PATCH /lol-champ-select/v1/session/actions/21",
+            "PATCH /lol-champ-select/v1/session/actions/21",
+            "POST /lol-champ-select/v1/session/actions/21/complete",
+            "POST /lol-champ-select/v1/session/pick-order-swaps/4/accept",
+            "POST /lol-champ-select/v1/session/trades/3/request",
+            "POST /lol-champ-select/v1/session/bench/swap/22",
+            "PATCH /lol-champ-select/v1/session/my-selection",
+            "PATCH /lol-champ-select/v1/session/my-selection",
+        ]
+    );
+    assert_eq!(requests[0].2, json!({ "championId": 103 }));
+    assert_eq!(requests[6].2, json!({ "spell1Id": 4, "spell2Id": 14 }));
+    assert_eq!(requests[7].2, json!({ "selectedSkinId": 103001 }));
+}
+
+#[tokio::test]
+async fn champ_select_stream() {
+    let server = MockLcuServer::start().await.unwrap();
+    let rest_client = server.rest_client();
+
+    // outside of champ select the session is not found
+    let ws_client = server.websocket_client().await.unwrap();
+    let mut stream = ChampSelectStream::new(&rest_client, ws_client)
+        .await
+        .unwrap();
+    server
+        .wait_for_subscription("/lol-champ-select/v1/session")
+        .await;
+
+    server.publish("/lol-champ-select/v1/session", "Create", session_json());
+    let mut update = session_json();
+    update["timer"]["phase"] = json!("FINALIZATION");
+    server.publish("/lol-champ-select/v1/session", "Update", update);
+    server.publish("/lol-champ-select/v1/session", "Delete", Value::Null);
+
+    let Some(ChampSelectUpdate::Session(session)) = stream.next().await else {
+        panic!("expected the created session");
+    };
+    assert_eq!(session.timer.phase, ChampSelectPhase::BanPick);
+    let Some(ChampSelectUpdate::Session(session)) = stream.next().await else {
+        panic!("expected the updated session");
+    };
+    assert_eq!(session.timer.phase, ChampSelectPhase::Finalization);
+    assert!(matches!(
+        stream.next().await,
+        Some(ChampSelectUpdate::Ended)
+    ));
+
+    // already in champ select
+    server.route("GET", "/lol-champ-select/v1/session", |_| {
+        MockResponse::json(&session_json())
+    });
+    let ws_client = server.websocket_client().await.unwrap();
+    let mut stream = ChampSelectStream::new(&rest_client, ws_client)
+        .await
+        .unwrap();
+    let Some(ChampSelectUpdate::Session(session)) = stream.next().await else {
+        panic!("expected the current session");
+    };
+    assert_eq!(session.counter, 7);
+}
@@ -0,0 +1,171 @@
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use shaco::{
+    analysis::clock::{ClockChange, GameClock},
+    testing::{MockGame, MockIngameServer},
+};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-6
+}
+
+fn seconds(from: Instant, to: Instant) -> f64 {
+    to.duration_since(from).as_secs_f64()
+}
+
+#[test]
+fn pause_and_resume() {
+    let start = Instant::now();
+    let at = |s: f64| start + Duration::from_secs_f64(s);
+    let mut clock = GameClock::new();
+
+    // 100s into the game, sampled every half second
+    for i in 0..10 {
+        let t = i as f64 * 0.5;
+        assert_eq!(clock.push(100.0 + t, at(t)), None);
+    }
+    assert!(close(clock.rate().unwrap(), 1.0));
+    assert!(close(clock.game_time_at(at(2.25)).unwrap(), 102.25));
+    assert!(close(seconds(start, clock.instant_of(103.0).unwrap()), 3.0));
+    // before the first sample
+    assert!(close(seconds(clock.instant_of(99.5).unwrap(), start), 0.5));
+
+    // the game stops at 105 and stands still for 10s
+    assert_eq!(clock.push(105.0, at(5.0)), None);
+    assert_eq!(
+        clock.push(105.0, at(5.5)),
+        Some(ClockChange::Paused { game_time: 105.0 })
+    );
+    assert!(clock.is_paused());
+    assert_eq!(clock.push(105.0, at(15.0)), None);
+    assert_eq!(
+        clock.push(105.5, at(15.5)),
+        Some(ClockChange::Resumed { game_time: 105.0 })
+    );
+    assert!(!clock.is_paused());
+    let pause = clock.pauses()[0];
+    assert!(close(seconds(start, pause.started), 5.0));
+    assert!(close(seconds(start, pause.ended.unwrap()), 15.0));
+    assert_eq!(clock.game_time_at(at(12.0)), Some(105.0));
+
+    clock.push(106.0, at(16.0));
+    assert!(close(clock.game_time_at(at(20.0)).unwrap(), 110.0));
+    assert!(close(
+        seconds(start, clock.instant_of(110.0).unwrap()),
+        20.0
+    ));
+    // events before the pause keep their instant
+    assert!(close(seconds(start, clock.instant_of(104.0).unwrap()), 4.0));
+
+    let system_time = clock.system_time_of(110.0).unwrap();
+    assert!(close(
+        clock.game_time_at_system_time(system_time).unwrap(),
+        110.0
+    ));
+}
+
+#[test]
+fn pause_sampled_at_high_frequency() {
+    let start = Instant::now();
+    let at = |s: f64| start + Duration::from_secs_f64(s);
+    let mut clock = GameClock::new();
+
+    // sampled every 100ms, closer together than a pause can be told from the jitter
+    for i in 0..=20 {
+        let t = i as f64 * 0.1;
+        assert_eq!(clock.push(100.0 + t, at(t)), None);
+    }
+    assert!(close(clock.rate().unwrap(), 1.0));
+
+    // the game stops at 102 and stands still for 3s
+    let changes = (1..=30)
+        .filter_map(|i| clock.push(102.0, at(2.0 + i as f64 * 0.1)))
+        .collect::<Vec<_>>();
+    assert_eq!(changes, [ClockChange::Paused { game_time: 102.0 }]);
+    assert!(clock.is_paused());
+    assert!(close(seconds(start, clock.pauses()[0].started), 2.0));
+    // the samples of the pause don't slow down the rate before it
+    assert!(close(clock.rate().unwrap(), 1.0));
+
+    assert_eq!(
+        clock.push(102.1, at(5.1)),
+        Some(ClockChange::Resumed { game_time: 102.0 })
+    );
+    let pause = clock.pauses()[0];
+    assert!(close(seconds(start, pause.ended.unwrap()), 5.0));
+}
+
+#[test]
+fn replay_seeks_and_speed() {
+    let start = Instant::now();
+    let at = |s: f64| start + Duration::from_secs_f64(s);
+    let mut clock = GameClock::new();
+
+    // a replay at double speed
+    for i in 0..5 {
+        let t = i as f64;
+        assert_eq!(clock.push(600.0 + 2.0 * t, at(t)), None);
+    }
+    assert!(close(clock.rate().unwrap(), 2.0));
+    // the clock was created right after the first instant
+    assert!((clock.offset().unwrap() - 600.0).abs() < 0.1);
+
+    // seeking back to 300
+    assert_eq!(
+        clock.push(300.0, at(5.0)),
+        Some(ClockChange::Jumped {
+            from: 608.0,
+            to: 300.0
+        })
+    );
+    assert_eq!(clock.rate(), None);
+    clock.push(301.0, at(5.5));
+    clock.push(302.0, at(6.0));
+    assert!(close(clock.rate().unwrap(), 2.0));
+    assert!(close(seconds(start, clock.instant_of(301.0).unwrap()), 5.5));
+    // ahead of the replay again
+    assert!(close(
+        seconds(start, clock.instant_of(450.0).unwrap()),
+        80.0
+    ));
+    // game times that were passed before the seek
+    assert!(close(seconds(start, clock.instant_of(604.0).unwrap()), 2.0));
+
+    // back to normal speed
+    let change = (1..=3)
+        .find_map(|i| clock.push(302.0 + i as f64, at(6.0 + i as f64)))
+        .unwrap();
+    assert!(matches!(change, ClockChange::Jumped { .. }));
+    clock.push(306.0, at(10.0));
+    assert!(close(clock.rate().unwrap(), 1.0));
+
+    // seeking forward skips game time
+    assert!(matches!(
+        clock.push(900.0, at(11.0)),
+        Some(ClockChange::Jumped { .. })
+    ));
+    clock.push(901.0, at(12.0));
+    assert_eq!(clock.instant_of(800.0), None);
+    assert!(close(
+        seconds(start, clock.instant_of(905.0).unwrap()),
+        16.0
+    ));
+}
+
+#[tokio::test]
+async fn sample_client() {
+    let fixture: Value =
+        serde_json::from_str(include_str!("GetLiveclientdataAllgamedata_Arena6.json")).unwrap();
+    let game_time = fixture["gameData"]["gameTime"].as_f64().unwrap();
+    let server = MockIngameServer::start(MockGame::Live(fixture))
+        .await
+        .unwrap();
+    let client = server.ingame_client();
+
+    let mut clock = GameClock::new();
+    assert_eq!(clock.sample(&client).await.unwrap(), None);
+    assert_eq!(clock.rate(), None);
+    let now = clock.game_time_at(Instant::now()).unwrap();
+    assert!(game_time <= now && now < game_time + 1.0);
+}
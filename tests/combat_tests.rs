@@ -0,0 +1,138 @@
+use shaco::{
+    analysis::combat::{damage_multiplier, BaseStats, CombatCalculator, Target},
+    model::ingame::{AllGameData, PlayerChampionStats},
+};
+
+fn champion_stats(json: &str) -> PlayerChampionStats {
+    let data: AllGameData = serde_json::from_str(json).unwrap();
+    data.active_player.unwrap().champion_stats
+}
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() <= expected.abs() * 1e-5,
+        "{actual} != {expected}"
+    );
+}
+
+fn target(armor: f32, magic_resist: f32) -> Target {
+    Target {
+        max_health: 2000.0,
+        current_health: 1000.0,
+        armor,
+        magic_resist,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn damage_multipliers() {
+    assert_eq!(damage_multiplier(0.0), 1.0);
+    assert_eq!(damage_multiplier(100.0), 0.5);
+    assert_eq!(damage_multiplier(-100.0), 1.5);
+    assert!(damage_multiplier(-10000.0) < 2.0);
+}
+
+#[test]
+fn effective_health_of_active_player() {
+    let stats = champion_stats(include_str!("GetLiveclientdataAllgamedata_Arena6.json"));
+    let calculator = CombatCalculator::new(&stats);
+
+    let effective = calculator.effective_health();
+    assert_close(
+        effective.physical,
+        stats.current_health * (1.0 + stats.armor / 100.0),
+    );
+    assert_close(
+        effective.magic,
+        stats.current_health * (1.0 + stats.magic_resist / 100.0),
+    );
+    assert!(calculator.max_effective_health().physical > effective.physical);
+}
+
+#[test]
+fn auto_attacks_with_crits() {
+    // 100% crit chance with 230% crit damage
+    let stats = champion_stats(include_str!("GetLiveclientdataAllgamedata_Arena3.json"));
+    let calculator = CombatCalculator::new(&stats);
+    assert_close(calculator.auto_attack_damage(), stats.attack_damage * 2.3);
+    assert_close(
+        calculator.auto_attack_dps(None),
+        stats.attack_damage * 2.3 * stats.attack_speed,
+    );
+
+    // 90% crit chance with 230% crit damage
+    let stats = champion_stats(include_str!("GetLiveclientdataAllgamedata_Arena6.json"));
+    let calculator = CombatCalculator::new(&stats);
+    assert_close(calculator.auto_attack_damage(), stats.attack_damage * 2.17);
+
+    let target = target(100.0, 0.0);
+    let dps = calculator.auto_attack_dps(Some(&target));
+    // 40% armor penetration leaves 60 armor
+    assert_close(dps, calculator.auto_attack_dps(None) * 100.0 / 160.0);
+    assert_close(calculator.time_to_kill(&target), 1000.0 / dps);
+}
+
+#[test]
+fn penetration() {
+    // 40% armor penetration
+    let mut stats = champion_stats(include_str!("GetLiveclientdataAllgamedata_Arena3.json"));
+    let calculator = CombatCalculator::new(&stats);
+    assert_close(calculator.effective_armor(&target(100.0, 50.0)), 60.0);
+    assert_close(
+        calculator.effective_magic_resist(&target(100.0, 50.0)),
+        50.0,
+    );
+    assert_close(
+        calculator.physical_damage(160.0, &target(100.0, 0.0)),
+        100.0,
+    );
+    // negative armor isn't reduced further
+    assert_eq!(calculator.effective_armor(&target(-20.0, 0.0)), -20.0);
+
+    // percent before flat, bonus penetration only on bonus resistances
+    stats.armor_penetration_percent = 1.0;
+    stats.bonus_armor_penetration_percent = 0.6;
+    stats.armor_penetration_flat = 5.0;
+    stats.physical_lethality = 5.0;
+    stats.magic_penetration_flat = 10.0;
+    stats.magic_penetration_percent = 0.5;
+    let calculator = CombatCalculator::new(&stats);
+    let target = target(50.0, 50.0).with_bonus(0.0, 50.0, 0.0);
+    assert_close(calculator.effective_armor(&target), 50.0 + 30.0 - 10.0);
+    assert_close(calculator.effective_magic_resist(&target), 15.0);
+    assert_close(calculator.magic_damage(115.0, &target), 100.0);
+    // flat penetration can't reduce below 0
+    stats.physical_lethality = 500.0;
+    assert_eq!(CombatCalculator::new(&stats).effective_armor(&target), 0.0);
+}
+
+#[test]
+fn enemies_from_base_stats() {
+    let base = BaseStats {
+        health: 600.0,
+        health_per_level: 100.0,
+        armor: 30.0,
+        armor_per_level: 4.0,
+        magic_resist: 32.0,
+        magic_resist_per_level: 2.0,
+    };
+    let level_1 = base.at_level(1);
+    assert_eq!(level_1.max_health, 600.0);
+    assert_eq!(level_1.armor, 30.0);
+
+    let level_18 = base.at_level(18);
+    assert_close(level_18.max_health, 600.0 + 17.0 * 100.0);
+    assert_close(level_18.armor, 30.0 + 17.0 * 4.0);
+    assert_close(level_18.magic_resist, 32.0 + 17.0 * 2.0);
+
+    let level_9 = base.at_level(9);
+    assert!(level_9.armor > 30.0 + 4.0 * 8.0 * 0.7025 && level_9.armor < 30.0 + 4.0 * 8.0);
+
+    let tank = level_18.with_bonus(1000.0, 100.0, 50.0);
+    assert_eq!(tank.current_health, tank.max_health);
+    assert_close(
+        tank.effective_health().physical,
+        tank.max_health * (1.0 + tank.armor / 100.0),
+    );
+}
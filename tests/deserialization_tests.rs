@@ -1,15 +1,28 @@
 use serde::{Deserialize, Serialize};
 
 use shaco::{
-    ingame::IngameClient,
+    error::IngameClientError,
     model::ingame::{AllGameData, GameEvent, GameMode},
+    testing::{MockGame, MockIngameServer, MockResponse},
 };
 
+fn fixture() -> serde_json::Value {
+    serde_json::from_str(include_str!("GetLiveclientdataAllgamedata_Arena5.json")).unwrap()
+}
+
+/// the ARAM fixtures were captured before Riot IDs were introduced, add them like the API does today
+fn aram_fixture() -> serde_json::Value {
+    serde_json::from_str(include_str!("aram_allgamedata1.json")).unwrap()
+}
+
 /// check if all api calls deserialize without errors \
 /// DOES NOT CHECK IF THE EVENTS GET DESERIALIZED CORRECTLY
 #[tokio::test]
 async fn ingame_livegame_api_deserialization() {
-    let client = IngameClient::new();
+    let server = MockIngameServer::start(MockGame::Live(aram_fixture()))
+        .await
+        .unwrap();
+    let client = server.ingame_client();
 
     assert!(client.active_game().await);
     assert!(client.active_game_loadingscreen().await);
@@ -36,7 +49,10 @@ async fn ingame_livegame_api_deserialization() {
 /// DOES NOT CHECK IF THE EVENTS GET DESERIALIZED CORRECTLY
 #[tokio::test]
 async fn ingame_spectate_api_deserialization() {
-    let client = IngameClient::new();
+    let server = MockIngameServer::start(MockGame::Spectator(aram_fixture()))
+        .await
+        .unwrap();
+    let client = server.ingame_client();
 
     assert!(client.active_game().await);
     assert!(client.active_game_loadingscreen().await);
@@ -52,6 +68,61 @@ async fn ingame_spectate_api_deserialization() {
     client.player_main_runes(riot_id).await.unwrap();
     client.player_scores(riot_id).await.unwrap();
     client.player_summoner_spells(riot_id).await.unwrap();
+
+    assert!(matches!(
+        client.active_player().await,
+        Err(IngameClientError::ApiNotAvailableInSpectatorMode)
+    ));
+}
+
+/// check that HTTP errors are mapped to the right [IngameClientError]
+#[tokio::test]
+async fn ingame_api_errors() {
+    let server = MockIngameServer::start(MockGame::LoadingScreen)
+        .await
+        .unwrap();
+    let client = server.ingame_client();
+
+    assert!(!client.active_game().await);
+    assert!(client.active_game_loadingscreen().await);
+    assert!(matches!(
+        client.game_stats().await,
+        Err(IngameClientError::ApiNotAvailableDuringLoadingScreen)
+    ));
+    assert!(matches!(
+        client.is_spectator_mode().await,
+        Err(IngameClientError::ApiNotAvailableDuringLoadingScreen)
+    ));
+
+    server.set_game(MockGame::Live(fixture()));
+    server.set_response("/GetLiveclientdataEventdata", MockResponse::status(500));
+    server.set_response("/GetLiveclientdataPlayerlist", MockResponse::status(403));
+    server.set_response(
+        "/GetLiveclientdataGamestats",
+        MockResponse::new(200, "{\"gameMode\": 42}"),
+    );
+    assert!(matches!(
+        client.event_data(None).await,
+        Err(IngameClientError::ServerError(_))
+    ));
+    assert!(matches!(
+        client.player_list(None).await,
+        Err(IngameClientError::ClientError(_))
+    ));
+    assert!(matches!(
+        client.game_stats().await,
+        Err(IngameClientError::DeserializationError(_))
+    ));
+
+    server.clear_responses();
+    assert_eq!(client.event_data(Some(100)).await.unwrap().len(), 29);
+
+    server.set_game(MockGame::NotRunning);
+    assert!(!client.active_game_loadingscreen().await);
+    assert!(matches!(
+        client.all_game_data(None).await,
+        Err(IngameClientError::ConnectionError(_))
+    ));
 }
 
 #[test]
@@ -0,0 +1,113 @@
+use futures_util::StreamExt;
+use serde_json::json;
+use shaco::{
+    gameflow::GameflowPhaseStream,
+    model::lcu::gameflow::{GameflowPhase, GameflowSession, GameflowTransition},
+    testing::{MockLcuServer, MockResponse},
+};
+
+#[tokio::test]
+async fn gameflow_phase_transitions() {
+    let server = MockLcuServer::start().await.unwrap();
+    server.route("GET", "/lol-gameflow/v1/gameflow-phase", |_| {
+        MockResponse::json(&"Lobby")
+    });
+
+    let rest_client = server.rest_client();
+    assert_eq!(
+        rest_client.gameflow_phase().await.unwrap(),
+        GameflowPhase::Lobby
+    );
+    let ws_client = server.websocket_client().await.unwrap();
+    let mut stream = GameflowPhaseStream::new(&rest_client, ws_client)
+        .await
+        .unwrap();
+    server
+        .wait_for_subscription("/lol-gameflow/v1/gameflow-phase")
+        .await;
+
+    let phase = "/lol-gameflow/v1/gameflow-phase";
+    server.publish(phase, "Update", json!("Lobby"));
+    server.publish(phase, "Update", json!("Matchmaking"));
+    server.publish(phase, "Update", json!("ReadyCheck"));
+    server.publish(phase, "Update", json!("SomeFuturePhase"));
+    server.publish(phase, "Delete", json!(null));
+
+    let transitions = [
+        (GameflowPhase::None, GameflowPhase::Lobby),
+        // the repeated lobby is skipped
+        (GameflowPhase::Lobby, GameflowPhase::Matchmaking),
+        (GameflowPhase::Matchmaking, GameflowPhase::ReadyCheck),
+        (GameflowPhase::ReadyCheck, GameflowPhase::Unknown),
+        (GameflowPhase::Unknown, GameflowPhase::None),
+    ];
+    for (previous, next) in transitions {
+        assert_eq!(
+            stream.next().await.unwrap(),
+            GameflowTransition { previous, next }
+        );
+    }
+    assert_eq!(stream.phase(), GameflowPhase::None);
+
+    server.close_websockets();
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn gameflow_session() {
+    let server = MockLcuServer::start().await.unwrap();
+    server.route("GET", "/lol-gameflow/v1/session", |_| {
+        MockResponse::json(&json!({
+            "phase": "ChampSelect",
+            "gameClient": {
+                "observerServerIp": "",
+                "observerServerPort": 0,
+                "running": false,
+                "serverIp": "",
+                "serverPort": 0,
+                "visible": false
+            },
+            "gameData": {
+                "gameId": 0,
+                "gameName": "",
+                "isCustomGame": false,
+                "password": "",
+                "playerChampionSelections": [],
+                "queue": {
+                    "id": 420,
+                    "name": "Ranked Solo/Duo",
+                    "description": "Ranked Solo/Duo",
+                    "gameMode": "CLASSIC",
+                    "mapId": 11,
+                    "isRanked": true,
+                    "type": "RANKED_SOLO_5x5",
+                    "category": "PvP"
+                },
+                "spectatorsAllowed": false,
+                "teamOne": [{ "summonerId": 1 }],
+                "teamTwo": []
+            },
+            "map": {
+                "id": 11,
+                "name": "Summoner's Rift",
+                "description": "",
+                "gameMode": "CLASSIC",
+                "isRGM": false
+            },
+            "gameDodge": { "state": "Invalid" }
+        }))
+    });
+
+    let session: GameflowSession = server.rest_client().gameflow_session().await.unwrap();
+    assert_eq!(session.phase, GameflowPhase::ChampSelect);
+    assert_eq!(session.game_data.queue.id, 420);
+    assert_eq!(session.game_data.queue.queue_type, "RANKED_SOLO_5x5");
+    assert!(session.game_data.queue.is_ranked);
+    assert_eq!(session.game_data.team_one.len(), 1);
+    assert_eq!(session.map.id, 11);
+
+    // outside of a lobby most fields are missing
+    let session: GameflowSession = serde_json::from_value(json!({ "phase": "None" })).unwrap();
+    assert_eq!(session.phase, GameflowPhase::None);
+    assert_eq!(session.game_data.queue.id, 0);
+}
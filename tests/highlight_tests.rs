@@ -0,0 +1,179 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+use shaco::{
+    analysis::highlights::{HighlightDetector, HighlightKind, Objective},
+    model::ingame::{GameEvent, TeamId},
+};
+
+fn fixture_events(json: &str) -> Vec<GameEvent> {
+    let events: Value = serde_json::from_str(json).unwrap();
+    events["Events"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|e| GameEvent::deserialize(e).ok())
+        .collect()
+}
+
+fn count(events: &[GameEvent], filter: fn(&GameEvent) -> bool) -> usize {
+    events.iter().filter(|e| filter(e)).count()
+}
+
+#[test]
+fn highlights_of_fixture() {
+    let events = fixture_events(include_str!("GetLiveclientdataEventdata2.json"));
+    let highlights = HighlightDetector::new().detect(&events);
+
+    let of_kind =
+        |filter: fn(&HighlightKind) -> bool| highlights.iter().filter(|h| filter(&h.kind)).count();
+    assert_eq!(
+        of_kind(|k| matches!(k, HighlightKind::Multikill { .. })),
+        count(&events, |e| matches!(e, GameEvent::Multikill(_)))
+    );
+    assert_eq!(
+        of_kind(|k| matches!(k, HighlightKind::Ace { .. })),
+        count(&events, |e| matches!(e, GameEvent::Ace(_)))
+    );
+    assert_eq!(of_kind(|k| matches!(k, HighlightKind::FirstBlood)), 1);
+    assert!(of_kind(|k| matches!(k, HighlightKind::Teamfight { .. })) > 0);
+
+    assert!(highlights
+        .windows(2)
+        .all(|w| w[0].start_time <= w[1].start_time));
+    for highlight in highlights.iter() {
+        assert!(highlight.start_time >= 0.0);
+        assert!(highlight.duration() >= 15.0);
+        assert!(!highlight.players.is_empty());
+    }
+
+    // multikills start with the first kill of the streak
+    let multikill = highlights
+        .iter()
+        .find(|h| matches!(h.kind, HighlightKind::Multikill { .. }))
+        .unwrap();
+    let first_kill = events
+        .iter()
+        .find_map(|e| match e {
+            GameEvent::ChampionKill(kill) if kill.event_time + 10.0 >= multikill.start_time => {
+                Some(kill.event_time)
+            }
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(multikill.start_time, first_kill - 10.0);
+}
+
+#[test]
+fn highlight_padding_and_seek() {
+    let events = fixture_events(include_str!("GetLiveclientdataEventdata1.json"));
+    let mut detector = HighlightDetector::new();
+    detector.set_padding(0.0, 0.0);
+    let highlights = detector.detect(&events);
+
+    let first_blood = highlights
+        .iter()
+        .find(|h| h.kind == HighlightKind::FirstBlood)
+        .unwrap();
+    let event_time = events
+        .iter()
+        .find_map(|e| match e {
+            GameEvent::FirstBlood(e) => Some(e.event_time),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(first_blood.start_time, event_time);
+    assert_eq!(first_blood.duration(), 0.0);
+
+    assert_eq!(
+        serde_json::to_value(first_blood.seek()).unwrap(),
+        json!({ "time": event_time, "paused": false })
+    );
+    assert_eq!(
+        serde_json::to_value(first_blood).unwrap()["kind"],
+        json!({ "type": "firstBlood" })
+    );
+
+    detector.set_teamfight(10.0, 100);
+    assert!(!detector
+        .detect(&events)
+        .iter()
+        .any(|h| matches!(h.kind, HighlightKind::Teamfight { .. })));
+}
+
+fn event(value: Value) -> GameEvent {
+    GameEvent::deserialize(&value).unwrap()
+}
+
+#[test]
+fn highlights_of_steals_teamfights_and_base_races() {
+    let kill = |id: u32, time: f64, killer: &str, victim: &str| {
+        event(json!({
+            "EventID": id, "EventName": "ChampionKill", "EventTime": time,
+            "KillerName": killer, "VictimName": victim, "Assisters": ["Support"]
+        }))
+    };
+    let events = vec![
+        event(json!({
+            "EventID": 0, "EventName": "BaronKill", "EventTime": 1200.0, "Stolen": "True",
+            "KillerName": "Jungler", "Assisters": []
+        })),
+        event(json!({
+            "EventID": 1, "EventName": "DragonKill", "EventTime": 1300.0, "Stolen": "False",
+            "KillerName": "Jungler", "Assisters": [], "DragonType": "Fire"
+        })),
+        kill(2, 1500.0, "Carry", "Enemy1"),
+        kill(3, 1504.0, "Carry", "Enemy2"),
+        kill(4, 1509.0, "Enemy3", "Carry"),
+        event(json!({
+            "EventID": 5, "EventName": "InhibKilled", "EventTime": 1800.0,
+            "KillerName": "Carry", "Assisters": [], "InhibKilled": "Barracks_T2_C1"
+        })),
+        event(json!({
+            "EventID": 6, "EventName": "TurretKilled", "EventTime": 1820.0,
+            "KillerName": "Enemy1", "Assisters": ["Enemy2"], "TurretKilled": "Turret_T1_C_01_A"
+        })),
+    ];
+    let highlights = HighlightDetector::new().detect(&events);
+    assert_eq!(highlights.len(), 3);
+
+    assert_eq!(
+        highlights[0].kind,
+        HighlightKind::StolenObjective {
+            objective: Objective::Baron
+        }
+    );
+    assert_eq!(highlights[0].players, ["Jungler"]);
+    assert_eq!(highlights[1].kind, HighlightKind::Teamfight { kills: 3 });
+    assert_eq!(
+        highlights[1].players,
+        ["Carry", "Support", "Enemy1", "Enemy2", "Enemy3"]
+    );
+    assert_eq!(
+        (highlights[1].start_time, highlights[1].end_time),
+        (1490.0, 1514.0)
+    );
+    assert_eq!(highlights[2].kind, HighlightKind::BaseRace);
+    assert_eq!(highlights[2].players, ["Carry", "Enemy1", "Enemy2"]);
+
+    // structures of one team only are no base race
+    let one_sided = &events[..6];
+    assert!(!HighlightDetector::new()
+        .detect(one_sided)
+        .iter()
+        .any(|h| h.kind == HighlightKind::BaseRace));
+    let ace = event(json!({
+        "EventID": 7, "EventName": "Ace", "EventTime": 1512.0, "Acer": "Enemy3", "AcingTeam": "CHAOS"
+    }));
+    let highlights = HighlightDetector::new().detect(&[events[2].clone(), events[3].clone(), ace]);
+    let ace = highlights
+        .iter()
+        .find(|h| matches!(h.kind, HighlightKind::Ace { .. }))
+        .unwrap();
+    assert_eq!(
+        ace.kind,
+        HighlightKind::Ace {
+            acing_team: TeamId::Chaos
+        }
+    );
+    assert_eq!(ace.start_time, 1490.0);
+}
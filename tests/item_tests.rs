@@ -0,0 +1,152 @@
+use serde_json::json;
+use shaco::{
+    analysis::items::{ItemEventKind, ItemRarity, ItemTracker},
+    model::ingame::{AllGameData, PlayerItem},
+    static_data::StaticData,
+};
+
+fn static_data() -> StaticData {
+    StaticData::load(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/static_data")).unwrap()
+}
+
+fn items(ids: &[i32]) -> Vec<PlayerItem> {
+    ids.iter()
+        .enumerate()
+        .map(|(slot, id)| {
+            serde_json::from_value(json!({
+                "canUse": false, "consumable": false, "count": 1, "displayName": format!("Item {id}"),
+                "itemID": id, "price": 0, "rawDescription": "", "rawDisplayName": "", "slot": slot
+            }))
+            .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn purchases_upgrades_and_power_spikes() {
+    let data = static_data();
+    let mut tracker = ItemTracker::new(&data);
+    let player = "Player#EUW";
+
+    // the first sample is only recorded
+    assert!(tracker
+        .push_player_items(player, 0.0, &items(&[]))
+        .is_empty());
+
+    let events = tracker.push_player_items(player, 60.0, &items(&[1036, 1001]));
+    assert_eq!(events.len(), 2);
+    assert!(events.iter().all(|e| e.kind == ItemEventKind::Purchased));
+    assert_eq!(events[0].item_name, "Item 1036");
+
+    tracker.push_player_items(player, 300.0, &items(&[1036, 1001, 1028]));
+    let paths = tracker.build_paths(player);
+    let targets = paths.iter().map(|p| p.target).collect::<Vec<_>>();
+    assert_eq!(targets, [3004, 6632]);
+    assert_eq!(paths[0].owned, [1036]);
+    assert_eq!(paths[0].missing, [3070, 1036]);
+    assert_eq!(paths[0].remaining_gold, 2900 - 350);
+    // Phage is missing nothing but Sheen
+    assert_eq!(paths[1].missing, [3057]);
+
+    let events = tracker.push_player_items(player, 400.0, &items(&[1001, 3044]));
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].kind,
+        ItemEventKind::Upgraded {
+            components: vec![1028, 1036]
+        }
+    );
+    let paths = tracker.build_paths(player);
+    assert_eq!(paths.len(), 1);
+    assert_eq!(
+        (paths[0].target, paths[0].remaining_gold),
+        (6632, 3300 - 1100)
+    );
+
+    // samples that aren't newer are ignored
+    assert!(tracker
+        .push_player_items(player, 400.0, &items(&[]))
+        .is_empty());
+
+    // tier 2 boots are no power spike
+    let events = tracker.push_player_items(player, 600.0, &items(&[3158, 3044]));
+    assert_eq!(
+        events[0].kind,
+        ItemEventKind::Upgraded {
+            components: vec![1001]
+        }
+    );
+
+    let events = tracker.push_player_items(player, 900.0, &items(&[3158, 6632, 2052]));
+    assert_eq!(
+        events[0].kind,
+        ItemEventKind::PowerSpike {
+            rarity: ItemRarity::Mythic,
+            components: vec![3044],
+            completed_items: 1
+        }
+    );
+    assert_eq!(events[1].kind, ItemEventKind::Purchased);
+    assert!(tracker.build_paths(player).is_empty());
+
+    let events = tracker.push_player_items(player, 1000.0, &items(&[3158, 6632]));
+    assert_eq!(
+        (events[0].item_id, &events[0].kind),
+        (2052, &ItemEventKind::Removed)
+    );
+
+    assert_eq!(tracker.power_spikes().count(), 1);
+    assert_eq!(tracker.events().len(), 8);
+    assert_eq!(
+        serde_json::to_value(tracker.power_spikes().next()).unwrap()["kind"],
+        json!({ "type": "powerSpike", "rarity": "Mythic", "components": [3044], "completedItems": 1 })
+    );
+}
+
+#[test]
+fn manamune_transforming_is_no_power_spike() {
+    let data = static_data();
+    let mut tracker = ItemTracker::new(&data);
+    let player = "Player#EUW";
+
+    tracker.push_player_items(player, 0.0, &items(&[3070, 1036]));
+    let events = tracker.push_player_items(player, 600.0, &items(&[3004]));
+    assert!(matches!(
+        events[0].kind,
+        ItemEventKind::PowerSpike {
+            rarity: ItemRarity::Legendary,
+            ..
+        }
+    ));
+    let events = tracker.push_player_items(player, 900.0, &items(&[3042]));
+    assert_eq!(
+        events[0].kind,
+        ItemEventKind::Upgraded {
+            components: vec![3004]
+        }
+    );
+}
+
+#[test]
+fn arena_items_of_all_players() {
+    let data = static_data();
+    let mut tracker = ItemTracker::new(&data);
+    let game: AllGameData =
+        serde_json::from_str(include_str!("GetLiveclientdataAllgamedata_Arena6.json")).unwrap();
+
+    assert!(tracker.push(&game).is_empty());
+    let lucian = &game.all_players[0].riot_id.riot_id;
+    let items = tracker.items(lucian).unwrap();
+    assert_eq!(items[..2], [3184, 3158]);
+
+    let mut later = game.clone();
+    later.game_data.game_time += 30.0;
+    later.all_players[0].items.remove(1);
+    let events = tracker.push(&later);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].riot_id, *lucian);
+    assert_eq!(
+        (events[0].item_id, &events[0].kind),
+        (3158, &ItemEventKind::Removed)
+    );
+}
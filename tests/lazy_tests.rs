@@ -0,0 +1,73 @@
+use serde_json::Value;
+use shaco::{
+    model::{ingame::AllGameData, lazy::LazyGameData},
+    testing::{MockGame, MockIngameServer},
+};
+
+const FIXTURES: [&str; 3] = [
+    include_str!("GetLiveclientdataAllgamedata_Arena2.json"),
+    include_str!("GetLiveclientdataAllgamedata_Arena5.json"),
+    include_str!("GetLiveclientdataAllgamedata_Arena6.json"),
+];
+
+#[test]
+fn lazy_matches_eager() {
+    for fixture in FIXTURES {
+        let eager: AllGameData = serde_json::from_str(fixture).unwrap();
+        let lazy: LazyGameData = serde_json::from_str(fixture).unwrap();
+
+        // the Arena special events are skipped as well
+        assert_eq!(lazy.all_players.len(), eager.all_players.len());
+        assert_eq!(lazy.events.len(), eager.events.len());
+        assert_eq!(lazy.game_data.game_time, eager.game_data.game_time);
+        assert_eq!(
+            lazy.active_player().unwrap().riot_id.riot_id,
+            eager.active_player.unwrap().riot_id.riot_id
+        );
+        for (lazy, eager) in lazy.all_players.iter().zip(eager.all_players.iter()) {
+            assert_eq!(lazy.riot_id.riot_id, eager.riot_id.riot_id);
+            assert_eq!(lazy.scores.kills, eager.scores.kills);
+            let items = lazy.items().unwrap();
+            assert_eq!(items.len(), eager.items.len());
+            for (lazy, eager) in items.iter().zip(eager.items.iter()) {
+                assert_eq!(lazy.item_id, eager.item_id);
+                assert_eq!(lazy.raw_description, eager.raw_description);
+            }
+            assert_eq!(
+                serde_json::to_value(lazy.summoner_spells().unwrap()).unwrap(),
+                serde_json::to_value(&eager.summoner_spells).unwrap()
+            );
+            assert_eq!(lazy.runes().unwrap().is_some(), eager.runes.is_some());
+        }
+    }
+}
+
+#[tokio::test]
+async fn only_new_events() {
+    let fixture: Value = serde_json::from_str(FIXTURES[2]).unwrap();
+    let server = MockIngameServer::start(MockGame::Spectator(fixture))
+        .await
+        .unwrap();
+    let client = server.ingame_client();
+
+    let all = client.all_game_data_lazy(None).await.unwrap();
+    assert!(all.active_player().is_none());
+    let next_event_id = all.next_event_id();
+    // events unknown to the crate are skipped, but not the last one
+    assert_eq!(next_event_id, 74);
+    assert!(all.events.len() < 74);
+
+    let some = client.all_game_data_lazy(Some(70)).await.unwrap();
+    assert_eq!(some.events.len(), 4);
+    assert_eq!(some.events[0].get_event_id(), 70);
+    assert_eq!(some.next_event_id(), next_event_id);
+
+    let none = client
+        .all_game_data_lazy(Some(next_event_id))
+        .await
+        .unwrap();
+    assert!(none.events.is_empty());
+    // polling again without new events keeps the requested id
+    assert_eq!(none.next_event_id(), next_event_id);
+    assert_eq!(none.all_players.len(), all.all_players.len());
+}
@@ -0,0 +1,194 @@
+use std::sync::{Arc, Mutex};
+
+use futures_util::StreamExt;
+use serde_json::{json, Value};
+use shaco::{
+    lobby::InvitationStream,
+    model::lcu::{
+        lobby::{InvitationState, LobbyPosition},
+        matchmaking::SearchState,
+    },
+    testing::{MockLcuServer, MockResponse},
+};
+
+fn lobby_json() -> Value {
+    json!({
+        "partyId": "party",
+        "canStartActivity": true,
+        "gameConfig": { "queueId": 420, "gameMode": "CLASSIC", "mapId": 11, "showPositionSelector": true },
+        "localMember": {
+            "summonerId": 1, "gameName": "Me", "tagLine": "EUW", "isLeader": true,
+            "firstPositionPreference": "MIDDLE", "secondPositionPreference": "UNSELECTED"
+        },
+        "members": [
+            { "summonerId": 1, "isLeader": true, "firstPositionPreference": "MIDDLE" },
+            { "summonerId": 2, "isLeader": false, "firstPositionPreference": "FILL" }
+        ],
+        "invitations": [{ "invitationId": "a", "toSummonerId": 3, "state": "Pending" }]
+    })
+}
+
+#[tokio::test]
+async fn lobby_management() {
+    let server = MockLcuServer::start().await.unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    for (method, path) in [
+        (
+            "PUT",
+            "/lol-lobby/v2/lobby/members/localMember/position-preferences",
+        ),
+        ("POST", "/lol-lobby/v2/lobby/invitations"),
+        ("POST", "/lol-lobby/v2/received-invitations/b/accept"),
+        ("POST", "/lol-lobby/v2/lobby/members/2/promote"),
+        ("POST", "/lol-lobby/v2/lobby/members/2/kick"),
+        ("POST", "/lol-lobby/v2/lobby/matchmaking/search"),
+        ("DELETE", "/lol-lobby/v2/lobby/matchmaking/search"),
+    ] {
+        let requests = requests.clone();
+        server.route(method, path, move |request| {
+            let body = request.json::<Value>().unwrap_or(Value::Null);
+            requests
+                .lock()
+                .unwrap()
+                .push((format!("{} {}", request.method, request.path), body));
+            MockResponse::status(204)
+        });
+    }
+    server.route("POST", "/lol-lobby/v2/lobby", |request| {
+        let body: Value = request.json().unwrap();
+        let mut lobby = lobby_json();
+        lobby["gameConfig"]["queueId"] = body["queueId"].clone();
+        MockResponse::json(&lobby)
+    });
+    server.route(
+        "GET",
+        "/lol-summoner/v1/summoners",
+        |request| match request.query("name") {
+            Some("Teammate #EUW") => MockResponse::json(&json!({
+                "summonerId": 2, "gameName": "Teammate ", "tagLine": "EUW"
+            })),
+            _ => MockResponse::status(404),
+        },
+    );
+    server.route("GET", "/lol-matchmaking/v1/search", |_| {
+        MockResponse::json(&json!({
+            "searchState": "Searching",
+            "queueId": 420,
+            "isCurrentlyInQueue": true,
+            "estimatedQueueTime": 95.5,
+            "timeInQueue": 12.0,
+            "errors": [],
+            "lowPriorityData": {
+                "bustedLeaverAccessToken": "",
+                "penalizedSummonerIds": [2],
+                "penaltyTime": 300.0,
+                "penaltyTimeRemaining": 120.0,
+                "reason": "LEAVER_BUSTED"
+            },
+            "dodgeData": { "dodgerId": 0, "state": "Invalid" },
+            "readyCheck": { "state": "Invalid", "playerResponse": "None" }
+        }))
+    });
+
+    let client = server.rest_client();
+    let lobby = client.create_lobby(440).await.unwrap();
+    assert_eq!(lobby.game_config.queue_id, 440);
+    assert_eq!(
+        lobby.local_member.first_position_preference,
+        LobbyPosition::Middle
+    );
+    assert_eq!(
+        lobby.members[1].first_position_preference,
+        LobbyPosition::Fill
+    );
+    assert_eq!(lobby.invitations[0].state, InvitationState::Pending);
+
+    client
+        .set_position_preferences(LobbyPosition::Jungle, LobbyPosition::Utility)
+        .await
+        .unwrap();
+    let summoner = client.invite_by_riot_id("Teammate #EUW").await.unwrap();
+    assert_eq!(summoner.summoner_id, 2);
+    assert!(client.invite_by_riot_id("Nobody#EUW").await.is_err());
+    client.accept_invitation("b").await.unwrap();
+    client.promote_member(2).await.unwrap();
+    client.kick_member(2).await.unwrap();
+    client.start_search().await.unwrap();
+    client.cancel_search().await.unwrap();
+
+    let search = client.search_state().await.unwrap();
+    assert_eq!(search.search_state, SearchState::Searching);
+    assert_eq!(search.estimated_queue_time, 95.5);
+    assert!(search.low_priority_data.is_penalized());
+
+    let requests = requests.lock().unwrap();
+    let paths = requests.iter().map(|(path, _)| path).collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        [
+            "PUT /lol-lobby/v2/lobby/members/localMember/position-preferences",
+            "POST /lol-lobby/v2/lobby/invitations",
+            "POST /lol-lobby/v2/received-invitations/b/accept",
+            "POST /lol-lobby/v2/lobby/members/2/promote",
+            "POST /lol-lobby/v2/lobby/members/2/kick",
+            "POST /lol-lobby/v2/lobby/matchmaking/search",
+            "DELETE /lol-lobby/v2/lobby/matchmaking/search",
+        ]
+    );
+    assert_eq!(
+        requests[0].1,
+        json!({ "firstPreference": "JUNGLE", "secondPreference": "UTILITY" })
+    );
+    assert_eq!(requests[1].1, json!([{ "toSummonerId": 2 }]));
+}
+
+#[tokio::test]
+async fn invitation_stream() {
+    let server = MockLcuServer::start().await.unwrap();
+    server.route("GET", "/lol-lobby/v2/received-invitations", |_| {
+        MockResponse::json(&json!([
+            { "invitationId": "a", "fromSummonerId": 2, "state": "Pending", "canAcceptInvitation": true },
+            { "invitationId": "old", "fromSummonerId": 3, "state": "Declined" }
+        ]))
+    });
+
+    let rest_client = server.rest_client();
+    let ws_client = server.websocket_client().await.unwrap();
+    let mut stream = InvitationStream::new(&rest_client, ws_client)
+        .await
+        .unwrap();
+    server
+        .wait_for_subscription("/lol-lobby/v2/received-invitations")
+        .await;
+
+    let uri = "/lol-lobby/v2/received-invitations";
+    // the invitation "a" is sent again with every update
+    server.publish(
+        uri,
+        "Update",
+        json!([
+            { "invitationId": "a", "state": "Pending" },
+            { "invitationId": "b", "fromSummonerId": 4, "state": "Pending", "gameConfig": { "queueId": 450 } }
+        ]),
+    );
+    server.publish(uri, "Delete", Value::Null);
+    server.publish(
+        uri,
+        "Create",
+        json!([{ "invitationId": "c", "state": "Pending" }]),
+    );
+
+    let invitations = [
+        stream.next().await.unwrap(),
+        stream.next().await.unwrap(),
+        stream.next().await.unwrap(),
+    ];
+    assert_eq!(invitations[0].invitation_id, "a");
+    assert!(invitations[0].can_accept_invitation);
+    assert_eq!(invitations[1].invitation_id, "b");
+    assert_eq!(invitations[1].game_config.queue_id, 450);
+    assert_eq!(invitations[2].invitation_id, "c");
+
+    server.close_websockets();
+    assert!(stream.next().await.is_none());
+}
@@ -0,0 +1,104 @@
+use futures_util::StreamExt;
+use serde_json::json;
+use shaco::{
+    model::ws::LcuSubscriptionType,
+    rest::RESTClient,
+    testing::{MockLcuServer, MockResponse},
+    ws::LcuWebsocketClient,
+};
+
+#[tokio::test]
+async fn lcu_rest_routes() {
+    let server = MockLcuServer::start().await.unwrap();
+    server.route("GET", "/lol-gameflow/v1/gameflow-phase", |_| {
+        MockResponse::json(&"Lobby")
+    });
+    server.route("PUT", "/lol-chat/v1/me", |request| {
+        let body: serde_json::Value = request.json().unwrap();
+        MockResponse::json(&json!({ "statusMessage": body["statusMessage"] }))
+    });
+
+    let client = server.rest_client();
+    assert_eq!(
+        client.get("/lol-gameflow/v1/gameflow-phase").await.unwrap(),
+        "Lobby"
+    );
+    assert_eq!(
+        client
+            .put("/lol-chat/v1/me", json!({ "statusMessage": "hello" }))
+            .await
+            .unwrap()["statusMessage"],
+        "hello"
+    );
+
+    let not_found = client.get("/lol-summoner/v1/current-summoner").await;
+    assert_eq!(not_found.unwrap_err().status().unwrap(), 404);
+
+    // `+` is only a space in the query
+    server.route("GET", "/lol-summoner/v1/alias+1", |request| {
+        MockResponse::json(&request.query("name"))
+    });
+    assert_eq!(
+        client
+            .get("/lol-summoner/v1/alias+1?name=Riot+Games%23EUW")
+            .await
+            .unwrap(),
+        "Riot Games#EUW"
+    );
+}
+
+#[tokio::test]
+async fn lcu_rejects_bad_credentials() {
+    let server = MockLcuServer::with_auth_token("correct").await.unwrap();
+    server.route("GET", "/lol-gameflow/v1/gameflow-phase", |_| {
+        MockResponse::json(&"Lobby")
+    });
+
+    let client =
+        RESTClient::with_auth(server.port(), "wrong", server.certificate().as_bytes()).unwrap();
+    let unauthorized = client.get("/lol-gameflow/v1/gameflow-phase").await;
+    assert_eq!(unauthorized.unwrap_err().status().unwrap(), 401);
+
+    let websocket = LcuWebsocketClient::connect_with_auth(
+        server.port(),
+        "wrong",
+        server.certificate().as_bytes(),
+    )
+    .await;
+    assert!(websocket.is_err());
+}
+
+#[tokio::test]
+async fn lcu_websocket_events() {
+    let server = MockLcuServer::start().await.unwrap();
+    let mut client = server.websocket_client().await.unwrap();
+
+    client
+        .subscribe(LcuSubscriptionType::JsonApiEvent(
+            "/lol-gameflow/v1/gameflow-phase".to_string(),
+        ))
+        .await
+        .unwrap();
+    server
+        .wait_for_subscription("/lol-gameflow/v1/gameflow-phase")
+        .await;
+
+    // not subscribed, must not be received
+    server.publish("/lol-lobby/v2/lobby", "Update", json!({}));
+    server.publish(
+        "/lol-gameflow/v1/gameflow-phase",
+        "Update",
+        json!("Matchmaking"),
+    );
+
+    let event = client.next().await.unwrap();
+    assert!(matches!(
+        event.subscription_type,
+        LcuSubscriptionType::JsonApiEvent(ref s) if s == "lol-gameflow_v1_gameflow-phase"
+    ));
+    assert_eq!(event.event_type, "Update");
+    assert_eq!(event.data, "Matchmaking");
+
+    server.close_websockets();
+    assert!(client.next().await.is_none());
+}
@@ -0,0 +1,240 @@
+use serde_json::{json, Value};
+use shaco::{
+    error::IngameClientError,
+    ingame::RouteStyle,
+    openapi::{compare_spec, EndpointDrift},
+    testing::{GameSimulator, MockGame, MockIngameServer, MockResponse, SimulatedMap},
+};
+
+const ROUTES: [&str; 12] = [
+    "allgamedata",
+    "eventdata",
+    "gamestats",
+    "playerlist",
+    "playeritems",
+    "playermainrunes",
+    "playerscores",
+    "playersummonerspells",
+    "activeplayer",
+    "activeplayerabilities",
+    "activeplayername",
+    "activeplayerrunes",
+];
+
+fn fixture() -> Value {
+    serde_json::from_str(include_str!("GetLiveclientdataAllgamedata_Arena6.json")).unwrap()
+}
+
+/// An OpenAPI 3 description with an untyped response for every route, \
+/// but the game stats described with `mapTerrain` replaced by `gameVersion`
+fn openapi_spec() -> Value {
+    let mut paths = ROUTES
+        .iter()
+        .map(|route| {
+            let operation = json!({ "get": { "responses": { "200": { "description": "OK" } } } });
+            (format!("/liveclientdata/{route}"), operation)
+        })
+        .collect::<serde_json::Map<_, _>>();
+    paths["/liveclientdata/gamestats"]["get"]["responses"]["200"]["content"] = json!({
+        "application/json": { "schema": { "$ref": "#/components/schemas/GameStats" } }
+    });
+    let properties = [
+        "gameMode",
+        "gameTime",
+        "mapName",
+        "mapNumber",
+        "gameVersion",
+    ]
+    .iter()
+    .map(|p| (p.to_string(), json!({ "type": "string" })))
+    .collect::<serde_json::Map<_, _>>();
+    json!({
+        "openapi": "3.0.0",
+        "paths": paths,
+        "components": { "schemas": { "GameStats": { "type": "object", "properties": properties } } }
+    })
+}
+
+#[test]
+fn route_paths() {
+    assert_eq!(
+        RouteStyle::Modern.path("GetLiveclientdataActiveplayerrunes"),
+        "liveclientdata/activeplayerrunes"
+    );
+    assert_eq!(
+        RouteStyle::Legacy.path("GetLiveclientdataActiveplayerrunes"),
+        "GetLiveclientdataActiveplayerrunes"
+    );
+    assert_eq!(RouteStyle::Modern.path("Help"), "Help");
+    // only the path is lowercased
+    assert_eq!(
+        RouteStyle::Modern.path("GetLiveclientdataAllgamedata?eventID=3"),
+        "liveclientdata/allgamedata?eventID=3"
+    );
+}
+
+#[tokio::test]
+async fn modern_routes() {
+    let server = MockIngameServer::start(MockGame::Live(fixture()))
+        .await
+        .unwrap();
+    let mut client = server.ingame_client();
+    assert_eq!(client.route_style(), RouteStyle::Legacy);
+
+    let mut game_data = fixture()["gameData"].clone();
+    game_data["gameTime"] = json!(1234.5);
+    server.set_response("/liveclientdata/gamestats", MockResponse::json(&game_data));
+    client.set_route_style(RouteStyle::Modern);
+    assert_eq!(client.game_stats().await.unwrap().game_time, 1234.5);
+    client.set_route_style(RouteStyle::Legacy);
+    assert_ne!(client.game_stats().await.unwrap().game_time, 1234.5);
+
+    // all endpoints work with the modern routes
+    assert_eq!(
+        client.detect_route_style().await.unwrap(),
+        RouteStyle::Modern
+    );
+    let data = client.all_game_data(None).await.unwrap();
+    let riot_id = &data.all_players[0].riot_id.riot_id;
+    assert_eq!(
+        client.player_list(None).await.unwrap().len(),
+        data.all_players.len()
+    );
+    assert!(!client.player_items(riot_id).await.unwrap().is_empty());
+    assert_eq!(client.active_player_name().await.unwrap(), *riot_id);
+
+    server.set_response("/liveclientdata/gamestats", MockResponse::status(404));
+    assert_eq!(
+        client.detect_route_style().await.unwrap(),
+        RouteStyle::Legacy
+    );
+    assert_eq!(client.route_style(), RouteStyle::Legacy);
+
+    server.clear_responses();
+    server.set_game(MockGame::LoadingScreen);
+    assert!(matches!(
+        client.detect_route_style().await,
+        Err(IngameClientError::ApiNotAvailableDuringLoadingScreen)
+    ));
+}
+
+#[tokio::test]
+async fn schema_drift() {
+    let server = MockIngameServer::start(MockGame::Live(fixture()))
+        .await
+        .unwrap();
+    let client = server.ingame_client();
+    server.set_response(
+        "/swagger/v3/openapi.json",
+        MockResponse::json(&openapi_spec()),
+    );
+
+    let spec = client.openapi_spec().await.unwrap();
+    let drift = compare_spec(&spec);
+    assert!(drift.missing_endpoints.is_empty());
+    assert_eq!(
+        drift.endpoints,
+        [EndpointDrift {
+            endpoint: "GetLiveclientdataGamestats".to_string(),
+            missing_fields: vec!["mapTerrain".to_string()],
+            unknown_fields: vec!["gameVersion".to_string()],
+        }]
+    );
+
+    let mut spec = openapi_spec();
+    let paths = spec["paths"].as_object_mut().unwrap();
+    paths.remove("/liveclientdata/playerscores");
+    // the legacy routes count as well
+    let item_list = paths.remove("/liveclientdata/playeritems").unwrap();
+    paths.insert("/GetLiveclientdataPlayeritems".to_string(), item_list);
+    let drift = compare_spec(&spec);
+    assert_eq!(drift.missing_endpoints, ["GetLiveclientdataPlayerscores"]);
+}
+
+#[tokio::test]
+async fn swagger_2_description() {
+    let server = MockIngameServer::start(MockGame::Live(fixture()))
+        .await
+        .unwrap();
+    let client = server.ingame_client();
+
+    // the players of the fixture have exactly the fields the crate reads
+    let player = fixture()["allPlayers"][0].clone();
+    let properties = player
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(|key| (key.clone(), json!({})))
+        .collect::<serde_json::Map<_, _>>();
+    let mut spec = openapi_spec();
+    spec.as_object_mut().unwrap().remove("openapi");
+    spec["swagger"] = json!("2.0");
+    spec["paths"]["/liveclientdata/gamestats"]["get"]["responses"]["200"] = json!({});
+    spec["paths"]["/liveclientdata/playerlist"]["get"]["responses"]["200"] = json!({
+        "schema": { "type": "array", "items": { "$ref": "#/definitions/Player" } }
+    });
+    spec["definitions"] = json!({ "Player": { "type": "object", "properties": properties } });
+    server.set_response("/swagger/v2/swagger.json", MockResponse::json(&spec));
+
+    let drift = compare_spec(&client.openapi_spec().await.unwrap());
+    assert!(drift.is_empty(), "{drift:?}");
+
+    spec["definitions"]["Player"]["properties"]
+        .as_object_mut()
+        .unwrap()
+        .remove("isBot");
+    let drift = compare_spec(&spec);
+    assert_eq!(drift.endpoints[0].missing_fields, ["isBot"]);
+}
+
+/// A description of every route with the properties the models of the crate serialize to, \
+/// the fields compared by [compare_spec] have to be the same
+#[test]
+fn fields_match_the_models() {
+    // the players in Arena have no runes
+    let mut simulator = GameSimulator::new(SimulatedMap::SummonersRift, 0);
+    simulator.advance(600.0);
+    let data = serde_json::to_value(simulator.snapshot()).unwrap();
+    // the skin name is left out for the default skin
+    let player = data["allPlayers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|p| p.get("skinName").is_some())
+        .unwrap();
+    let active_player = &data["activePlayer"];
+    let responses = [
+        ("allgamedata", &data),
+        ("eventdata", &data["events"]),
+        ("gamestats", &data["gameData"]),
+        ("playerlist", player),
+        ("playeritems", &player["items"][0]),
+        ("playermainrunes", &player["runes"]),
+        ("playerscores", &player["scores"]),
+        ("playersummonerspells", &player["summonerSpells"]),
+        ("activeplayer", active_player),
+        ("activeplayerabilities", &active_player["abilities"]),
+        ("activeplayername", &active_player["riotId"]),
+        ("activeplayerrunes", &active_player["fullRunes"]),
+    ];
+    let paths = responses
+        .iter()
+        .map(|(route, response)| {
+            let properties = response
+                .as_object()
+                .map(|object| {
+                    object
+                        .keys()
+                        .map(|key| (key.clone(), json!({})))
+                        .collect::<serde_json::Map<_, _>>()
+                })
+                .unwrap_or_default();
+            let schema = json!({ "type": "object", "properties": properties });
+            let operation = json!({ "get": { "responses": { "200": { "schema": schema } } } });
+            (format!("/liveclientdata/{route}"), operation)
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    let drift = compare_spec(&json!({ "swagger": "2.0", "paths": paths }));
+    assert!(drift.is_empty(), "{drift:#?}");
+}
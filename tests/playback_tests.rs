@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use shaco::{
+    ingame::{EventStream, IngameApi},
+    model::ingame::{AllGameData, TeamId},
+    playback::Playback,
+};
+
+fn arena_playback() -> Playback {
+    let data: AllGameData =
+        serde_json::from_str(include_str!("GetLiveclientdataAllgamedata_Arena5.json")).unwrap();
+    Playback::from_snapshots([data])
+}
+
+#[tokio::test]
+async fn playback_virtual_time() {
+    let playback = arena_playback();
+    playback.pause();
+    playback.seek(0.0);
+    assert_eq!(playback.game_time(), 0.0);
+
+    let all_events = playback.event_data(None).await.unwrap();
+    assert!(all_events.is_empty() || all_events.iter().all(|e| e.get_event_time() == 0.0));
+
+    let half_time = playback.end_time() / 2.0;
+    playback.seek(half_time);
+    let events = playback.event_data(None).await.unwrap();
+    assert!(events.iter().all(|e| e.get_event_time() <= half_time));
+    assert_eq!(
+        playback.game_stats().await.unwrap().game_time,
+        playback.game_time()
+    );
+
+    // only the events starting with the given event id are returned
+    let later_events = playback.event_data(Some(2)).await.unwrap();
+    assert_eq!(later_events.len(), events.len() - 2);
+
+    let players = playback.player_list(None).await.unwrap();
+    let order = playback.player_list(Some(TeamId::Order)).await.unwrap();
+    assert_eq!(players.len(), 16);
+    assert!(order.iter().all(|p| p.team == TeamId::Order));
+
+    let riot_id = &players.first().unwrap().riot_id.riot_id;
+    playback.player_items(riot_id).await.unwrap();
+    playback.player_scores(riot_id).await.unwrap();
+    playback.player_summoner_spells(riot_id).await.unwrap();
+    assert!(playback.player_scores("unknown player").await.is_err());
+
+    playback.seek(playback.end_time() + 1.0);
+    assert!(playback.is_finished());
+    assert!(!playback.active_game().await);
+    assert!(playback.all_game_data(None).await.is_err());
+}
+
+#[tokio::test]
+async fn playback_event_stream() {
+    let playback = arena_playback();
+    let expected = playback.clone();
+    expected.pause();
+    expected.seek(expected.end_time());
+    let expected_events = expected.event_data(None).await.unwrap().len();
+
+    // clones share the clock, restart playback at a high speed
+    playback.seek(0.0);
+    playback.set_speed(10_000.0);
+    playback.resume();
+
+    let event_stream = EventStream::from_ingame_client(playback, Some(Duration::from_millis(10)));
+    let events = tokio::time::timeout(Duration::from_secs(10), event_stream.collect::<Vec<_>>())
+        .await
+        .unwrap();
+
+    assert_eq!(events.len(), expected_events);
+}
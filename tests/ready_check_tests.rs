@@ -0,0 +1,271 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::StreamExt;
+use serde_json::{json, Value};
+use shaco::{
+    model::lcu::matchmaking::ReadyCheck,
+    ready_check::{
+        ReadyCheckContext, ReadyCheckDecision, ReadyCheckOutcome, ReadyCheckPolicy,
+        ReadyCheckWatcher,
+    },
+    testing::{MockLcuServer, MockResponse},
+};
+
+fn context(queue_id: Option<i32>, timer: f64) -> ReadyCheckContext {
+    ReadyCheckContext {
+        ready_check: ReadyCheck {
+            timer,
+            ..Default::default()
+        },
+        queue_id,
+    }
+}
+
+#[tokio::test]
+async fn policies() {
+    let now = SystemTime::now();
+    assert_eq!(
+        ReadyCheckPolicy::Always
+            .decide(context(None, 0.0), now)
+            .await,
+        ReadyCheckDecision::Accept
+    );
+    let queues = ReadyCheckPolicy::Queues(vec![420, 440]);
+    assert_eq!(
+        queues.decide(context(Some(440), 0.0), now).await,
+        ReadyCheckDecision::Accept
+    );
+    assert_eq!(
+        queues.decide(context(Some(450), 0.0), now).await,
+        ReadyCheckDecision::Ignore
+    );
+    assert_eq!(
+        queues.decide(context(None, 0.0), now).await,
+        ReadyCheckDecision::Ignore
+    );
+
+    let hours = |h: u64| Duration::from_secs(h * 60 * 60);
+    // 22:00 UTC on the first day
+    let evening = UNIX_EPOCH + hours(22);
+    let window = |start, end, utc_offset_secs| ReadyCheckPolicy::TimeWindow {
+        start: hours(start),
+        end: hours(end),
+        utc_offset_secs,
+    };
+    assert_eq!(
+        window(18, 23, 0).decide(context(None, 0.0), evening).await,
+        ReadyCheckDecision::Accept
+    );
+    // 00:00 in UTC+2
+    assert_eq!(
+        window(18, 23, 2 * 60 * 60)
+            .decide(context(None, 0.0), evening)
+            .await,
+        ReadyCheckDecision::Ignore
+    );
+    // around midnight
+    assert_eq!(
+        window(20, 2, 2 * 60 * 60)
+            .decide(context(None, 0.0), evening)
+            .await,
+        ReadyCheckDecision::Accept
+    );
+    assert_eq!(
+        window(20, 2, -(3 * 60 * 60))
+            .decide(context(None, 0.0), evening)
+            .await,
+        ReadyCheckDecision::Ignore
+    );
+
+    let callback = ReadyCheckPolicy::callback(
+        Duration::from_secs(5),
+        ReadyCheckDecision::Accept,
+        |context| async move {
+            if context.queue_id == Some(450) {
+                ReadyCheckDecision::Decline
+            } else {
+                // never answers in time
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                ReadyCheckDecision::Decline
+            }
+        },
+    );
+    assert_eq!(
+        callback.decide(context(Some(450), 0.0), now).await,
+        ReadyCheckDecision::Decline
+    );
+    // the deadline counts from when the match was found
+    let started = tokio::time::Instant::now();
+    assert_eq!(
+        callback.decide(context(Some(420), 4.9), now).await,
+        ReadyCheckDecision::Accept
+    );
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn watcher_outcomes() {
+    let server = MockLcuServer::start().await.unwrap();
+    let accepted = Arc::new(Mutex::new(0));
+    let counter = accepted.clone();
+    server.route(
+        "POST",
+        "/lol-matchmaking/v1/ready-check/accept",
+        move |_| {
+            *counter.lock().unwrap() += 1;
+            MockResponse::status(204)
+        },
+    );
+    server.route("GET", "/lol-matchmaking/v1/search", |_| {
+        MockResponse::json(&json!({ "searchState": "Found", "queueId": 420 }))
+    });
+    let current = Arc::new(Mutex::new(Value::Null));
+    let ready_check = current.clone();
+    server.route("GET", "/lol-matchmaking/v1/ready-check", move |_| {
+        MockResponse::json(&*ready_check.lock().unwrap())
+    });
+
+    let policy = ReadyCheckPolicy::callback(
+        Duration::from_secs(8),
+        ReadyCheckDecision::Ignore,
+        |context| async move {
+            if context.ready_check.dodge_warning.is_empty() {
+                ReadyCheckDecision::Accept
+            } else {
+                ReadyCheckDecision::Decline
+            }
+        },
+    );
+    let mut watcher = ReadyCheckWatcher::new(
+        server.rest_client(),
+        server.websocket_client().await.unwrap(),
+        policy,
+    )
+    .await
+    .unwrap();
+    server
+        .wait_for_subscription("/lol-matchmaking/v1/ready-check")
+        .await;
+
+    let publish = |event_type: &str, ready_check: Value| {
+        *current.lock().unwrap() = ready_check.clone();
+        server.publish("/lol-matchmaking/v1/ready-check", event_type, ready_check);
+    };
+    let in_progress = json!({ "state": "InProgress", "playerResponse": "None", "timer": 1.0 });
+    let decided = ReadyCheckOutcome::Decided {
+        queue_id: Some(420),
+        decision: ReadyCheckDecision::Accept,
+    };
+
+    publish("Update", in_progress.clone());
+    // repeated with the increasing timer
+    publish("Update", in_progress.clone());
+    assert_eq!(watcher.next().await.unwrap(), decided);
+    publish(
+        "Update",
+        json!({ "state": "StrangerNotReady", "playerResponse": "Accepted", "declinerIds": [7] }),
+    );
+    assert_eq!(
+        watcher.next().await.unwrap(),
+        ReadyCheckOutcome::Dodged {
+            decliner_ids: vec![7],
+            by_party: false,
+            requeued: true
+        }
+    );
+    publish("Delete", json!(null));
+    publish("Update", in_progress);
+    assert_eq!(watcher.next().await.unwrap(), decided);
+    publish(
+        "Update",
+        json!({ "state": "EveryoneReady", "playerResponse": "Accepted" }),
+    );
+    assert_eq!(
+        watcher.next().await.unwrap(),
+        ReadyCheckOutcome::EveryoneReady
+    );
+    // the decline isn't routed
+    publish(
+        "Update",
+        json!({ "state": "InProgress", "playerResponse": "None", "dodgeWarning": "PENALTY" }),
+    );
+    let ReadyCheckOutcome::Failed { decision, .. } = watcher.next().await.unwrap() else {
+        panic!("declining should fail");
+    };
+    assert_eq!(decision, ReadyCheckDecision::Decline);
+    assert_eq!(*accepted.lock().unwrap(), 2);
+
+    server.close_websockets();
+    assert!(watcher.next().await.is_none());
+}
+
+#[tokio::test]
+async fn late_decisions() {
+    let server = MockLcuServer::start().await.unwrap();
+    let accepted = Arc::new(Mutex::new(0));
+    let counter = accepted.clone();
+    server.route(
+        "POST",
+        "/lol-matchmaking/v1/ready-check/accept",
+        move |_| {
+            *counter.lock().unwrap() += 1;
+            MockResponse::status(204)
+        },
+    );
+    // the player accepted in the client meanwhile
+    server.route("GET", "/lol-matchmaking/v1/ready-check", |_| {
+        MockResponse::json(&json!({ "state": "InProgress", "playerResponse": "Accepted" }))
+    });
+
+    let policy = ReadyCheckPolicy::callback(
+        Duration::from_secs(8),
+        ReadyCheckDecision::Ignore,
+        |_| async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            ReadyCheckDecision::Accept
+        },
+    );
+    let mut watcher = ReadyCheckWatcher::new(
+        server.rest_client(),
+        server.websocket_client().await.unwrap(),
+        policy,
+    )
+    .await
+    .unwrap();
+    server
+        .wait_for_subscription("/lol-matchmaking/v1/ready-check")
+        .await;
+
+    let uri = "/lol-matchmaking/v1/ready-check";
+    let in_progress = json!({ "state": "InProgress", "playerResponse": "None" });
+    // the slow callback doesn't hold up the dodge, its decision is dropped
+    let started = tokio::time::Instant::now();
+    server.publish(uri, "Update", in_progress.clone());
+    server.publish(
+        uri,
+        "Update",
+        json!({ "state": "PartyNotReady", "playerResponse": "None", "declinerIds": [2] }),
+    );
+    assert!(matches!(
+        watcher.next().await.unwrap(),
+        ReadyCheckOutcome::Dodged { by_party: true, .. }
+    ));
+    assert!(started.elapsed() < Duration::from_millis(300));
+
+    // the ready check is checked again before answering it
+    server.publish(uri, "Update", in_progress);
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    server.publish(
+        uri,
+        "Update",
+        json!({ "state": "EveryoneReady", "playerResponse": "Accepted" }),
+    );
+    assert_eq!(
+        watcher.next().await.unwrap(),
+        ReadyCheckOutcome::EveryoneReady
+    );
+    assert_eq!(*accepted.lock().unwrap(), 0);
+}
@@ -0,0 +1,111 @@
+use std::{io::Cursor, time::Duration};
+
+use shaco::{
+    ingame::RouteStyle,
+    model::ingame::GameMode,
+    recorder::{RecordedEntry, Recorder, RecordingReader, RecordingWriter},
+    testing::{MockGame, MockIngameServer},
+};
+
+/// write fixtures through the [RecordingWriter] and check that the [RecordingReader] returns the same data
+#[test]
+fn recording_round_trip() {
+    let full: serde_json::Value =
+        serde_json::from_str(include_str!("GetLiveclientdataAllgamedata_Arena5.json")).unwrap();
+    let mut partial = full.clone();
+    partial["events"]["Events"]
+        .as_array_mut()
+        .unwrap()
+        .truncate(100);
+
+    let mut writer = RecordingWriter::new(Vec::new(), full["gameData"].clone()).unwrap();
+    writer
+        .write_snapshot(Duration::from_secs(1), partial)
+        .unwrap();
+    assert_eq!(writer.next_event_id(), 100);
+    // the second sample contains all events again, already written events must be skipped
+    writer
+        .write_snapshot(Duration::from_secs(2), full.clone())
+        .unwrap();
+    let recording = writer.into_inner().unwrap();
+
+    let reader = RecordingReader::new(Cursor::new(recording)).unwrap();
+    assert!(matches!(
+        reader.header().game_stats.game_mode,
+        GameMode::Arena
+    ));
+
+    let entries = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    let snapshots = entries
+        .iter()
+        .filter_map(|entry| match entry {
+            RecordedEntry::Snapshot { elapsed, data } => Some((elapsed, data)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let events = entries
+        .iter()
+        .filter(|entry| matches!(entry, RecordedEntry::Event { .. }))
+        .count();
+
+    assert_eq!(snapshots.len(), 2);
+    assert_eq!(*snapshots[0].0, Duration::from_secs(1));
+    assert_eq!(snapshots[0].1.events.len(), 100);
+    assert_eq!(
+        snapshots[1].1.events.len(),
+        full["events"]["Events"].as_array().unwrap().len()
+    );
+    assert_eq!(events, snapshots[1].1.events.len());
+    assert_eq!(snapshots[1].1.all_players.len(), 16);
+}
+
+#[test]
+fn recording_without_header() {
+    let recording = "{\"type\":\"snapshot\",\"elapsed\":0,\"data\":{}}\n";
+    assert!(RecordingReader::new(Cursor::new(recording)).is_err());
+}
+
+/// record a game from a [MockIngameServer] through the `/liveclientdata/*` routes
+#[tokio::test]
+async fn recording_with_modern_routes() {
+    let full: serde_json::Value =
+        serde_json::from_str(include_str!("GetLiveclientdataAllgamedata_Arena5.json")).unwrap();
+    let event_count = full["events"]["Events"].as_array().unwrap().len();
+    let mut partial = full.clone();
+    partial["events"]["Events"]
+        .as_array_mut()
+        .unwrap()
+        .truncate(100);
+
+    let server = MockIngameServer::start(MockGame::Live(partial))
+        .await
+        .unwrap();
+    let mut client = server.ingame_client();
+    client.set_route_style(RouteStyle::Modern);
+    let recorder = Recorder::new(client, Vec::new(), Some(Duration::from_millis(50)));
+    let recording = tokio::spawn(recorder.record());
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    server.set_game(MockGame::Live(full));
+    let recording = recording.await.unwrap().unwrap();
+
+    let entries = RecordingReader::new(Cursor::new(recording))
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let snapshots = entries
+        .iter()
+        .filter_map(|entry| match entry {
+            RecordedEntry::Snapshot { data, .. } => Some(data),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let events = entries
+        .iter()
+        .filter(|entry| matches!(entry, RecordedEntry::Event { .. }))
+        .count();
+
+    assert!(snapshots.len() > 1);
+    assert_eq!(events, event_count);
+    assert_eq!(snapshots.last().unwrap().events.len(), event_count);
+}
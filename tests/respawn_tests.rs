@@ -0,0 +1,101 @@
+use shaco::{
+    analysis::respawn::RespawnTracker,
+    model::ingame::{GameEvent, TeamId},
+    testing::{GameSimulator, SimulatedMap},
+};
+
+#[test]
+fn deaths_of_simulated_game() {
+    let mut simulator = GameSimulator::new(SimulatedMap::SummonersRift, 3);
+    simulator.set_interval(1.0);
+    let mut tracker = RespawnTracker::new();
+    let mut end = None;
+    for data in simulator {
+        tracker.push(&data);
+        end = Some(data);
+    }
+    let end = end.unwrap();
+
+    let kills = end
+        .events
+        .iter()
+        .filter_map(|e| match e {
+            GameEvent::ChampionKill(kill) => Some(kill.event_time),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    // every death lasts at least a second, so a sample sees it
+    assert_eq!(tracker.deaths().len(), kills.len());
+    for player in end.all_players.iter() {
+        let deaths = tracker
+            .deaths()
+            .iter()
+            .filter(|d| d.riot_id == player.riot_id.riot_id)
+            .count();
+        assert_eq!(deaths, player.scores.deaths as usize);
+    }
+    for death in tracker.deaths() {
+        assert!(kills.contains(&death.time));
+        assert!(death.respawn_at > death.time);
+    }
+
+    assert!(!tracker.advantages().is_empty());
+    for advantage in tracker.advantages() {
+        assert!(advantage.players > advantage.opponents);
+        assert!(advantage.until >= advantage.time);
+    }
+}
+
+#[test]
+fn numbers_advantage() {
+    let mut simulator = GameSimulator::new(SimulatedMap::SummonersRift, 3);
+    simulator.advance(90.0);
+    let alive = simulator.snapshot();
+    assert!(alive.all_players.iter().all(|p| !p.is_dead));
+    let time = alive.game_data.game_time;
+
+    let mut tracker = RespawnTracker::new();
+    assert!(tracker.push(&alive).is_none());
+
+    let mut two_dead = alive.clone();
+    two_dead.game_data.game_time += 1.0;
+    for (index, respawn_timer) in [(6, 20.0), (8, 12.0)] {
+        two_dead.all_players[index].is_dead = true;
+        two_dead.all_players[index].respawn_timer = respawn_timer;
+    }
+    let advantage = tracker.push(&two_dead).unwrap().clone();
+    assert_eq!(advantage.team, TeamId::Order);
+    assert_eq!(advantage.until, time + 13.0);
+    assert_eq!(advantage.to_string(), "5v3 for the next 12s");
+
+    let chaos = tracker.team_status(TeamId::Chaos);
+    assert_eq!(chaos.alive.len(), 3);
+    let dead = chaos.dead.iter().map(|d| d.respawn_at).collect::<Vec<_>>();
+    assert_eq!(dead, [time + 13.0, time + 21.0]);
+    assert_eq!(tracker.alive_at(TeamId::Chaos, time + 15.0), 4);
+    let riot_id = &two_dead.all_players[6].riot_id.riot_id;
+    assert_eq!(tracker.respawn_at(riot_id), Some(time + 21.0));
+
+    // the same deaths don't repeat the advantage
+    let mut later = two_dead.clone();
+    later.game_data.game_time += 1.0;
+    later.all_players[6].respawn_timer -= 1.0;
+    later.all_players[8].respawn_timer -= 1.0;
+    assert!(tracker.push(&later).is_none());
+    assert_eq!(tracker.deaths().len(), 2);
+
+    let mut one_respawned = later.clone();
+    one_respawned.game_data.game_time += 12.0;
+    one_respawned.all_players[8].is_dead = false;
+    one_respawned.all_players[6].respawn_timer -= 12.0;
+    let advantage = tracker.push(&one_respawned).unwrap();
+    assert_eq!((advantage.players, advantage.opponents), (5, 4));
+    assert_eq!(advantage.until, time + 21.0);
+
+    let mut all_alive = one_respawned.clone();
+    all_alive.game_data.game_time += 10.0;
+    all_alive.all_players[6].is_dead = false;
+    assert!(tracker.push(&all_alive).is_none());
+    assert_eq!(tracker.advantages().len(), 2);
+    assert_eq!(tracker.respawn_at(riot_id), None);
+}
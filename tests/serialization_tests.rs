@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use shaco::{
+    model::ingame::{AllGameData, DragonType, GameEvent, Killer, SummonerSpells, TeamId, Turret},
+    testing::{GameSimulator, MockGame, MockIngameServer, SimulatedMap},
+};
+
+/// fields the API sends that are not part of the model
+const UNMODELED_FIELDS: [&str; 2] = ["screenPositionBottom", "screenPositionCenter"];
+
+fn aram_fixture() -> Value {
+    serde_json::from_str(include_str!("aram_allgamedata1.json")).unwrap()
+}
+
+/// `serialized` has to equal `original`, except that arrays may skip elements that failed to deserialize
+fn wire_equal(original: &Value, serialized: &Value, path: &str) -> Result<(), String> {
+    match (original, serialized) {
+        (Value::Object(original), Value::Object(serialized)) => {
+            for (key, value) in original {
+                if UNMODELED_FIELDS.contains(&key.as_str()) {
+                    continue;
+                }
+                let serialized = serialized
+                    .get(key)
+                    .ok_or(format!("{path}.{key} is missing"))?;
+                wire_equal(value, serialized, &format!("{path}.{key}"))?;
+            }
+            match serialized.keys().find(|key| !original.contains_key(*key)) {
+                Some(key) => Err(format!("{path}.{key} is not sent by the API")),
+                None => Ok(()),
+            }
+        }
+        (Value::Array(original), Value::Array(serialized)) => {
+            let mut original = original.iter();
+            for (index, value) in serialized.iter().enumerate() {
+                let path = format!("{path}[{index}]");
+                // report the difference to the next element if no element matches
+                let first = original.clone().next();
+                if !original.any(|o| wire_equal(o, value, &path).is_ok()) {
+                    return match first {
+                        Some(first) => wire_equal(first, value, &path),
+                        None => Err(format!("{path} is not sent by the API")),
+                    };
+                }
+            }
+            Ok(())
+        }
+        _ if original == serialized => Ok(()),
+        _ => Err(format!("{path}: expected {original}, got {serialized}")),
+    }
+}
+
+fn assert_round_trip(original: &Value) {
+    let data = AllGameData::deserialize(original).unwrap();
+    let serialized = serde_json::to_value(&data).unwrap();
+    wire_equal(original, &serialized, "").unwrap();
+
+    let reserialized =
+        serde_json::to_value(AllGameData::deserialize(&serialized).unwrap()).unwrap();
+    assert_eq!(serialized, reserialized);
+}
+
+#[test]
+fn all_game_data_serializes_to_api_json() {
+    for fixture in [
+        include_str!("GetLiveclientdataAllgamedata_Arena.json"),
+        include_str!("GetLiveclientdataAllgamedata_Arena2.json"),
+        include_str!("GetLiveclientdataAllgamedata_Arena3.json"),
+        include_str!("GetLiveclientdataAllgamedata_Arena4.json"),
+        include_str!("GetLiveclientdataAllgamedata_Arena5.json"),
+        include_str!("GetLiveclientdataAllgamedata_Arena6.json"),
+        include_str!("GetLiveclientdataAllgamedata_Arena7.json"),
+    ] {
+        assert_round_trip(&serde_json::from_str(fixture).unwrap());
+    }
+    assert_round_trip(&aram_fixture());
+}
+
+#[test]
+fn events_serialize_to_api_json() {
+    for fixture in [
+        include_str!("GetLiveclientdataEventdata1.json"),
+        include_str!("GetLiveclientdataEventdata2.json"),
+    ] {
+        let original: Value = serde_json::from_str(fixture).unwrap();
+        let events = original["Events"].as_array().unwrap();
+        let deserialized = events
+            .iter()
+            .filter_map(|event| Some((event, GameEvent::deserialize(event).ok()?)))
+            .collect::<Vec<_>>();
+        assert!(!deserialized.is_empty());
+        for (event, deserialized) in deserialized {
+            assert_eq!(&serde_json::to_value(deserialized).unwrap(), event);
+        }
+    }
+}
+
+#[test]
+fn simulated_games_round_trip() {
+    for map in [
+        SimulatedMap::SummonersRift,
+        SimulatedMap::HowlingAbyss,
+        SimulatedMap::Arena,
+    ] {
+        let mut simulator = GameSimulator::new(map, 3);
+        simulator.run_to_end();
+        let serialized = serde_json::to_value(simulator.snapshot()).unwrap();
+        let reserialized =
+            serde_json::to_value(AllGameData::deserialize(&serialized).unwrap()).unwrap();
+        assert_eq!(serialized, reserialized);
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap()
+}
+
+#[test]
+fn enums_serialize_to_api_names() {
+    assert_eq!(to_json(&TeamId::Order), "\"ORDER\"");
+    assert_eq!(to_json(&DragonType::Infernal), "\"Fire\"");
+    assert_eq!(to_json(&Turret::Team1C10A), "\"Turret_T1_C_010_A\"");
+    assert_eq!(
+        to_json(&Killer::Dragon(DragonType::Elder, String::new())),
+        "\"SRU_Dragon_Elder\""
+    );
+    assert_eq!(
+        to_json(&Killer::Baron("12.1.1".to_string())),
+        "\"SRU_Baron12.1.1\""
+    );
+
+    for name in [
+        "SRU_Dragon_Water",
+        "Turret_T2_L_03_A",
+        "Minion_T100L0S25N0155",
+        "SRU_RiftHerald",
+        "SRU_Baron",
+        "SRU_Baron12.1.1",
+        "SRU_Murkwolf8.1.1",
+        "SRU_RazorbeakMini9.1.2",
+        "SRU_Dragon_Fire6.1.1",
+        "Obelisk",
+        "Redmer",
+        "Blue Bot",
+    ] {
+        let killer: Killer = serde_json::from_value(name.into()).unwrap();
+        assert_eq!(serde_json::to_value(&killer).unwrap(), name, "{killer:?}");
+    }
+    let killer: Killer = serde_json::from_value("SRU_Murkwolf8.1.1".into()).unwrap();
+    assert!(matches!(killer, Killer::Murkwolf(camp) if camp == "8.1.1"));
+    let killer: Killer = serde_json::from_value("SRU_Dragon_Fire6.1.1".into()).unwrap();
+    assert!(matches!(killer, Killer::Dragon(DragonType::Infernal, _)));
+    let killer: Killer = serde_json::from_value("Turret_T1_C_05_A".into()).unwrap();
+    assert!(matches!(killer, Killer::Turret(Turret::Team1C05A)));
+}
+
+/// serialized models can be served by the mock server
+#[tokio::test]
+async fn serve_serialized_game() {
+    let mut simulator = GameSimulator::new(SimulatedMap::SummonersRift, 5);
+    simulator.advance(900.0);
+    let snapshot = simulator.snapshot();
+    let server = MockIngameServer::start(MockGame::Live(serde_json::to_value(&snapshot).unwrap()))
+        .await
+        .unwrap();
+    let client = server.ingame_client();
+
+    let served = client.all_game_data(None).await.unwrap();
+    assert_eq!(to_json(&served), to_json(&snapshot));
+    let riot_id = &snapshot.all_players[0].riot_id.riot_id;
+    let spells = client.player_summoner_spells(riot_id).await.unwrap();
+    assert!(matches!(spells, SummonerSpells::SummonerSpells { .. }));
+}
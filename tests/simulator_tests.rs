@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use shaco::{
+    model::ingame::{GameEvent, GameMode, Killer, Position, TeamId, Turret},
+    testing::{GameSimulator, SimulatedMap},
+};
+
+const MAPS: [SimulatedMap; 3] = [
+    SimulatedMap::SummonersRift,
+    SimulatedMap::HowlingAbyss,
+    SimulatedMap::Arena,
+];
+
+#[test]
+fn simulator_is_deterministic() {
+    for map in MAPS {
+        let first = GameSimulator::new(map, 42).collect::<Vec<_>>();
+        let second = GameSimulator::new(map, 42).collect::<Vec<_>>();
+        let other_seed = GameSimulator::new(map, 43).collect::<Vec<_>>();
+
+        assert_eq!(format!("{first:?}"), format!("{second:?}"));
+        assert_ne!(format!("{first:?}"), format!("{other_seed:?}"));
+    }
+}
+
+#[test]
+fn simulated_games_are_plausible() {
+    for map in MAPS {
+        for seed in 0..20 {
+            let snapshots = GameSimulator::new(map, seed).collect::<Vec<_>>();
+            let last = snapshots.last().unwrap();
+            let game_time = last.game_data.game_time;
+            let events = &last.events;
+
+            assert!(matches!(events.first(), Some(GameEvent::GameStart(_))));
+            assert!(matches!(events.last(), Some(GameEvent::GameEnd(_))));
+            assert!(events
+                .iter()
+                .enumerate()
+                .all(|(id, e)| e.get_event_id() == id as u32 && e.get_event_time() <= game_time));
+            assert!(snapshots
+                .windows(2)
+                .all(|w| w[0].game_data.game_time < w[1].game_data.game_time
+                    && w[0].events.len() <= w[1].events.len()));
+
+            let players = &last.all_players;
+            let names = players
+                .iter()
+                .map(|p| p.riot_id.game_name.as_str())
+                .collect::<HashSet<_>>();
+            let kills = events
+                .iter()
+                .filter_map(|e| match e {
+                    GameEvent::ChampionKill(kill) => Some(kill),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            assert!(!kills.is_empty());
+            for kill in kills.iter() {
+                assert!(names.contains(kill.victim_name.as_str()));
+                assert!(kill.assisters.iter().all(|a| names.contains(a.as_str())));
+                let Killer::Summoner(killer) = &kill.killer_name else {
+                    panic!("champions are killed by players");
+                };
+                assert!(names.contains(killer.as_str()));
+            }
+            let total_kills = players.iter().map(|p| p.scores.kills).sum::<i32>();
+            let total_deaths = players.iter().map(|p| p.scores.deaths).sum::<i32>();
+            assert_eq!(total_kills as usize, kills.len());
+            assert_eq!(total_deaths as usize, kills.len());
+
+            assert!(players.iter().all(|p| (1..=18).contains(&p.level)));
+            assert!(players.iter().all(|p| !p.items.is_empty()));
+            assert!(events.iter().all(|e| match e {
+                GameEvent::TurretKilled(turret) => !matches!(turret.turret_killed, Turret::Unknown),
+                _ => true,
+            }));
+
+            match map {
+                SimulatedMap::SummonersRift => {
+                    assert!(matches!(last.game_data.game_mode, GameMode::Classic));
+                    assert_eq!(players.len(), 10);
+                    assert!(players
+                        .iter()
+                        .any(|p| matches!(p.position, Position::Jungle)));
+                    assert!(events.iter().any(|e| matches!(e, GameEvent::DragonKill(_))));
+                    assert!(events
+                        .iter()
+                        .any(|e| matches!(e, GameEvent::TurretKilled(_))));
+                    assert!(game_time > 900.0 && game_time < 3600.0);
+                }
+                SimulatedMap::HowlingAbyss => {
+                    assert!(matches!(last.game_data.game_mode, GameMode::Aram));
+                    assert_eq!(players.len(), 10);
+                    assert!(events
+                        .iter()
+                        .any(|e| matches!(e, GameEvent::TurretKilled(_))));
+                    assert!(!events.iter().any(|e| matches!(e, GameEvent::DragonKill(_))));
+                }
+                SimulatedMap::Arena => {
+                    assert!(matches!(last.game_data.game_mode, GameMode::Arena));
+                    assert_eq!(players.len(), 16);
+                    assert!(players.iter().all(|p| p.runes.is_none()));
+                    assert_eq!(
+                        players.iter().filter(|p| p.team == TeamId::Order).count(),
+                        8
+                    );
+                    // every subteam but the winner gets eliminated
+                    let aces = events
+                        .iter()
+                        .filter(|e| matches!(e, GameEvent::Ace(_)))
+                        .count();
+                    assert_eq!(aces, 7);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn simulator_spectator_mode() {
+    let mut simulator = GameSimulator::new(SimulatedMap::SummonersRift, 1);
+    assert!(simulator.snapshot().active_player.is_some());
+
+    simulator.set_spectator(true);
+    simulator.set_interval(60.0);
+    simulator.advance(600.0);
+    assert_eq!(simulator.game_time(), 600.0);
+    // the first snapshot is the current state
+    let snapshot = simulator.next().unwrap();
+    assert!(snapshot.active_player.is_none());
+    assert_eq!(snapshot.game_data.game_time, 600.0);
+    assert_eq!(simulator.next().unwrap().game_data.game_time, 660.0);
+
+    simulator.run_to_end();
+    assert!(simulator.is_finished());
+    assert!(simulator.next().is_some());
+    assert!(simulator.next().is_none());
+}
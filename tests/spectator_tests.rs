@@ -0,0 +1,94 @@
+use serde_json::{json, Value};
+use shaco::{
+    error::IngameClientError,
+    ingame::{IngameEndpoint, IngameMode},
+    testing::{MockGame, MockIngameServer, MockResponse},
+};
+
+fn fixture() -> Value {
+    serde_json::from_str(include_str!("GetLiveclientdataAllgamedata_Arena6.json")).unwrap()
+}
+
+#[tokio::test]
+async fn capabilities() {
+    let server = MockIngameServer::start(MockGame::Live(fixture()))
+        .await
+        .unwrap();
+    let client = server.ingame_client();
+
+    let live = client.capabilities().await.unwrap();
+    assert_eq!(live.mode, IngameMode::Live);
+    assert!(!live.replay_api);
+    assert!(live.supports(IngameEndpoint::ActivePlayerRunes));
+    assert!(!live.supports(IngameEndpoint::ReplayPlayback));
+
+    server.set_game(MockGame::Spectator(fixture()));
+    let spectator = client.capabilities().await.unwrap();
+    assert_eq!(spectator.mode, IngameMode::Spectator);
+    assert!(spectator.replay_api);
+    assert!(spectator.supports(IngameEndpoint::PlayerList));
+    assert!(spectator.supports(IngameEndpoint::ReplayRender));
+    assert!(!spectator
+        .endpoints
+        .iter()
+        .any(|endpoint| endpoint.is_active_player()));
+
+    // replays are loaded up to their end
+    server.set_response(
+        "/replay/playback",
+        MockResponse::json(&json!({
+            "length": 1800.0, "paused": true, "seeking": false, "speed": 2.0, "time": 120.0
+        })),
+    );
+    assert_eq!(
+        client.capabilities().await.unwrap().mode,
+        IngameMode::Replay
+    );
+
+    // spectated games without the replay API
+    server.set_response("/replay/playback", MockResponse::status(404));
+    let spectator = client.capabilities().await.unwrap();
+    assert_eq!(spectator.mode, IngameMode::Spectator);
+    assert!(!spectator.replay_api);
+    assert!(!spectator.supports(IngameEndpoint::ReplayRender));
+
+    server.clear_responses();
+    server.set_game(MockGame::LoadingScreen);
+    assert!(matches!(
+        client.capabilities().await,
+        Err(IngameClientError::ApiNotAvailableDuringLoadingScreen)
+    ));
+}
+
+#[tokio::test]
+async fn spectator_view() {
+    let server = MockIngameServer::start(MockGame::Spectator(fixture()))
+        .await
+        .unwrap();
+    let view = server.ingame_client().spectator_view();
+
+    let data = view.all_game_data(None).await.unwrap();
+    assert_eq!(
+        data.all_players.len(),
+        view.player_list(None).await.unwrap().len()
+    );
+    assert_eq!(
+        view.game_stats().await.unwrap().game_time,
+        data.game_data.game_time
+    );
+    let playback = view.playback().await.unwrap();
+    assert_eq!(playback.time, data.game_data.game_time);
+
+    assert!(view.selected_player().await.unwrap().is_none());
+    let player = &data.all_players[3];
+    let render = view.select_player(player).await.unwrap();
+    assert!(render.camera_attached);
+    let selected = view.selected_player().await.unwrap().unwrap();
+    assert_eq!(selected.riot_id.riot_id, player.riot_id.riot_id);
+
+    let client = view.into_client();
+    assert!(matches!(
+        client.active_player().await,
+        Err(IngameClientError::ApiNotAvailableInSpectatorMode)
+    ));
+}
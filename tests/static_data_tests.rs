@@ -0,0 +1,145 @@
+use shaco::{
+    error::StaticDataError,
+    model::ingame::{AllGameData, FullPlayerRunes, PlayerRunes, SummonerSpells},
+    static_data::StaticData,
+};
+
+const DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/static_data");
+
+fn static_data() -> StaticData {
+    StaticData::load(DIR).unwrap()
+}
+
+fn aram_fixture() -> AllGameData {
+    serde_json::from_str(include_str!("aram_allgamedata2.json")).unwrap()
+}
+
+#[test]
+fn items() {
+    let data = static_data();
+    assert_eq!(data.version, "14.1.1");
+
+    let boots = data.item(3158).unwrap();
+    assert_eq!(boots.name, "Ionian Boots of Lucidity");
+    assert_eq!(boots.from, [1001]);
+    assert_eq!((boots.gold.base, boots.gold.total), (650, 950));
+    assert!(boots.tags.iter().any(|t| t == "Boots"));
+
+    // Arena copies resolve to the regular item
+    assert_eq!(data.item(223158).unwrap().id, 3158);
+    assert!(data.item(9999).is_none());
+
+    let components = data
+        .components(3042)
+        .iter()
+        .map(|item| item.id)
+        .collect::<Vec<_>>();
+    assert_eq!(components, [3004, 3070, 1036, 1036]);
+    let into = data
+        .builds_into(1001)
+        .iter()
+        .map(|item| item.id)
+        .collect::<Vec<_>>();
+    // Berserker's Greaves aren't part of the test data
+    assert_eq!(into, [3158]);
+}
+
+#[test]
+fn players_of_fixture() {
+    let data = static_data();
+    let game = aram_fixture();
+    let ezreal = &game.all_players[0];
+
+    let champion = data.player_champion(ezreal).unwrap();
+    assert_eq!(champion.name, ezreal.champion_name);
+    assert_eq!(champion.key, 81);
+    assert_eq!(data.champion_by_key(81).unwrap().id, "Ezreal");
+
+    let items = ezreal
+        .items
+        .iter()
+        .filter_map(|item| data.player_item(item))
+        .map(|item| item.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        items,
+        [
+            "Divine Sunderer",
+            "Muramana",
+            "Ionian Boots of Lucidity",
+            "Poro-Snax"
+        ]
+    );
+
+    let SummonerSpells::SummonerSpells {
+        summoner_spell_one, ..
+    } = &ezreal.summoner_spells
+    else {
+        panic!("Ezreal has summoner spells");
+    };
+    let flash = data.player_summoner_spell(summoner_spell_one).unwrap();
+    assert_eq!(flash.cooldown, [300.0]);
+    assert_eq!(data.summoner_spell_by_key(4).unwrap().id, "SummonerFlash");
+
+    let PlayerRunes { keystone, .. } = ezreal.runes.as_ref().unwrap();
+    assert_eq!(data.player_rune(keystone).unwrap().key, "Conqueror");
+    assert_eq!(data.tree_of_rune(keystone.id).unwrap().name, "Precision");
+
+    let FullPlayerRunes::Runes {
+        general_runes,
+        primary_rune_tree,
+        ..
+    } = game.active_player.unwrap().runes
+    else {
+        panic!("the active player has runes");
+    };
+    assert_eq!(
+        data.player_rune_tree(&primary_rune_tree).unwrap().key,
+        "Precision"
+    );
+    assert!(general_runes
+        .iter()
+        .all(|rune| data.player_rune(rune).is_some()));
+}
+
+#[test]
+fn arena_players() {
+    let data = static_data();
+    let game: AllGameData =
+        serde_json::from_str(include_str!("GetLiveclientdataAllgamedata_Arena6.json")).unwrap();
+    let lucian = &game.all_players[0];
+
+    let champion = data.player_champion(lucian).unwrap();
+    let stats = champion.base_stats();
+    assert_eq!(stats.health, 641.0);
+    assert_eq!(stats.armor_per_level, 4.2);
+    let target = stats.at_level(lucian.level);
+    assert!(target.max_health > stats.health || lucian.level == 1);
+
+    assert_eq!(data.player_item(&lucian.items[1]).unwrap().id, 3158);
+    // spells on cooldown still resolve
+    let SummonerSpells::SummonerSpells {
+        summoner_spell_two, ..
+    } = &game.all_players[2].summoner_spells
+    else {
+        panic!("Arena players have summoner spells");
+    };
+    assert!(summoner_spell_two.raw_display_name.contains("_CD_"));
+    assert_eq!(
+        data.player_summoner_spell(summoner_spell_two).unwrap().id,
+        "SummonerCherryFlash"
+    );
+}
+
+#[test]
+fn load_errors() {
+    assert!(matches!(
+        StaticData::load(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/missing")),
+        Err(StaticDataError::Io(_))
+    ));
+    let items = std::fs::read_to_string(format!("{DIR}/item.json")).unwrap();
+    assert!(matches!(
+        StaticData::from_json(&items, "{}", "{}", "{}"),
+        Err(StaticDataError::Format(_))
+    ));
+}
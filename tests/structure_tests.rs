@@ -0,0 +1,95 @@
+use shaco::{
+    analysis::structures::StructureStatus,
+    model::{
+        ingame::{GameEvent, Inhibitor, MapName, TeamId, Turret},
+        map::{Lane, TurretTier},
+    },
+    testing::{GameSimulator, SimulatedMap},
+};
+
+#[test]
+fn map_tables() {
+    let rift = MapName::Map11;
+    let aram = MapName::Map12;
+    assert_eq!(Turret::on_map(&rift).count(), 24);
+    assert_eq!(Turret::on_map(&aram).count(), 10);
+    assert_eq!(Turret::on_map(&MapName::Map30).count(), 0);
+
+    // the same turret on both maps
+    let turret = Turret::Team2L03A;
+    assert_eq!(turret.team(), Some(TeamId::Chaos));
+    assert_eq!(turret.lane(&rift), Some(Lane::Top));
+    assert_eq!(turret.tier(&rift), Some(TurretTier::Outer));
+    assert_eq!(turret.lane(&aram), None);
+    assert_eq!(turret.tier(&aram), Some(TurretTier::Nexus));
+    assert_ne!(turret.map_coordinates(&rift), turret.map_coordinates(&aram));
+
+    assert_eq!(Turret::Obelisk.team(), None);
+    assert_eq!(
+        Turret::Team1Fountain.tier(&rift),
+        Some(TurretTier::Fountain)
+    );
+    assert_eq!(Turret::Team1Fountain.map_coordinates(&rift), None);
+    assert_eq!(Inhibitor::Team1R1.lane(&rift), Some(Lane::Bot));
+    assert_eq!(Inhibitor::Team1R1.lane(&aram), None);
+    assert_eq!(Inhibitor::Team2C1.team(), Some(TeamId::Chaos));
+
+    // blue is bottom left, red top right of the diagonal through the middle of the map
+    for (map, middle) in [(&rift, 14870.0), (&aram, 12988.0)] {
+        for turret in Turret::on_map(map) {
+            let Some(position) = turret.map_coordinates(map) else {
+                continue;
+            };
+            let blue_side = position.x + position.y < middle;
+            assert_eq!(blue_side, turret.team() == Some(TeamId::Order), "{turret}");
+        }
+        for inhibitor in Inhibitor::on_map(map) {
+            let position = inhibitor.map_coordinates(map).unwrap();
+            let blue_side = position.x + position.y < middle;
+            assert_eq!(blue_side, inhibitor.team() == Some(TeamId::Order));
+        }
+    }
+}
+
+#[test]
+fn structures_of_simulated_games() {
+    for (map, lanes) in [
+        (SimulatedMap::SummonersRift, 3),
+        (SimulatedMap::HowlingAbyss, 1),
+    ] {
+        let mut simulator = GameSimulator::new(map, 5);
+        simulator.run_to_end();
+        let data = simulator.snapshot();
+        let status = StructureStatus::from_game_data(&data);
+
+        let turrets_killed = data
+            .events
+            .iter()
+            .filter(|e| matches!(e, GameEvent::TurretKilled(_)))
+            .count();
+        assert!(turrets_killed > 0);
+        assert_eq!(status.destroyed_turrets().len(), turrets_killed);
+
+        for team in [TeamId::Order, TeamId::Chaos] {
+            let lane_structures = status.lanes(team);
+            assert_eq!(lane_structures.len(), lanes);
+            let remaining = status.remaining_turrets(team);
+            for lane in lane_structures {
+                assert_eq!(
+                    lane.turrets.len() + lane.destroyed_turrets,
+                    if lanes == 3 { 3 } else { 2 }
+                );
+                assert!(lane.turrets.iter().all(|t| remaining.contains(t)));
+                assert_eq!(
+                    status.next_turret(team, lane.lane),
+                    lane.turrets.first().copied()
+                );
+                let inhibitor_down = status
+                    .inhibitors_down(team)
+                    .iter()
+                    .any(|i| i.lane(&data.game_data.map_name) == Some(lane.lane));
+                assert_eq!(lane.inhibitor_up, !inhibitor_down);
+            }
+        }
+    }
+}
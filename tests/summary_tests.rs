@@ -0,0 +1,108 @@
+use serde_json::Value;
+use shaco::{
+    analysis::summary::{GameSummary, ObjectiveKind},
+    model::ingame::{GameEvent, GameResult, TeamId},
+    testing::{GameSimulator, SimulatedMap},
+};
+
+#[test]
+fn summary_of_simulated_game() {
+    let mut simulator = GameSimulator::new(SimulatedMap::SummonersRift, 11);
+    simulator.run_to_end();
+    let data = simulator.snapshot();
+    let summary = GameSummary::from_game_data(&data);
+
+    let Some(GameEvent::GameEnd(end)) = data.events.last() else {
+        panic!("the game didn't end");
+    };
+    assert_eq!(summary.game_length, end.event_time);
+    let active_team = data
+        .all_players
+        .iter()
+        .find(|p| p.riot_id.riot_id == data.active_player.as_ref().unwrap().riot_id.riot_id)
+        .unwrap()
+        .team;
+    let results = summary
+        .teams
+        .iter()
+        .map(|t| (t.team, t.result.unwrap()))
+        .collect::<Vec<_>>();
+    assert!(results.contains(&(active_team, end.result)));
+    assert_eq!(
+        results
+            .iter()
+            .filter(|(_, result)| *result == GameResult::Win)
+            .count(),
+        1
+    );
+
+    let objectives = data
+        .events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e,
+                GameEvent::DragonKill(_)
+                    | GameEvent::HordeKill(_)
+                    | GameEvent::HeraldKill(_)
+                    | GameEvent::BaronKill(_)
+                    | GameEvent::TurretKilled(_)
+                    | GameEvent::InhibKilled(_)
+            )
+        })
+        .count();
+    assert_eq!(summary.objectives.len(), objectives);
+    // structures are taken by the enemies of their team
+    for objective in summary.objectives.iter() {
+        if let ObjectiveKind::Turret { turret } = objective.objective {
+            assert_ne!(objective.team, turret.team());
+        }
+    }
+    assert!(summary.first_blood.is_some());
+    assert_eq!(summary.players.len(), 10);
+
+    // the events can come from the event history instead of the data
+    let mut last = data.clone();
+    last.events.clear();
+    let from_history = GameSummary::new(&last, &data.events);
+    assert_eq!(from_history.objectives.len(), summary.objectives.len());
+    assert_eq!(from_history.teams[0].result, summary.teams[0].result);
+
+    let markdown = summary.to_markdown();
+    assert!(markdown.starts_with("## "));
+    assert!(markdown.contains("### Scoreboard"));
+    assert!(markdown.contains("### Objectives"));
+    assert!(markdown.contains("**First blood**"));
+    // a header, a separator and a row per player
+    let scoreboard = markdown
+        .split("### Scoreboard")
+        .nth(1)
+        .unwrap()
+        .lines()
+        .filter(|line| line.starts_with('|'))
+        .count();
+    assert_eq!(scoreboard, 12);
+
+    let mut json = Vec::new();
+    summary.write_json(&mut json).unwrap();
+    let json: Value = serde_json::from_slice(&json).unwrap();
+    assert_eq!(json["teams"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        json["players"][0]["items"],
+        serde_json::to_value(&data.all_players[0].items).unwrap()
+    );
+    assert!(json["objectives"][0]["objective"]["type"].is_string());
+}
+
+#[test]
+fn spectated_game_has_no_result() {
+    let mut simulator = GameSimulator::new(SimulatedMap::HowlingAbyss, 2);
+    simulator.run_to_end();
+    let mut data = simulator.snapshot();
+    data.active_player = None;
+
+    let summary = GameSummary::from_game_data(&data);
+    assert!(summary.teams.iter().all(|t| t.result.is_none()));
+    assert_eq!(summary.teams[1].team, TeamId::Chaos);
+    assert!(summary.to_markdown().contains("| Order | - |"));
+}
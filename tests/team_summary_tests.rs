@@ -0,0 +1,88 @@
+use shaco::{
+    analysis::team::TeamSummary,
+    model::ingame::{AllGameData, GameEvent, Killer, TeamId, Turret},
+    testing::{GameSimulator, SimulatedMap},
+};
+
+fn aram_fixture() -> AllGameData {
+    serde_json::from_str(include_str!("aram_allgamedata1.json")).unwrap()
+}
+
+fn count(data: &AllGameData, filter: fn(&GameEvent) -> bool) -> u32 {
+    data.events.iter().filter(|e| filter(e)).count() as u32
+}
+
+#[test]
+fn team_summary_of_fixture() {
+    let data = aram_fixture();
+    let [order, chaos] = TeamSummary::both_teams(&data);
+
+    assert_eq!(order.team, TeamId::Order);
+    assert_eq!(order.players, 5);
+    assert_eq!(chaos.players, 5);
+    let kills = data.all_players.iter().map(|p| p.scores.kills).sum::<i32>();
+    assert_eq!(order.kills + chaos.kills, kills);
+    assert!(order.item_value > 0 && chaos.item_value > 0);
+    assert_eq!(
+        order.gold_difference(&chaos),
+        order.item_value - chaos.item_value
+    );
+    assert_eq!(
+        order.gold_difference(&chaos),
+        -chaos.gold_difference(&order)
+    );
+    let dead = data.all_players.iter().filter(|p| p.is_dead).count();
+    assert_eq!(order.dead_players.len() + chaos.dead_players.len(), dead);
+
+    // the obelisk "kills" itself when nobody destroyed it
+    let turrets = count(&data, |e| match e {
+        GameEvent::TurretKilled(e) => !matches!(e.killer_name, Killer::Turret(Turret::Obelisk)),
+        _ => false,
+    });
+    assert_eq!(order.objectives.turrets + chaos.objectives.turrets, turrets);
+    let inhibitors = count(&data, |e| matches!(e, GameEvent::InhibKilled(_)));
+    assert_eq!(
+        order.objectives.inhibitors + chaos.objectives.inhibitors,
+        inhibitors
+    );
+    assert!(order.objectives.first_blood ^ chaos.objectives.first_blood);
+    assert!(order.objectives.first_brick ^ chaos.objectives.first_brick);
+}
+
+#[test]
+fn team_summary_of_simulated_games() {
+    for seed in 0..10 {
+        let mut simulator = GameSimulator::new(SimulatedMap::SummonersRift, seed);
+        simulator.run_to_end();
+        let data = simulator.snapshot();
+        let [order, chaos] = TeamSummary::both_teams(&data);
+
+        let objectives = [&order.objectives, &chaos.objectives];
+        let total = |get: fn(&&shaco::analysis::team::ObjectivesTaken) -> u32| {
+            objectives.iter().map(get).sum::<u32>()
+        };
+        assert_eq!(
+            total(|o| o.dragons.len() as u32),
+            count(&data, |e| matches!(e, GameEvent::DragonKill(_)))
+        );
+        assert_eq!(
+            total(|o| o.barons),
+            count(&data, |e| matches!(e, GameEvent::BaronKill(_)))
+        );
+        assert_eq!(
+            total(|o| o.voidgrubs),
+            count(&data, |e| matches!(e, GameEvent::HordeKill(_)))
+        );
+        assert_eq!(
+            total(|o| o.turrets),
+            count(&data, |e| matches!(e, GameEvent::TurretKilled(_)))
+        );
+
+        // the winner destroyed at least the 3 turrets of a lane and the 2 nexus turrets
+        let won = |summary: &TeamSummary| summary.objectives.turrets >= 5;
+        assert!(won(&order) || won(&chaos));
+        assert_eq!(order.kills, chaos.deaths);
+        assert_eq!(chaos.kills, order.deaths);
+        assert!((1.0..=18.0).contains(&order.average_level));
+    }
+}
@@ -0,0 +1,117 @@
+use shaco::{
+    analysis::timeline::{write_csv, PlayerTimeline},
+    model::ingame::{GameEvent, Killer, TeamId},
+    testing::{GameSimulator, SimulatedMap},
+};
+
+fn simulated_timelines(interval: f64) -> Vec<PlayerTimeline> {
+    let mut simulator = GameSimulator::new(SimulatedMap::SummonersRift, 7);
+    simulator.set_interval(interval);
+    PlayerTimeline::from_samples(simulator)
+}
+
+#[test]
+fn timelines_of_simulated_game() {
+    let mut simulator = GameSimulator::new(SimulatedMap::SummonersRift, 7);
+    simulator.run_to_end();
+    let end = simulator.snapshot();
+    let timelines = simulated_timelines(1.0);
+
+    assert_eq!(timelines.len(), 10);
+    for (timeline, player) in timelines.iter().zip(end.all_players.iter()) {
+        assert_eq!(timeline.riot_id, player.riot_id.riot_id);
+        assert!(timeline
+            .samples
+            .windows(2)
+            .all(|w| w[0].game_time < w[1].game_time && w[0].level <= w[1].level));
+
+        let last = timeline.last().unwrap();
+        assert_eq!(last.game_time, end.game_data.game_time);
+        assert_eq!(last.creep_score, player.scores.creep_score);
+        let cs_per_minute = player.scores.creep_score as f64 / (last.game_time / 60.0);
+        assert!((last.cs_per_minute - cs_per_minute).abs() < 1e-9);
+        assert!(timeline
+            .samples
+            .iter()
+            .all(|s| (0.0..=1.0).contains(&s.kill_participation)));
+        assert!(last.gold_per_minute > 0.0);
+
+        // every death lasts at least a second, so a sample sees it
+        assert_eq!(timeline.deaths.len(), player.scores.deaths as usize);
+        assert!(timeline
+            .deaths
+            .iter()
+            .all(|d| d.time_dead.is_some_and(|t| t > 0.0)));
+        assert!(timeline.time_spent_dead() > 0.0 || timeline.deaths.is_empty());
+    }
+
+    // kills and assists of a team against its kills from the events
+    let team_kills = end
+        .events
+        .iter()
+        .filter(|e| match e {
+            GameEvent::ChampionKill(kill) => match &kill.killer_name {
+                Killer::Summoner(name) => end
+                    .all_players
+                    .iter()
+                    .any(|p| &p.riot_id.game_name == name && p.team == TeamId::Order),
+                _ => false,
+            },
+            _ => false,
+        })
+        .count();
+    for (timeline, player) in timelines.iter().zip(end.all_players.iter()) {
+        if player.team != TeamId::Order || team_kills == 0 {
+            continue;
+        }
+        let involved = (player.scores.kills + player.scores.assists) as f64;
+        let participation = timeline.last().unwrap().kill_participation;
+        assert!((participation - involved / team_kills as f64).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn timelines_ignore_old_samples() {
+    let mut simulator = GameSimulator::new(SimulatedMap::HowlingAbyss, 2);
+    simulator.advance(300.0);
+    let early = simulator.snapshot();
+    simulator.advance(300.0);
+    let late = simulator.snapshot();
+
+    let mut timeline = PlayerTimeline::new(&early.all_players[3]);
+    assert!(timeline.push(&early));
+    assert!(timeline.push(&late));
+    assert!(timeline.push(&early));
+    assert!(timeline.push(&late));
+    assert_eq!(timeline.samples.len(), 2);
+    assert_eq!(
+        timeline.deaths.len(),
+        late.all_players[3].scores.deaths as usize
+    );
+
+    let mut without_player = late.clone();
+    without_player.all_players.remove(3);
+    assert!(!timeline.push(&without_player));
+}
+
+#[test]
+fn timelines_export() {
+    let timelines = simulated_timelines(60.0);
+    let samples = timelines[0].samples.len();
+
+    let mut csv = Vec::new();
+    write_csv(&timelines, &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 1 + 10 * samples);
+    let columns = lines[0].split(',').count();
+    assert!(lines.iter().all(|l| l.split(',').count() == columns));
+    assert!(lines[1].starts_with("Player 1#SIM,"));
+    assert!(lines[1].contains(",ORDER,"));
+
+    let json = serde_json::to_value(&timelines).unwrap();
+    assert_eq!(json[0]["riotId"], "Player 1#SIM");
+    assert_eq!(json[0]["samples"].as_array().unwrap().len(), samples);
+    assert!(json[0]["samples"][1]["csPerMinute"].is_number());
+    assert!(json[0].get("teamKills").is_none());
+}